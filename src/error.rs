@@ -0,0 +1,97 @@
+#[cfg(feature = "alloc")]
+use crate::num::bigint::ParseBigIntError;
+
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned by the `try_*` counterparts of panicking constructors and operations.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An input had `found` items where `expected` were needed.
+    ShapeMismatch {
+        expected: usize,
+        found: usize,
+    },
+    SingularMatrix,
+    /// A heap allocated vector or matrix, or a curve, had fewer than `minimum` items.
+    DimensionTooSmall {
+        minimum: usize,
+        found: usize,
+    },
+    DivisionByZero,
+    /// An argument was outside the domain of the operation.
+    InvalidArgument(&'static str),
+    #[cfg(feature = "alloc")]
+    Parse(ParseBigIntError),
+}
+
+impl Display for Error {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ShapeMismatch { expected, found } => {
+                write!(fmt, "Expected {} items, got {}", expected, found)
+            }
+            Error::SingularMatrix => fmt.write_str("Matrix is singular"),
+            Error::DimensionTooSmall { minimum, found } => {
+                write!(fmt, "Expected at least {} items, got {}", minimum, found)
+            }
+            Error::DivisionByZero => fmt.write_str("Division by zero"),
+            Error::InvalidArgument(message) => fmt.write_str(message),
+            #[cfg(feature = "alloc")]
+            Error::Parse(error) => Display::fmt(error, fmt),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "alloc")]
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseBigIntError> for Error {
+    fn from(error: ParseBigIntError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Checks that `found` items were given where `expected` were needed.
+pub(crate) fn check_shape(expected: usize, found: usize) -> Result<(), Error> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(Error::ShapeMismatch { expected, found })
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::num::bigint::BigInt;
+    use core::error::Error as _;
+
+    #[test]
+    fn conversions() {
+        fn parse(text: &str) -> Result<BigInt, Error> {
+            Ok(text.parse::<BigInt>()? * BigInt::from(2))
+        }
+        assert_eq!(parse("21").unwrap(), BigInt::from(42));
+        let error = parse("x").unwrap_err();
+        assert!(matches!(error, Error::Parse(_)));
+        assert!(error.source().is_some());
+        assert_eq!(error.to_string(), "invalid digit found in string");
+        assert_eq!(
+            Error::ShapeMismatch {
+                expected: 4,
+                found: 3
+            }
+            .to_string(),
+            "Expected 4 items, got 3"
+        );
+    }
+}
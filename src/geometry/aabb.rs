@@ -0,0 +1,188 @@
+use super::sphere::Sphere;
+use super::triangle::Triangle;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// An axis-aligned box between the corners `min` and `max`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Aabb<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T: Real> Aabb<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+    /// The smallest box containing every point, or `None` if there are no points.
+    pub fn from_points(points: &[Vector3<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| {
+            Self::new(
+                Vector3::from_array(core::array::from_fn(|i| {
+                    aabb.min.storage[i].min(point.storage[i])
+                })),
+                Vector3::from_array(core::array::from_fn(|i| {
+                    aabb.max.storage[i].max(point.storage[i])
+                })),
+            )
+        }))
+    }
+    pub fn center(&self) -> Vector3<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+    pub fn half_extents(&self) -> Vector3<T> {
+        (self.max - self.min) / (T::one() + T::one())
+    }
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        (0..3).all(|i| {
+            self.min.storage[i] <= point.storage[i] && point.storage[i] <= self.max.storage[i]
+        })
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        Vector3::from_array(core::array::from_fn(|i| {
+            point.storage[i].clamp(self.min.storage[i], self.max.storage[i])
+        }))
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.closest_point(point) - point).length()
+    }
+    pub fn intersects_aabb(&self, other: &Aabb<T>) -> bool {
+        (0..3).all(|i| {
+            self.min.storage[i] <= other.max.storage[i]
+                && other.min.storage[i] <= self.max.storage[i]
+        })
+    }
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        (self.closest_point(sphere.center) - sphere.center).length_squared()
+            <= sphere.radius * sphere.radius
+    }
+    /// Separating axis test against the box axes, the triangle normal and the nine cross products
+    /// of their edges.
+    pub fn intersects_triangle(&self, triangle: &Triangle<T>) -> bool {
+        let center = self.center();
+        let extents = self.half_extents();
+        let vertices = [
+            triangle.a - center,
+            triangle.b - center,
+            triangle.c - center,
+        ];
+        let edges = [
+            vertices[1] - vertices[0],
+            vertices[2] - vertices[1],
+            vertices[0] - vertices[2],
+        ];
+        let (zero, one) = (T::zero(), T::one());
+        let box_axes = [
+            Vector3::new(one, zero, zero),
+            Vector3::new(zero, one, zero),
+            Vector3::new(zero, zero, one),
+        ];
+        let separates = |axis: Vector3<T>| {
+            let projections = vertices.map(|vertex| vertex.dot(axis));
+            let min = projections[0].min(projections[1]).min(projections[2]);
+            let max = projections[0].max(projections[1]).max(projections[2]);
+            let radius = (0..3).fold(zero, |radius, i| {
+                radius + extents.storage[i] * axis.storage[i].abs()
+            });
+            min > radius || max < -radius
+        };
+        let crosses = box_axes
+            .into_iter()
+            .flat_map(|box_axis| edges.map(|edge| box_axis.cross(edge)));
+        !box_axes
+            .into_iter()
+            .chain([edges[0].cross(edges[1])])
+            .chain(crosses)
+            .any(separates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit() -> Aabb<f64> {
+        Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn queries() {
+        let aabb = Aabb::from_points(&[
+            Vector3::new(1.0, -1.0, 0.0),
+            Vector3::new(-1.0, 1.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        ])
+        .unwrap();
+        assert_eq!(aabb, unit());
+        assert_eq!(Aabb::<f64>::from_points(&[]), None);
+        assert_eq!(aabb.center(), Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(aabb.half_extents(), Vector3::new(1.0, 1.0, 1.0));
+        assert!(aabb.contains_point(Vector3::new(1.0, 0.5, -1.0)));
+        assert!(!aabb.contains_point(Vector3::new(1.5, 0.5, -1.0)));
+        assert_eq!(
+            aabb.closest_point(Vector3::new(4.0, 0.5, -5.0)),
+            Vector3::new(1.0, 0.5, -1.0)
+        );
+        assert_eq!(aabb.distance_to_point(Vector3::new(4.0, 5.0, 0.0)), 5.0);
+
+        let other = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+        assert!(aabb.intersects_aabb(&other));
+        assert!(!aabb.intersects_aabb(&Aabb::new(
+            Vector3::new(1.1, 0.0, 0.0),
+            Vector3::new(2.0, 2.0, 2.0)
+        )));
+        assert!(aabb.intersects_sphere(&Sphere::new(Vector3::new(2.0, 2.0, 0.0), 1.5)));
+        assert!(!aabb.intersects_sphere(&Sphere::new(Vector3::new(2.0, 2.0, 2.0), 1.5)));
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = |a: [f64; 3], b: [f64; 3], c: [f64; 3]| {
+            Triangle::new(
+                Vector3::from_array(a),
+                Vector3::from_array(b),
+                Vector3::from_array(c),
+            )
+        };
+        // A large triangle cutting through the box with every vertex outside it.
+        assert!(unit().intersects_triangle(&triangle(
+            [-5.0, -5.0, 0.0],
+            [5.0, -5.0, 0.0],
+            [0.0, 5.0, 0.0]
+        )));
+        assert!(unit().intersects_triangle(&triangle(
+            [0.0, 0.0, 0.0],
+            [0.5, 0.0, 0.0],
+            [0.0, 0.5, 0.0]
+        )));
+        // Separated by a box face.
+        assert!(!unit().intersects_triangle(&triangle(
+            [2.0, -5.0, 0.0],
+            [2.0, 5.0, 0.0],
+            [3.0, 0.0, 5.0]
+        )));
+        // Separated by the triangle normal, passing just beyond the corner.
+        assert!(!unit().intersects_triangle(&triangle(
+            [3.5, 0.0, 0.0],
+            [0.0, 3.5, 0.0],
+            [0.0, 0.0, 3.5]
+        )));
+        assert!(unit().intersects_triangle(&triangle(
+            [2.5, 0.0, 0.0],
+            [0.0, 2.5, 0.0],
+            [0.0, 0.0, 2.5]
+        )));
+        // Only separated by the cross product of an edge and the Z axis, cutting off the corner.
+        assert!(!unit().intersects_triangle(&triangle(
+            [2.0, 0.5, 0.0],
+            [0.5, 2.0, 0.0],
+            [2.0, 2.0, 0.0]
+        )));
+        assert!(unit().intersects_triangle(&triangle(
+            [1.5, 0.0, 0.0],
+            [0.0, 1.5, 0.0],
+            [2.0, 2.0, 0.0]
+        )));
+    }
+}
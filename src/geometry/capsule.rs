@@ -0,0 +1,66 @@
+use super::segment::Segment;
+use super::sphere::Sphere;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// The points within `radius` of `segment`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Capsule<T> {
+    pub segment: Segment<T>,
+    pub radius: T,
+}
+
+impl<T: Real> Capsule<T> {
+    pub fn new(segment: Segment<T>, radius: T) -> Self {
+        Self { segment, radius }
+    }
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.segment.distance_to_point(point) <= self.radius
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        Sphere::new(self.segment.closest_point(point), self.radius).closest_point(point)
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.segment.distance_to_point(point) - self.radius).max(T::zero())
+    }
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        self.segment.distance_to_point(sphere.center) <= self.radius + sphere.radius
+    }
+    pub fn intersects_capsule(&self, other: &Capsule<T>) -> bool {
+        self.segment.distance_to_segment(&other.segment) <= self.radius + other.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries() {
+        let capsule = Capsule::new(
+            Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0)),
+            1.0,
+        );
+        assert!(capsule.contains_point(Vector3::new(1.0, 2.0, 0.0)));
+        assert!(capsule.contains_point(Vector3::new(0.0, 5.0, 0.0)));
+        assert!(!capsule.contains_point(Vector3::new(0.0, 5.5, 0.0)));
+        assert_eq!(
+            capsule.closest_point(Vector3::new(3.0, 2.0, 0.0)),
+            Vector3::new(1.0, 2.0, 0.0)
+        );
+        assert_eq!(capsule.distance_to_point(Vector3::new(0.0, -3.0, 0.0)), 2.0);
+
+        assert!(capsule.intersects_sphere(&Sphere::new(Vector3::new(2.0, 4.0, 0.0), 1.0)));
+        assert!(!capsule.intersects_sphere(&Sphere::new(Vector3::new(2.0, 5.0, 0.0), 1.0)));
+        let crossing = Capsule::new(
+            Segment::new(Vector3::new(-5.0, 2.0, 1.5), Vector3::new(5.0, 2.0, 1.5)),
+            0.5,
+        );
+        assert!(capsule.intersects_capsule(&crossing));
+        let above = Capsule::new(
+            Segment::new(Vector3::new(-5.0, 2.0, 1.6), Vector3::new(5.0, 2.0, 1.6)),
+            0.5,
+        );
+        assert!(!capsule.intersects_capsule(&above));
+    }
+}
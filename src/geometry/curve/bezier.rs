@@ -0,0 +1,275 @@
+use super::{difference, length, lerp, zero, CurvePoint};
+use crate::num::{One, Real, Signed, Zero};
+
+use alloc::{vec, vec::Vec};
+
+/// A Bézier curve of any degree, defined for `t` in `[0, 1]`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Bezier<P> {
+    points: Vec<P>,
+}
+
+// Gauss–Legendre nodes and weights on `[-1, 1]`, used to integrate the speed of a curve.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.5688888888888889),
+    (-0.5384693101056831, 0.4786286704993665),
+    (0.5384693101056831, 0.4786286704993665),
+    (-0.906179845938664, 0.2369268850561891),
+    (0.906179845938664, 0.2369268850561891),
+];
+const ARC_LENGTH_SEGMENTS: usize = 16;
+
+/// Runs de Casteljau's algorithm, returning the control points of the curves over `[0, t]` and
+/// `[t, 1]`, with `lerp` interpolating at `t`.
+fn de_casteljau<X: Copy>(points: &[X], lerp: impl Fn(X, X) -> X) -> (Vec<X>, Vec<X>) {
+    let mut points = points.to_vec();
+    let mut left = vec![points[0]];
+    let mut right = vec![points[points.len() - 1]];
+    for level in (1..points.len()).rev() {
+        for i in 0..level {
+            points[i] = lerp(points[i], points[i + 1]);
+        }
+        left.push(points[0]);
+        right.push(points[level - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Widens `min` and `max` to the range of a one-dimensional Bézier curve by subdividing it
+/// until the range of each piece's control points is already covered.
+fn extend_range<T: Real>(coefficients: &[T], depth: usize, min: &mut T, max: &mut T) {
+    let (first, last) = (coefficients[0], coefficients[coefficients.len() - 1]);
+    *min = min.min(first).min(last);
+    *max = max.max(first).max(last);
+    let (low, high) = coefficients
+        .iter()
+        .fold((first, first), |(low, high), &x| (low.min(x), high.max(x)));
+    if (low >= *min && high <= *max) || depth == 0 {
+        return;
+    }
+    let half = T::one() / (T::one() + T::one());
+    let (left, right) = de_casteljau(coefficients, |a, b| a + (b - a) * half);
+    extend_range(&left, depth - 1, min, max);
+    extend_range(&right, depth - 1, min, max);
+}
+
+impl<P: CurvePoint> Bezier<P> {
+    /// Panics if `points` is empty.
+    pub fn new(points: Vec<P>) -> Self {
+        Self::try_new(points).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_new(points: Vec<P>) -> Result<Self, crate::Error> {
+        if points.is_empty() {
+            return Err(crate::Error::DimensionTooSmall {
+                minimum: 1,
+                found: 0,
+            });
+        }
+        Ok(Self { points })
+    }
+    pub fn quadratic(start: P, control: P, end: P) -> Self {
+        Self::new(vec![start, control, end])
+    }
+    pub fn cubic(start: P, control_start: P, control_end: P, end: P) -> Self {
+        Self::new(vec![start, control_start, control_end, end])
+    }
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    pub fn degree(&self) -> usize {
+        self.points.len() - 1
+    }
+    pub fn evaluate(&self, t: P::Scalar) -> P {
+        let mut points = self.points.clone();
+        for level in (1..points.len()).rev() {
+            for i in 0..level {
+                points[i] = lerp(points[i], points[i + 1], t);
+            }
+        }
+        points[0]
+    }
+    /// Splits the curve at `t` into the curves over `[0, t]` and `[t, 1]`.
+    pub fn split(&self, t: P::Scalar) -> (Self, Self) {
+        let (left, right) = de_casteljau(&self.points, |a, b| lerp(a, b, t));
+        (Self::new(left), Self::new(right))
+    }
+    /// The curve of the derivative with respect to `t`, one degree lower.
+    pub fn derivative(&self) -> Self {
+        if self.points.len() == 1 {
+            return Self::new(vec![zero()]);
+        }
+        let degree = P::Scalar::from_f64(self.degree() as f64);
+        Self::new(
+            self.points
+                .windows(2)
+                .map(|pair| difference(pair[0], pair[1], degree))
+                .collect(),
+        )
+    }
+    fn integrate_speed(derivative: &Self, start: P::Scalar, end: P::Scalar) -> P::Scalar {
+        let half = P::Scalar::one() / (P::Scalar::one() + P::Scalar::one());
+        let step = (end - start) / P::Scalar::from_f64(ARC_LENGTH_SEGMENTS as f64);
+        (0..ARC_LENGTH_SEGMENTS)
+            .flat_map(|segment| GAUSS_LEGENDRE.map(move |node| (segment, node)))
+            .map(|(segment, (node, weight))| {
+                let middle = start + step * (P::Scalar::from_f64(segment as f64) + half);
+                let t = middle + step * half * P::Scalar::from_f64(node);
+                length(derivative.evaluate(t)) * P::Scalar::from_f64(weight) * step * half
+            })
+            .fold(P::Scalar::zero(), |sum, x| sum + x)
+    }
+    /// The arc length of the whole curve.
+    pub fn length(&self) -> P::Scalar {
+        self.length_to(P::Scalar::one())
+    }
+    /// The arc length of the curve from `0` to `t`.
+    pub fn length_to(&self, t: P::Scalar) -> P::Scalar {
+        Self::integrate_speed(&self.derivative(), P::Scalar::zero(), t)
+    }
+    /// The parameter at which the arc length from the start reaches `distance`, for moving along
+    /// the curve at constant speed. Clamped to `[0, 1]`.
+    pub fn parameter_at_length(&self, distance: P::Scalar) -> P::Scalar {
+        let (zero, one) = (P::Scalar::zero(), P::Scalar::one());
+        let derivative = self.derivative();
+        let total = Self::integrate_speed(&derivative, zero, one);
+        if distance <= zero {
+            return zero;
+        }
+        if distance >= total {
+            return one;
+        }
+        // Newton's method, falling back to bisection when a step leaves the bracket.
+        let tolerance = P::Scalar::epsilon() * P::Scalar::from_f64(16.0) * total;
+        let (mut low, mut high) = (zero, one);
+        let mut t = distance / total;
+        for _ in 0..64 {
+            let error = Self::integrate_speed(&derivative, zero, t) - distance;
+            if error.abs() <= tolerance {
+                break;
+            }
+            if error > zero {
+                high = t;
+            } else {
+                low = t;
+            }
+            let speed = length(derivative.evaluate(t));
+            let next = t - error / speed;
+            t = if speed > zero && next > low && next < high {
+                next
+            } else {
+                (low + high) / (one + one)
+            };
+        }
+        t
+    }
+    /// The smallest box containing the curve, as its minimum and maximum corners.
+    pub fn bounding_box(&self) -> (P, P) {
+        let bounds: Vec<_> = (0..P::DIMENSION)
+            .map(|i| {
+                let coefficients: Vec<_> = self.points.iter().map(|p| p.component(i)).collect();
+                let (mut min, mut max) = (coefficients[0], coefficients[0]);
+                extend_range(&coefficients, 32, &mut min, &mut max);
+                (min, max)
+            })
+            .collect();
+        (P::from_fn(|i| bounds[i].0), P::from_fn(|i| bounds[i].1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::vector::vector_2::Vector2;
+    use crate::linear_algebra::vector::vector_3::Vector3;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn evaluation() {
+        let curve = Bezier::quadratic(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 2.0),
+            Vector2::new(2.0, 0.0),
+        );
+        assert_eq!(curve.degree(), 2);
+        assert_eq!(curve.evaluate(0.0), Vector2::new(0.0, 0.0));
+        assert_eq!(curve.evaluate(0.5), Vector2::new(1.0, 1.0));
+        assert_eq!(curve.evaluate(1.0), Vector2::new(2.0, 0.0));
+
+        let derivative = curve.derivative();
+        assert_eq!(
+            derivative.points(),
+            [Vector2::new(2.0, 4.0), Vector2::new(2.0, -4.0)]
+        );
+        assert_eq!(derivative.evaluate(0.5), Vector2::new(2.0, 0.0));
+        assert_eq!(
+            derivative.derivative().derivative().points(),
+            [Vector2::new(0.0, 0.0)]
+        );
+
+        let quartic = Bezier::new(vec![
+            Vector3::new(0.0f64, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(1.0, 1.0, 2.0),
+            Vector3::new(0.0, 1.0, 3.0),
+            Vector3::new(0.0, 2.0, 4.0),
+        ]);
+        let (left, right) = quartic.split(0.3);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let expected = quartic.evaluate(t);
+            let actual = if t <= 0.3 {
+                left.evaluate(t / 0.3)
+            } else {
+                right.evaluate((t - 0.3) / 0.7)
+            };
+            assert!((0..3).all(|k| (expected.storage[k] - actual.storage[k]).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn arc_length() {
+        // A straight line traversed at varying speed.
+        let line = Bezier::cubic(
+            Vector2::new(0.0f64, 0.0),
+            Vector2::new(0.1, 0.0),
+            Vector2::new(2.9, 0.0),
+            Vector2::new(3.0, 0.0),
+        );
+        assert!((line.length() - 3.0).abs() < 1e-12);
+        assert!((line.parameter_at_length(1.5) - 0.5).abs() < 1e-12);
+        assert_close(
+            line.evaluate(line.parameter_at_length(1.0)),
+            Vector2::new(1.0, 0.0),
+        );
+        assert_eq!(line.parameter_at_length(-1.0), 0.0);
+        assert_eq!(line.parameter_at_length(4.0), 1.0);
+
+        let curve = Bezier::cubic(
+            Vector2::new(0.0f64, 0.0),
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, -1.0),
+            Vector2::new(4.0, 1.0),
+        );
+        let t = curve.parameter_at_length(2.0);
+        assert!((curve.length_to(t) - 2.0).abs() < 1e-12);
+        let (left, _) = curve.split(t);
+        assert!((left.length() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let curve = Bezier::cubic(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 2.0),
+            Vector2::new(2.0, -2.0),
+            Vector2::new(3.0, 0.0),
+        );
+        // y = 6t(1 - t)(1 - 2t), with extrema at t = 1/2 ± √3/6.
+        let t = 0.5 - 3.0f64.sqrt() / 6.0;
+        let extreme = 6.0 * t * (1.0 - t) * (1.0 - 2.0 * t);
+        let (min, max) = curve.bounding_box();
+        assert_close(min, Vector2::new(0.0, -extreme));
+        assert_close(max, Vector2::new(3.0, extreme));
+    }
+}
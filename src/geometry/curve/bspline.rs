@@ -0,0 +1,283 @@
+use super::{difference, lerp, zero, CurvePoint};
+use crate::error::check_shape;
+use crate::num::{One, Real, Zero};
+
+use alloc::{vec, vec::Vec};
+
+/// A B-spline curve of any degree over a non-decreasing knot vector, defined for `t` in
+/// [`BSpline::domain`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct BSpline<P: CurvePoint> {
+    degree: usize,
+    knots: Vec<P::Scalar>,
+    points: Vec<P>,
+}
+
+impl<P: CurvePoint> BSpline<P> {
+    /// Panics if there are not more control points than the degree, if the number of knots is
+    /// not the number of control points plus the degree plus one, or if the knots decrease.
+    pub fn new(degree: usize, knots: Vec<P::Scalar>, points: Vec<P>) -> Self {
+        Self::try_new(degree, knots, points).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_new(
+        degree: usize,
+        knots: Vec<P::Scalar>,
+        points: Vec<P>,
+    ) -> Result<Self, crate::Error> {
+        if points.len() <= degree {
+            return Err(crate::Error::DimensionTooSmall {
+                minimum: degree + 1,
+                found: points.len(),
+            });
+        }
+        check_shape(points.len() + degree + 1, knots.len())?;
+        if knots.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(crate::Error::InvalidArgument(
+                "Knots must be non-decreasing",
+            ));
+        }
+        Ok(Self {
+            degree,
+            knots,
+            points,
+        })
+    }
+    /// A B-spline with knots at consecutive integers, which does not pass through its first and
+    /// last control points.
+    pub fn uniform(degree: usize, points: Vec<P>) -> Self {
+        let knots = (0..points.len() + degree + 1)
+            .map(|i| P::Scalar::from_f64(i as f64))
+            .collect();
+        Self::new(degree, knots, points)
+    }
+    /// A B-spline over `[0, 1]` with evenly spaced interior knots and the end knots repeated, so
+    /// it starts and ends at its first and last control points. Panics if there are not more
+    /// control points than the degree.
+    pub fn clamped(degree: usize, points: Vec<P>) -> Self {
+        Self::try_clamped(degree, points).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_clamped(degree: usize, points: Vec<P>) -> Result<Self, crate::Error> {
+        if points.len() <= degree {
+            return Err(crate::Error::DimensionTooSmall {
+                minimum: degree + 1,
+                found: points.len(),
+            });
+        }
+        let spans = points.len() - degree;
+        let knots = core::iter::repeat_n(P::Scalar::zero(), degree)
+            .chain((0..=spans).map(|i| P::Scalar::from_f64(i as f64 / spans as f64)))
+            .chain(core::iter::repeat_n(P::Scalar::one(), degree))
+            .collect();
+        Self::try_new(degree, knots, points)
+    }
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+    pub fn knots(&self) -> &[P::Scalar] {
+        &self.knots
+    }
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+    /// The range of parameters over which the curve is defined.
+    pub fn domain(&self) -> (P::Scalar, P::Scalar) {
+        (self.knots[self.degree], self.knots[self.points.len()])
+    }
+    /// The index `k` of the non-empty knot span `[knots[k], knots[k + 1])` containing `t`, with
+    /// parameters outside the domain using the first or last span.
+    fn span(&self, t: P::Scalar) -> usize {
+        let inner = &self.knots[self.degree + 1..self.points.len()];
+        self.degree + inner.partition_point(|&knot| knot <= t)
+    }
+    /// Evaluates the curve with de Boor's algorithm.
+    pub fn evaluate(&self, t: P::Scalar) -> P {
+        let (degree, span) = (self.degree, self.span(t));
+        let mut points = self.points[span - degree..=span].to_vec();
+        for level in 1..=degree {
+            for j in (level..=degree).rev() {
+                let i = j + span - degree;
+                let start = self.knots[i];
+                let alpha = (t - start) / (self.knots[i + degree + 1 - level] - start);
+                points[j] = lerp(points[j - 1], points[j], alpha);
+            }
+        }
+        points[degree]
+    }
+    /// The curve of the derivative with respect to `t`, one degree lower.
+    pub fn derivative(&self) -> Self {
+        let degree = self.degree;
+        if degree == 0 {
+            return Self::new(0, self.knots.clone(), vec![zero(); self.points.len()]);
+        }
+        let scale = P::Scalar::from_f64(degree as f64);
+        let points = self
+            .points
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let width = self.knots[i + degree + 1] - self.knots[i + 1];
+                if width.is_zero() {
+                    zero()
+                } else {
+                    difference(pair[0], pair[1], scale / width)
+                }
+            })
+            .collect();
+        Self::new(
+            degree - 1,
+            self.knots[1..self.knots.len() - 1].to_vec(),
+            points,
+        )
+    }
+    /// Inserts the knot `t` with Boehm's algorithm, adding a control point without changing the
+    /// shape of the curve.
+    ///
+    /// Panics if `t` is outside the domain.
+    pub fn insert_knot(&self, t: P::Scalar) -> Self {
+        self.try_insert_knot(t)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_insert_knot(&self, t: P::Scalar) -> Result<Self, crate::Error> {
+        let (start, end) = self.domain();
+        if !(start <= t && t <= end) {
+            return Err(crate::Error::InvalidArgument(
+                "Knot is outside the domain of the curve",
+            ));
+        }
+        let (degree, span) = (self.degree, self.span(t));
+        let points = (0..=self.points.len())
+            .map(|i| {
+                if i + degree <= span {
+                    self.points[i]
+                } else if i > span {
+                    self.points[i - 1]
+                } else {
+                    let start = self.knots[i];
+                    let alpha = (t - start) / (self.knots[i + degree] - start);
+                    lerp(self.points[i - 1], self.points[i], alpha)
+                }
+            })
+            .collect();
+        let mut knots = self.knots.clone();
+        knots.insert(span + 1, t);
+        Ok(Self::new(degree, knots, points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::curve::bezier::Bezier;
+    use crate::linear_algebra::vector::vector_2::Vector2;
+    use crate::test_util::assert_close;
+
+    fn control_points() -> Vec<Vector2<f64>> {
+        vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 3.0),
+            Vector2::new(3.0, 3.0),
+            Vector2::new(4.0, 0.0),
+            Vector2::new(6.0, -1.0),
+            Vector2::new(7.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn evaluation() {
+        let points = control_points();
+        let uniform = BSpline::uniform(3, points.clone());
+        assert_eq!(uniform.domain(), (3.0, 6.0));
+        // At a knot, a uniform cubic B-spline is (P0 + 4 P1 + P2) / 6 of the surrounding points.
+        assert_close(uniform.evaluate(4.0), Vector2::new(17.0 / 6.0, 2.5));
+
+        let clamped = BSpline::clamped(3, points.clone());
+        assert_eq!(
+            clamped.knots(),
+            [0.0, 0.0, 0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0, 1.0, 1.0]
+        );
+        assert_close(clamped.evaluate(0.0), points[0]);
+        assert_close(clamped.evaluate(1.0), points[5]);
+
+        // With no interior knots a clamped B-spline is a Bézier curve.
+        let bezier = Bezier::new(points[..4].to_vec());
+        let spline = BSpline::clamped(3, points[..4].to_vec());
+        for i in 0..=8 {
+            let t = i as f64 / 8.0;
+            assert_close(spline.evaluate(t), bezier.evaluate(t));
+        }
+        assert_eq!(
+            BSpline::clamped(1, points.clone()).evaluate(0.1),
+            Vector2::new(0.5, 1.5)
+        );
+    }
+
+    #[test]
+    fn derivative() {
+        let spline = BSpline::new(
+            3,
+            vec![0.0, 0.0, 0.0, 0.0, 0.2, 0.7, 1.0, 1.0, 1.0, 1.0],
+            control_points(),
+        );
+        let derivative = spline.derivative();
+        assert_eq!(derivative.degree(), 2);
+        let h = 1e-6;
+        for t in [0.1, 0.3, 0.5, 0.9] {
+            let (before, after) = (spline.evaluate(t - h), spline.evaluate(t + h));
+            let estimate = after.zip_map(before, |a, b| (a - b) / (2.0 * h));
+            let exact = derivative.evaluate(t);
+            assert!((0..2).all(|i| (estimate.storage[i] - exact.storage[i]).abs() < 1e-6));
+        }
+    }
+
+    #[test]
+    fn knot_insertion() {
+        let spline = BSpline::clamped(3, control_points());
+        let refined = spline
+            .insert_knot(0.5)
+            .insert_knot(0.5)
+            .insert_knot(1.0 / 3.0);
+        assert_eq!(refined.points().len(), 9);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_close(refined.evaluate(t), spline.evaluate(t));
+        }
+        // Inserting a knot until it has multiplicity equal to the degree puts a control point
+        // on the curve.
+        let split = refined.insert_knot(0.5);
+        let point = spline.evaluate(0.5);
+        assert!(split.points().iter().any(|&control| {
+            (0..2).all(|i| (control.storage[i] - point.storage[i]).abs() < 1e-12)
+        }));
+    }
+
+    #[test]
+    fn fallible() {
+        assert!(matches!(
+            BSpline::try_new(3, vec![0.0; 4], control_points()[..3].to_vec()),
+            Err(crate::Error::DimensionTooSmall {
+                minimum: 4,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            BSpline::try_new(1, vec![0.0; 3], control_points()),
+            Err(crate::Error::ShapeMismatch { .. })
+        ));
+        let knots = (0..control_points().len() + 2)
+            .rev()
+            .map(|i| i as f64)
+            .collect();
+        assert!(BSpline::try_new(1, knots, control_points()).is_err());
+        assert!(matches!(
+            BSpline::try_clamped(3, control_points()[..3].to_vec()),
+            Err(crate::Error::DimensionTooSmall {
+                minimum: 4,
+                found: 3
+            })
+        ));
+        assert!(BSpline::<Vector2<f64>>::try_clamped(0, Vec::new()).is_err());
+        let spline = BSpline::clamped(3, control_points());
+        assert!(spline.try_insert_knot(2.0).is_err());
+        assert!(spline.try_insert_knot(0.5).is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+//! Parametric Bézier, B-spline and NURBS curves over `Vector2`, `Vector3` and `Vector4`.
+
+pub mod bezier;
+pub mod bspline;
+pub mod nurbs;
+
+use crate::linear_algebra::vector::vector_2::Vector2;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::vector_4::Vector4;
+use crate::linear_algebra::vector::Orientation;
+use crate::num::{Real, Zero};
+
+/// A point type curves can be built from, accessed one component at a time.
+pub trait CurvePoint: Copy {
+    type Scalar: Real;
+
+    const DIMENSION: usize;
+
+    fn component(&self, index: usize) -> Self::Scalar;
+    fn from_fn(f: impl FnMut(usize) -> Self::Scalar) -> Self;
+}
+
+macro_rules! curve_point_impl {
+    ($vector:ident, $n:literal) => {
+        impl<T: Real, O: Orientation> CurvePoint for $vector<T, O> {
+            type Scalar = T;
+
+            const DIMENSION: usize = $n;
+
+            fn component(&self, index: usize) -> T {
+                self.storage[index]
+            }
+            fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+                Self::from_array(core::array::from_fn(f))
+            }
+        }
+    };
+}
+
+curve_point_impl!(Vector2, 2);
+curve_point_impl!(Vector3, 3);
+curve_point_impl!(Vector4, 4);
+
+pub(crate) fn lerp<P: CurvePoint>(a: P, b: P, t: P::Scalar) -> P {
+    P::from_fn(|i| a.component(i) + (b.component(i) - a.component(i)) * t)
+}
+
+/// `(b - a) * scale`
+pub(crate) fn difference<P: CurvePoint>(a: P, b: P, scale: P::Scalar) -> P {
+    P::from_fn(|i| (b.component(i) - a.component(i)) * scale)
+}
+
+pub(crate) fn zero<P: CurvePoint>() -> P {
+    P::from_fn(|_| P::Scalar::zero())
+}
+
+pub(crate) fn length<P: CurvePoint>(point: P) -> P::Scalar {
+    (0..P::DIMENSION)
+        .map(|i| point.component(i) * point.component(i))
+        .fold(P::Scalar::zero(), |sum, x| sum + x)
+        .sqrt()
+}
@@ -0,0 +1,117 @@
+use super::bspline::BSpline;
+use crate::error::check_shape;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::vector_4::Vector4;
+use crate::linear_algebra::vector::{Orientation, Row};
+use crate::num::Real;
+
+use alloc::vec::Vec;
+
+/// A non-uniform rational B-spline in 3D space.
+///
+/// Control points are homogeneous `Vector4`s `(w x, w y, w z, w)` with weight `w`, so the curve
+/// is a B-spline in 4D projected back by dividing by its last component. This represents conic
+/// sections such as circles exactly.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Nurbs<T: Real, O: Orientation = Row> {
+    spline: BSpline<Vector4<T, O>>,
+}
+
+impl<T: Real, O: Orientation> Nurbs<T, O> {
+    /// Takes homogeneous control points, with the same requirements as [`BSpline::new`].
+    pub fn new(degree: usize, knots: Vec<T>, points: Vec<Vector4<T, O>>) -> Self {
+        Self {
+            spline: BSpline::new(degree, knots, points),
+        }
+    }
+    pub fn try_new(
+        degree: usize,
+        knots: Vec<T>,
+        points: Vec<Vector4<T, O>>,
+    ) -> Result<Self, crate::Error> {
+        Ok(Self {
+            spline: BSpline::try_new(degree, knots, points)?,
+        })
+    }
+    /// Weights 3D control points into homogeneous ones.
+    ///
+    /// Panics if there is not one weight per control point.
+    pub fn from_weighted(
+        degree: usize,
+        knots: Vec<T>,
+        points: Vec<Vector3<T, O>>,
+        weights: Vec<T>,
+    ) -> Self {
+        Self::try_from_weighted(degree, knots, points, weights)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_from_weighted(
+        degree: usize,
+        knots: Vec<T>,
+        points: Vec<Vector3<T, O>>,
+        weights: Vec<T>,
+    ) -> Result<Self, crate::Error> {
+        check_shape(points.len(), weights.len())?;
+        let points = points
+            .into_iter()
+            .zip(weights)
+            .map(|(point, weight)| {
+                let [x, y, z] = point.storage;
+                Vector4::from_array([x * weight, y * weight, z * weight, weight])
+            })
+            .collect();
+        Self::try_new(degree, knots, points)
+    }
+    /// The B-spline over the homogeneous control points.
+    pub fn spline(&self) -> &BSpline<Vector4<T, O>> {
+        &self.spline
+    }
+    pub fn domain(&self) -> (T, T) {
+        self.spline.domain()
+    }
+    pub fn evaluate(&self, t: T) -> Vector3<T, O> {
+        let [x, y, z, w] = self.spline.evaluate(t).storage;
+        Vector3::from_array([x / w, y / w, z / w])
+    }
+    /// The derivative with respect to `t`, from the quotient rule on the homogeneous curve.
+    pub fn derivative(&self, t: T) -> Vector3<T, O> {
+        let [_, _, _, w] = self.spline.evaluate(t).storage;
+        let [dx, dy, dz, dw] = self.spline.derivative().evaluate(t).storage;
+        let point = self.evaluate(t);
+        Vector3::from_array([dx, dy, dz]).zip_map(point, |d, p| (d - dw * p) / w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::f64::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn circle() {
+        // A quarter of the unit circle as a rational quadratic curve.
+        let arc = Nurbs::from_weighted(
+            2,
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            vec![
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(1.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+            ],
+            vec![1.0, FRAC_1_SQRT_2, 1.0],
+        );
+        assert_eq!(arc.evaluate(0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(arc.evaluate(1.0), Vector3::new(0.0, 1.0, 0.0));
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let point = arc.evaluate(t);
+            assert!((point.length() - 1.0).abs() < 1e-12);
+            // The tangent of a circle is perpendicular to the radius.
+            assert!(arc.derivative(t).dot(point).abs() < 1e-12);
+        }
+        let middle = arc.evaluate(0.5);
+        assert!((middle.storage[0] - FRAC_1_SQRT_2).abs() < 1e-12);
+        assert!((middle.storage[1] - FRAC_1_SQRT_2).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,20 @@
+//! Geometric primitives in 3D space with closest point queries, distances and intersection tests,
+//! robust algorithms on 2D points and polygons, and parametric curves.
+//!
+//! Solid primitives count points inside them as their own closest point, so distances to those
+//! points are zero.
+
+pub mod aabb;
+pub mod capsule;
+#[cfg(feature = "alloc")]
+pub mod curve;
+pub mod obb;
+pub mod plane;
+#[cfg(feature = "alloc")]
+pub mod polygon;
+#[cfg(feature = "alloc")]
+pub mod predicates;
+pub mod ray;
+pub mod segment;
+pub mod sphere;
+pub mod triangle;
@@ -0,0 +1,73 @@
+use super::aabb::Aabb;
+use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// An oriented box around `center`, extending `half_extents[i]` in both directions along the
+/// unit length, mutually orthogonal `axes[i]`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Obb<T> {
+    pub center: Vector3<T>,
+    pub axes: [Vector3<T>; 3],
+    pub half_extents: Vector3<T>,
+}
+
+impl<T: Real> Obb<T> {
+    pub fn new(center: Vector3<T>, axes: [Vector3<T>; 3], half_extents: Vector3<T>) -> Self {
+        Self {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+    /// The box `aabb` rotated around its center by a rotation matrix acting on column vectors.
+    pub fn from_aabb(aabb: &Aabb<T>, rotation: &Matrix3x3<T>) -> Self {
+        let columns = rotation.transpose().into_rows();
+        Self::new(
+            aabb.center(),
+            columns.map(Vector3::from_array),
+            aabb.half_extents(),
+        )
+    }
+    /// The coordinates of `point` along the axes, relative to the center.
+    fn local(&self, point: Vector3<T>) -> [T; 3] {
+        let offset = point - self.center;
+        self.axes.map(|axis| offset.dot(axis))
+    }
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let local = self.local(point);
+        (0..3).all(|i| local[i].abs() <= self.half_extents.storage[i])
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let local = self.local(point);
+        (0..3).fold(self.center, |closest, i| {
+            let extent = self.half_extents.storage[i];
+            closest + self.axes[i] * local[i].clamp(-extent, extent)
+        })
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.closest_point(point) - point).length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries() {
+        let aabb = Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 2.0, 3.0));
+        let quarter_turn =
+            Matrix3x3::from_rows([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        let obb = Obb::from_aabb(&aabb, &quarter_turn);
+        assert_eq!(obb.axes[0], Vector3::new(0.0, 1.0, 0.0));
+        assert!(obb.contains_point(Vector3::new(2.0, 1.0, 3.0)));
+        assert!(!obb.contains_point(Vector3::new(1.0, 2.0, 0.0)));
+        assert_eq!(
+            obb.closest_point(Vector3::new(5.0, 5.0, 0.0)),
+            Vector3::new(2.0, 1.0, 0.0)
+        );
+        assert_eq!(obb.distance_to_point(Vector3::new(2.0, 4.0, 7.0)), 5.0);
+        assert_eq!(obb.distance_to_point(Vector3::new(0.5, 0.5, 0.5)), 0.0);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// The plane of points `p` with `normal.dot(p) == distance`, where `normal` has unit length.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+impl<T: Real> Plane<T> {
+    /// Builds a plane from a normal that does not need to be normalized, scaling `distance` by
+    /// the same factor.
+    pub fn new(normal: Vector3<T>, distance: T) -> Self {
+        let length = normal.length();
+        Self {
+            normal: normal / length,
+            distance: distance / length,
+        }
+    }
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let normal = normal.normalize();
+        Self {
+            normal,
+            distance: normal.dot(point),
+        }
+    }
+    /// The plane through three points, facing the side from which they appear counterclockwise.
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a))
+    }
+    /// The distance of `point` from the plane, negative behind it.
+    pub fn signed_distance(&self, point: Vector3<T>) -> T {
+        self.normal.dot(point) - self.distance
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        point + self.normal * -self.signed_distance(point)
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        self.signed_distance(point).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let plane = Plane::from_points(
+            Vector3::new(0.0, 0.0, 2.0),
+            Vector3::new(1.0, 0.0, 2.0),
+            Vector3::new(0.0, 1.0, 2.0),
+        );
+        assert_eq!(plane, Plane::new(Vector3::new(0.0, 0.0, 3.0), 6.0));
+        assert_eq!(plane.signed_distance(Vector3::new(5.0, 5.0, -1.0)), -3.0);
+        assert_eq!(plane.distance_to_point(Vector3::new(5.0, 5.0, -1.0)), 3.0);
+        assert_eq!(
+            plane.closest_point(Vector3::new(5.0, 5.0, 7.0)),
+            Vector3::new(5.0, 5.0, 2.0)
+        );
+    }
+}
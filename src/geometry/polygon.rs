@@ -0,0 +1,506 @@
+//! Algorithms on 2D point sets, polylines and simple polygons given as slices of vertices.
+//!
+//! Every decision that depends on the relative position of points goes through the exact
+//! predicates in `predicates`, so results are consistent even for degenerate input.
+
+use super::predicates::orient2d;
+use crate::linear_algebra::vector::vector_2::Vector2;
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SegmentIntersection {
+    None,
+    Point(Vector2<f64>),
+    /// Collinear segments sharing the part between two points.
+    Overlap(Vector2<f64>, Vector2<f64>),
+}
+
+fn edges(polygon: &[Vector2<f64>]) -> impl Iterator<Item = (Vector2<f64>, Vector2<f64>)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+fn cross(a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    a.storage[0] * b.storage[1] - a.storage[1] * b.storage[0]
+}
+
+fn lexicographic(a: &Vector2<f64>, b: &Vector2<f64>) -> Ordering {
+    a.storage[0]
+        .total_cmp(&b.storage[0])
+        .then(a.storage[1].total_cmp(&b.storage[1]))
+}
+
+/// The area of a polygon, positive if its vertices are in counterclockwise order.
+pub fn signed_area(polygon: &[Vector2<f64>]) -> f64 {
+    edges(polygon).map(|(a, b)| cross(a, b)).sum::<f64>() / 2.0
+}
+
+pub fn area(polygon: &[Vector2<f64>]) -> f64 {
+    signed_area(polygon).abs()
+}
+
+/// The order of the vertices of a polygon, or `None` if it has no area.
+pub fn winding(polygon: &[Vector2<f64>]) -> Option<Winding> {
+    let area = signed_area(polygon);
+    if area > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else if area < 0.0 {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// The center of mass of a polygon, or `None` if it has no area.
+pub fn centroid(polygon: &[Vector2<f64>]) -> Option<Vector2<f64>> {
+    let area = signed_area(polygon);
+    if area == 0.0 {
+        return None;
+    }
+    let (x, y) = edges(polygon).fold((0.0, 0.0), |(x, y), (a, b)| {
+        let cross = cross(a, b);
+        (
+            x + (a.storage[0] + b.storage[0]) * cross,
+            y + (a.storage[1] + b.storage[1]) * cross,
+        )
+    });
+    Some(Vector2::new(x / (6.0 * area), y / (6.0 * area)))
+}
+
+fn on_segment(a: Vector2<f64>, b: Vector2<f64>, point: Vector2<f64>) -> bool {
+    orient2d(a, b, point) == 0.0
+        && (0..2).all(|i| {
+            let (min, max) = if a.storage[i] < b.storage[i] {
+                (a.storage[i], b.storage[i])
+            } else {
+                (b.storage[i], a.storage[i])
+            };
+            min <= point.storage[i] && point.storage[i] <= max
+        })
+}
+
+/// Whether `point` lies inside or on the boundary of a polygon, by its winding number.
+pub fn contains_point(polygon: &[Vector2<f64>], point: Vector2<f64>) -> bool {
+    let y = point.storage[1];
+    let mut winding_number = 0;
+    for (a, b) in edges(polygon) {
+        if on_segment(a, b, point) {
+            return true;
+        }
+        if a.storage[1] <= y {
+            if b.storage[1] > y && orient2d(a, b, point) > 0.0 {
+                winding_number += 1;
+            }
+        } else if b.storage[1] <= y && orient2d(a, b, point) < 0.0 {
+            winding_number -= 1;
+        }
+    }
+    winding_number != 0
+}
+
+/// The convex hull in counterclockwise order starting from the leftmost, lowest point, without
+/// collinear points. Computed with Andrew's monotone chain algorithm.
+pub fn convex_hull(points: &[Vector2<f64>]) -> Vec<Vector2<f64>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(lexicographic);
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<Vector2<f64>> = Vec::with_capacity(sorted.len() + 1);
+    let push = |hull: &mut Vec<Vector2<f64>>, minimum: usize, point| {
+        while hull.len() >= minimum
+            && orient2d(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    };
+    for &point in &sorted {
+        push(&mut hull, 2, point);
+    }
+    let lower = hull.len();
+    for &point in sorted.iter().rev().skip(1) {
+        push(&mut hull, lower + 1, point);
+    }
+    // The upper chain ends at the first point of the lower chain.
+    hull.pop();
+    hull
+}
+
+/// Where the segments `a0`-`a1` and `b0`-`b1` meet, including at their endpoints. Collinear
+/// segments report the part they share.
+pub fn segment_intersection(
+    a0: Vector2<f64>,
+    a1: Vector2<f64>,
+    b0: Vector2<f64>,
+    b1: Vector2<f64>,
+) -> SegmentIntersection {
+    let (d0, d1) = (orient2d(b0, b1, a0), orient2d(b0, b1, a1));
+    let (d2, d3) = (orient2d(a0, a1, b0), orient2d(a0, a1, b1));
+    if d0 == 0.0 && d1 == 0.0 && d2 == 0.0 && d3 == 0.0 {
+        return collinear_intersection(a0, a1, b0, b1);
+    }
+    if d0 * d1 > 0.0 || d2 * d3 > 0.0 {
+        return SegmentIntersection::None;
+    }
+    // The segments are not collinear, so `a0` and `a1` cannot both lie on the line through
+    // `b`, and after the test above `d0` and `d1` differ.
+    let t = d0 / (d0 - d1);
+    SegmentIntersection::Point(a0.zip_map(a1, |start, end| start + (end - start) * t))
+}
+
+fn collinear_intersection(
+    a0: Vector2<f64>,
+    a1: Vector2<f64>,
+    b0: Vector2<f64>,
+    b1: Vector2<f64>,
+) -> SegmentIntersection {
+    let ordered = |start: Vector2<f64>, end: Vector2<f64>| match lexicographic(&start, &end) {
+        Ordering::Greater => (end, start),
+        _ => (start, end),
+    };
+    let ((a_min, a_max), (b_min, b_max)) = (ordered(a0, a1), ordered(b0, b1));
+    let start = core::cmp::max_by(a_min, b_min, lexicographic);
+    let end = core::cmp::min_by(a_max, b_max, lexicographic);
+    match lexicographic(&start, &end) {
+        Ordering::Greater => SegmentIntersection::None,
+        Ordering::Equal => SegmentIntersection::Point(start),
+        Ordering::Less => SegmentIntersection::Overlap(start, end),
+    }
+}
+
+fn distance_to_segment(point: Vector2<f64>, a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    let direction = b.zip_map(a, |b, a| b - a);
+    let offset = point.zip_map(a, |point, a| point - a);
+    let length_squared = direction.length_squared();
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (offset.dot(direction) / length_squared).clamp(0.0, 1.0)
+    };
+    offset
+        .zip_map(direction, |offset, direction| offset - direction * t)
+        .length()
+}
+
+/// Simplifies a polyline with the Ramer–Douglas–Peucker algorithm, keeping its endpoints and
+/// removing points closer than `tolerance` to the simplified line.
+pub fn simplify(polyline: &[Vector2<f64>], tolerance: f64) -> Vec<Vector2<f64>> {
+    if polyline.len() < 3 {
+        return polyline.to_vec();
+    }
+    let mut keep = vec![false; polyline.len()];
+    keep[0] = true;
+    keep[polyline.len() - 1] = true;
+    let mut ranges = vec![(0, polyline.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| {
+                (
+                    i,
+                    distance_to_segment(polyline[i], polyline[start], polyline[end]),
+                )
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((index, distance)) = farthest {
+            if distance > tolerance {
+                keep[index] = true;
+                ranges.push((start, index));
+                ranges.push((index, end));
+            }
+        }
+    }
+    polyline
+        .iter()
+        .zip(keep)
+        .filter_map(|(&point, keep)| keep.then_some(point))
+        .collect()
+}
+
+/// Triangulates a simple polygon by ear clipping, returning counterclockwise triangles of
+/// vertex indices, or `None` if the polygon has no area or is not simple.
+pub fn triangulate(polygon: &[Vector2<f64>]) -> Option<Vec<[usize; 3]>> {
+    let order: Vec<usize> = match winding(polygon)? {
+        Winding::CounterClockwise => (0..polygon.len()).collect(),
+        Winding::Clockwise => (0..polygon.len()).rev().collect(),
+    };
+    let count = order.len();
+    // The remaining vertices form a doubly linked ring over positions in `order`.
+    let mut next: Vec<usize> = (0..count).map(|i| (i + 1) % count).collect();
+    let mut previous: Vec<usize> = (0..count).map(|i| (i + count - 1) % count).collect();
+    let mut triangles = Vec::with_capacity(count - 2);
+    let (mut remaining, mut current, mut misses) = (count, 0, 0);
+    while remaining > 3 {
+        let corner = [previous[current], current, next[current]];
+        let [a, b, c] = corner.map(|i| polygon[order[i]]);
+        let mut others = core::iter::successors(Some(next[corner[2]]), |&i| Some(next[i]))
+            .take_while(|&i| i != corner[0]);
+        let is_ear = orient2d(a, b, c) > 0.0
+            && others.all(|i| {
+                let point = polygon[order[i]];
+                orient2d(a, b, point) < 0.0
+                    || orient2d(b, c, point) < 0.0
+                    || orient2d(c, a, point) < 0.0
+            });
+        if is_ear {
+            triangles.push(corner.map(|i| order[i]));
+            let [before, _, after] = corner;
+            next[before] = after;
+            previous[after] = before;
+            remaining -= 1;
+            // Clipping only changes the neighbours' corners, so resume the search there.
+            current = before;
+            misses = 0;
+        } else {
+            misses += 1;
+            if misses > remaining {
+                return None;
+            }
+            current = next[current];
+        }
+    }
+    triangles.push([previous[current], current, next[current]].map(|i| order[i]));
+    Some(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coordinates: &[[f64; 2]]) -> Vec<Vector2<f64>> {
+        coordinates
+            .iter()
+            .map(|&point| Vector2::from_array(point))
+            .collect()
+    }
+
+    #[test]
+    fn measures() {
+        let square = points(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+        assert_eq!(signed_area(&square), 4.0);
+        assert_eq!(winding(&square), Some(Winding::CounterClockwise));
+        assert_eq!(centroid(&square), Some(Vector2::new(1.0, 1.0)));
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(signed_area(&reversed), -4.0);
+        assert_eq!(area(&reversed), 4.0);
+        assert_eq!(winding(&reversed), Some(Winding::Clockwise));
+        assert_eq!(centroid(&reversed), Some(Vector2::new(1.0, 1.0)));
+
+        let l_shape = points(&[
+            [0.0, 0.0],
+            [3.0, 0.0],
+            [3.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 3.0],
+            [0.0, 3.0],
+        ]);
+        assert_eq!(area(&l_shape), 5.0);
+        assert_eq!(centroid(&l_shape), Some(Vector2::new(1.1, 1.1)));
+        assert_eq!(
+            winding(&points(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])),
+            None
+        );
+    }
+
+    #[test]
+    fn point_in_polygon() {
+        let l_shape = points(&[
+            [0.0, 0.0],
+            [3.0, 0.0],
+            [3.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 3.0],
+            [0.0, 3.0],
+        ]);
+        assert!(contains_point(&l_shape, Vector2::new(0.5, 2.5)));
+        assert!(contains_point(&l_shape, Vector2::new(2.5, 0.5)));
+        assert!(!contains_point(&l_shape, Vector2::new(2.0, 2.0)));
+        assert!(contains_point(&l_shape, Vector2::new(2.0, 1.0)));
+        assert!(contains_point(&l_shape, Vector2::new(1.0, 3.0)));
+        assert!(!contains_point(&l_shape, Vector2::new(-1.0, 1.0)));
+        assert!(!contains_point(&l_shape, Vector2::new(4.0, 1.0)));
+    }
+
+    #[test]
+    fn hull() {
+        let cloud = points(&[
+            [1.0, 1.0],
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+            [1.0, 2.0],
+            [0.5, 1.5],
+            [2.0, 2.0],
+        ]);
+        assert_eq!(
+            convex_hull(&cloud),
+            points(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]])
+        );
+        assert_eq!(
+            convex_hull(&points(&[[1.0, 1.0], [0.0, 0.0], [2.0, 2.0]])),
+            points(&[[0.0, 0.0], [2.0, 2.0]])
+        );
+        assert_eq!(
+            convex_hull(&points(&[[1.0, 1.0], [1.0, 1.0]])),
+            points(&[[1.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn segments() {
+        let point = |x, y| Vector2::new(x, y);
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(2.0, 2.0),
+                point(0.0, 2.0),
+                point(2.0, 0.0)
+            ),
+            SegmentIntersection::Point(point(1.0, 1.0))
+        );
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(2.0, 2.0),
+                point(2.0, 2.0),
+                point(3.0, 0.0)
+            ),
+            SegmentIntersection::Point(point(2.0, 2.0))
+        );
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(0.5, 0.5),
+                point(0.0, 2.0),
+                point(2.0, 0.0)
+            ),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(3.0, 0.0),
+                point(4.0, 0.0),
+                point(2.0, 0.0)
+            ),
+            SegmentIntersection::Overlap(point(2.0, 0.0), point(3.0, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(3.0, 0.0),
+                point(4.0, 0.0),
+                point(3.0, 0.0)
+            ),
+            SegmentIntersection::Point(point(3.0, 0.0))
+        );
+        assert_eq!(
+            segment_intersection(
+                point(0.0, 0.0),
+                point(3.0, 0.0),
+                point(4.0, 0.0),
+                point(5.0, 0.0)
+            ),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            segment_intersection(
+                point(1.0, 0.0),
+                point(1.0, 0.0),
+                point(0.0, 0.0),
+                point(2.0, 0.0)
+            ),
+            SegmentIntersection::Point(point(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn simplification() {
+        let polyline = points(&[
+            [0.0, 0.0],
+            [1.0, 0.1],
+            [2.0, -0.1],
+            [3.0, 5.0],
+            [4.0, 6.2],
+            [5.0, 7.0],
+            [6.0, 8.1],
+            [7.0, 9.0],
+        ]);
+        assert_eq!(
+            simplify(&polyline, 0.5),
+            points(&[[0.0, 0.0], [2.0, -0.1], [3.0, 5.0], [7.0, 9.0]])
+        );
+        assert_eq!(
+            simplify(&polyline, 100.0),
+            points(&[[0.0, 0.0], [7.0, 9.0]])
+        );
+        assert_eq!(simplify(&polyline, 0.0).len(), polyline.len());
+    }
+
+    #[test]
+    fn triangulation() {
+        let l_shape = points(&[
+            [0.0, 0.0],
+            [3.0, 0.0],
+            [3.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 3.0],
+            [0.0, 3.0],
+        ]);
+        for polygon in [l_shape.clone(), l_shape.iter().rev().copied().collect()] {
+            let triangles = triangulate(&polygon).unwrap();
+            assert_eq!(triangles.len(), 4);
+            let mut total = 0.0;
+            for triangle in triangles {
+                let corners = triangle.map(|index| polygon[index]);
+                assert!(orient2d(corners[0], corners[1], corners[2]) > 0.0);
+                total += signed_area(&corners);
+                // No triangle may cover the notch of the L.
+                assert!(!contains_point(&corners, Vector2::new(2.0, 2.0)));
+            }
+            assert_eq!(total, 5.0);
+        }
+        assert_eq!(
+            triangulate(&points(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]])),
+            None
+        );
+        // A comb with many teeth, each of which is an ear.
+        let teeth = 200;
+        let mut comb = vec![
+            Vector2::new(0.0, -1.0),
+            Vector2::new(2.0 * teeth as f64, -1.0),
+        ];
+        for tooth in (0..teeth).rev() {
+            let x = 2.0 * tooth as f64;
+            comb.extend([
+                Vector2::new(x + 1.5, 0.0),
+                Vector2::new(x + 1.0, 1.0),
+                Vector2::new(x + 0.5, 0.0),
+            ]);
+        }
+        let triangles = triangulate(&comb).unwrap();
+        assert_eq!(triangles.len(), comb.len() - 2);
+        let total: f64 = triangles
+            .iter()
+            .map(|triangle| signed_area(&triangle.map(|index| comb[index])))
+            .sum();
+        assert!((total - signed_area(&comb)).abs() < 1e-9);
+        // A self-intersecting bow tie has no ears.
+        let bow_tie = points(&[[0.0, 0.0], [2.0, 2.0], [2.0, 0.0], [0.0, 2.0]]);
+        assert_eq!(triangulate(&bow_tie), None);
+    }
+}
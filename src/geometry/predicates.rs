@@ -0,0 +1,211 @@
+//! Robust geometric predicates for `f64` points.
+//!
+//! Each predicate first evaluates its determinant in floating point and checks it against an
+//! error bound. Only when the sign is uncertain is the determinant recomputed exactly with
+//! expansion arithmetic, following Shewchuk, "Adaptive Precision Floating-Point Arithmetic and
+//! Fast Robust Geometric Predicates", 1997. The sign of the result is always exact.
+
+use crate::linear_algebra::vector::vector_2::Vector2;
+
+use alloc::vec::Vec;
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Twice the signed area of the triangle `a`, `b`, `c`: positive if the points are in
+/// counterclockwise order, negative if clockwise and zero if collinear.
+pub fn orient2d(a: Vector2<f64>, b: Vector2<f64>, c: Vector2<f64>) -> f64 {
+    let [ax, ay] = a.storage;
+    let [bx, by] = b.storage;
+    let [cx, cy] = c.storage;
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let determinant = left - right;
+    if determinant.abs() > ORIENT_BOUND * (left.abs() + right.abs()) {
+        return determinant;
+    }
+    let left = Expansion::difference(ax, cx).mul(&Expansion::difference(by, cy));
+    let right = Expansion::difference(ay, cy).mul(&Expansion::difference(bx, cx));
+    left.sub(&right).estimate()
+}
+
+/// Positive if `d` lies inside the circle through the counterclockwise points `a`, `b`, `c`,
+/// negative if outside and zero if on the circle. The sign is reversed for clockwise points.
+pub fn incircle(a: Vector2<f64>, b: Vector2<f64>, c: Vector2<f64>, d: Vector2<f64>) -> f64 {
+    let [dx, dy] = d.storage;
+    let [adx, ady] = [a.storage[0] - dx, a.storage[1] - dy];
+    let [bdx, bdy] = [b.storage[0] - dx, b.storage[1] - dy];
+    let [cdx, cdy] = [c.storage[0] - dx, c.storage[1] - dy];
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let determinant =
+        alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if determinant.abs() > INCIRCLE_BOUND * permanent {
+        return determinant;
+    }
+
+    let difference = |point: Vector2<f64>| {
+        (
+            Expansion::difference(point.storage[0], dx),
+            Expansion::difference(point.storage[1], dy),
+        )
+    };
+    let (adx, ady) = difference(a);
+    let (bdx, bdy) = difference(b);
+    let (cdx, cdy) = difference(c);
+    let lift = |x: &Expansion, y: &Expansion| x.mul(x).add(&y.mul(y));
+    let cross = |x1: &Expansion, y1: &Expansion, x2: &Expansion, y2: &Expansion| {
+        x1.mul(y2).sub(&x2.mul(y1))
+    };
+    lift(&adx, &ady)
+        .mul(&cross(&bdx, &bdy, &cdx, &cdy))
+        .add(&lift(&bdx, &bdy).mul(&cross(&cdx, &cdy, &adx, &ady)))
+        .add(&lift(&cdx, &cdy).mul(&cross(&adx, &ady, &bdx, &bdy)))
+        .estimate()
+}
+
+/// `a + b` as a rounded sum and its exact rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Splits `a` into two halves of 26 significant bits each.
+fn split(a: f64) -> (f64, f64) {
+    let c = 134217729.0 * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+/// `a * b` as a rounded product and its exact rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = a_low * b_low - (((product - a_high * b_high) - a_low * b_high) - a_high * b_low);
+    (product, error)
+}
+
+/// An exact sum of non-overlapping floating point components in increasing magnitude.
+#[derive(Clone, Debug)]
+struct Expansion(Vec<f64>);
+
+impl Expansion {
+    fn difference(a: f64, b: f64) -> Self {
+        Self(Vec::new()).grow(a).grow(-b)
+    }
+    /// Adds a single component, eliminating zero components.
+    fn grow(self, value: f64) -> Self {
+        let mut components = Vec::with_capacity(self.0.len() + 1);
+        let mut sum = value;
+        for component in self.0 {
+            let (rounded, error) = two_sum(sum, component);
+            if error != 0.0 {
+                components.push(error);
+            }
+            sum = rounded;
+        }
+        if sum != 0.0 {
+            components.push(sum);
+        }
+        Self(components)
+    }
+    fn add(&self, other: &Self) -> Self {
+        other.0.iter().fold(self.clone(), |sum, &x| sum.grow(x))
+    }
+    fn sub(&self, other: &Self) -> Self {
+        other.0.iter().fold(self.clone(), |sum, &x| sum.grow(-x))
+    }
+    fn mul(&self, other: &Self) -> Self {
+        let mut product = Self(Vec::new());
+        for &a in &self.0 {
+            for &b in &other.0 {
+                let (rounded, error) = two_product(a, b);
+                product = product.grow(error).grow(rounded);
+            }
+        }
+        product
+    }
+    /// The largest component, which has the sign of the whole expansion.
+    fn estimate(&self) -> f64 {
+        self.0.last().copied().unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientation() {
+        let (a, b) = (Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+        assert_eq!(orient2d(a, b, Vector2::new(0.0, 1.0)), 1.0);
+        assert_eq!(orient2d(a, b, Vector2::new(0.0, -1.0)), -1.0);
+        assert_eq!(orient2d(a, b, Vector2::new(5.0, 0.0)), 0.0);
+
+        // Points on the line y = x near 0.5, where the naive determinant gets the sign wrong.
+        let (b, c) = (Vector2::new(12.0, 12.0), Vector2::new(24.0, 24.0));
+        let mut wrong = 0;
+        for i in 0..256 {
+            for j in 0..256 {
+                let a = Vector2::new(0.5 + i as f64 * f64::EPSILON, 0.5 + j as f64 * f64::EPSILON);
+                let naive = (a.storage[0] - c.storage[0]) * (b.storage[1] - c.storage[1])
+                    - (a.storage[1] - c.storage[1]) * (b.storage[0] - c.storage[0]);
+                let exact = orient2d(a, b, c);
+                if i == j {
+                    assert_eq!(exact, 0.0);
+                } else {
+                    assert_eq!(exact.signum(), (j as f64 - i as f64).signum());
+                }
+                if naive.signum() != exact.signum() || (naive == 0.0) != (exact == 0.0) {
+                    wrong += 1;
+                }
+            }
+        }
+        assert!(wrong > 0);
+    }
+
+    #[test]
+    fn circle() {
+        let (a, b, c) = (
+            Vector2::new(1.0, 0.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(-1.0, 0.0),
+        );
+        assert!(incircle(a, b, c, Vector2::new(0.0, 0.0)) > 0.0);
+        assert!(incircle(a, b, c, Vector2::new(2.0, 0.0)) < 0.0);
+        assert_eq!(incircle(a, b, c, Vector2::new(0.0, -1.0)), 0.0);
+        assert!(incircle(c, b, a, Vector2::new(0.0, 0.0)) < 0.0);
+
+        // Nearly cocircular points far from the origin.
+        let offset = 1e9;
+        let shift = |x: f64, y: f64| Vector2::new(x + offset, y + offset);
+        assert_eq!(
+            incircle(
+                shift(1.0, 0.0),
+                shift(0.0, 1.0),
+                shift(-1.0, 0.0),
+                shift(0.0, -1.0)
+            ),
+            0.0
+        );
+        assert!(
+            incircle(
+                shift(1.0, 0.0),
+                shift(0.0, 1.0),
+                shift(-1.0, 0.0),
+                Vector2::new(offset, offset - 1.0 + 2.0f64.powi(-22))
+            ) > 0.0
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use super::aabb::Aabb;
+use super::plane::Plane;
+use super::sphere::Sphere;
+use super::triangle::Triangle;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// A half-line starting at `origin`, with points `origin + direction * t` for `t >= 0`.
+///
+/// Intersection tests return the smallest such `t`, which is zero when the origin lies inside a
+/// solid.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+impl<T: Real> Ray<T> {
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self { origin, direction }
+    }
+    pub fn at(&self, t: T) -> Vector3<T> {
+        self.origin + self.direction * t
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let t = (point - self.origin).dot(self.direction) / self.direction.length_squared();
+        self.at(t.max(T::zero()))
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.closest_point(point) - point).length()
+    }
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denominator = plane.normal.dot(self.direction);
+        if denominator.abs() <= T::epsilon() {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denominator;
+        (t >= T::zero()).then_some(t)
+    }
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<T> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.length_squared();
+        let b = offset.dot(self.direction);
+        let c = offset.length_squared() - sphere.radius * sphere.radius;
+        if c <= T::zero() {
+            return Some(T::zero());
+        }
+        let discriminant = b * b - a * c;
+        if b > T::zero() || discriminant < T::zero() {
+            return None;
+        }
+        Some((-b - discriminant.sqrt()) / a)
+    }
+    /// Möller–Trumbore intersection with either side of the triangle.
+    pub fn intersect_triangle(&self, triangle: &Triangle<T>) -> Option<T> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if determinant.abs() <= T::epsilon() {
+            return None;
+        }
+        let inverse_determinant = T::one() / determinant;
+        let s = self.origin - triangle.a;
+        let u = s.dot(p) * inverse_determinant;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+        let t = edge2.dot(q) * inverse_determinant;
+        (t >= T::zero()).then_some(t)
+    }
+    /// Slab intersection with the box.
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<T> {
+        let mut near = T::zero();
+        let mut far = T::from_f64(f64::INFINITY);
+        for axis in 0..3 {
+            let origin = self.origin.storage[axis];
+            let direction = self.direction.storage[axis];
+            let (min, max) = (aabb.min.storage[axis], aabb.max.storage[axis]);
+            if direction.abs() <= T::epsilon() {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+            let inverse = T::one() / direction;
+            let (t1, t2) = ((min - origin) * inverse, (max - origin) * inverse);
+            let (t1, t2) = if t1 > t2 { (t2, t1) } else { (t1, t2) };
+            near = near.max(t1);
+            far = far.min(t2);
+            if near > far {
+                return None;
+            }
+        }
+        Some(near)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ray() -> Ray<f64> {
+        Ray::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 2.0))
+    }
+
+    #[test]
+    fn closest_point() {
+        assert_eq!(ray().at(1.0), Vector3::new(0.0, 0.0, -3.0));
+        assert_eq!(
+            ray().closest_point(Vector3::new(3.0, 0.0, 1.0)),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+        assert_eq!(ray().distance_to_point(Vector3::new(0.0, 4.0, -8.0)), 5.0);
+    }
+
+    #[test]
+    fn intersections() {
+        let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+        assert_eq!(ray().intersect_sphere(&sphere), Some(2.0));
+        let inside = Ray::new(Vector3::new(0.0, 0.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(inside.intersect_sphere(&sphere), Some(0.0));
+        let away = Ray::new(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(away.intersect_sphere(&sphere), None);
+
+        let plane = Plane::new(Vector3::new(0.0, 0.0, 1.0), 1.0);
+        assert_eq!(ray().intersect_plane(&plane), Some(3.0));
+        assert_eq!(away.intersect_plane(&plane), None);
+
+        let triangle = Triangle::new(
+            Vector3::new(-1.0, -1.0, 0.0),
+            Vector3::new(1.0, -1.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert_eq!(ray().intersect_triangle(&triangle), Some(2.5));
+        let beside = Ray::new(Vector3::new(2.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(beside.intersect_triangle(&triangle), None);
+        let parallel = Ray::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(parallel.intersect_triangle(&triangle), None);
+
+        let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+        assert_eq!(ray().intersect_aabb(&aabb), Some(2.0));
+        assert_eq!(inside.intersect_aabb(&aabb), Some(0.0));
+        assert_eq!(beside.intersect_aabb(&aabb), None);
+        assert_eq!(away.intersect_aabb(&aabb), None);
+        let diagonal = Ray::new(Vector3::new(-3.0, -3.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+        assert_eq!(diagonal.intersect_aabb(&aabb), Some(2.0));
+    }
+}
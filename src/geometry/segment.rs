@@ -0,0 +1,111 @@
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+/// The line segment between `start` and `end`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Segment<T> {
+    pub start: Vector3<T>,
+    pub end: Vector3<T>,
+}
+
+impl<T: Real> Segment<T> {
+    pub fn new(start: Vector3<T>, end: Vector3<T>) -> Self {
+        Self { start, end }
+    }
+    pub fn length(&self) -> T {
+        (self.start - self.end).length()
+    }
+    /// The point `start + (end - start) * t`.
+    pub fn at(&self, t: T) -> Vector3<T> {
+        self.start + (self.end - self.start) * t
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let direction = self.end - self.start;
+        let length_squared = direction.length_squared();
+        if length_squared <= T::epsilon() {
+            return self.start;
+        }
+        let t = (point - self.start).dot(direction) / length_squared;
+        self.at(t.clamp(T::zero(), T::one()))
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.closest_point(point) - point).length()
+    }
+    /// The closest pair of points, on this segment and on `other` respectively.
+    pub fn closest_points(&self, other: &Segment<T>) -> (Vector3<T>, Vector3<T>) {
+        // Ericson, "Real-Time Collision Detection", section 5.1.9.
+        let (zero, one) = (T::zero(), T::one());
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(r);
+        let (s, t) = if a <= T::epsilon() && e <= T::epsilon() {
+            (zero, zero)
+        } else if a <= T::epsilon() {
+            (zero, (f / e).clamp(zero, one))
+        } else {
+            let c = d1.dot(r);
+            if e <= T::epsilon() {
+                ((-c / a).clamp(zero, one), zero)
+            } else {
+                let b = d1.dot(d2);
+                let denominator = a * e - b * b;
+                let s = if denominator > zero {
+                    ((b * f - c * e) / denominator).clamp(zero, one)
+                } else {
+                    zero
+                };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    ((-c / a).clamp(zero, one), zero)
+                } else if t > one {
+                    (((b - c) / a).clamp(zero, one), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+    pub fn distance_to_segment(&self, other: &Segment<T>) -> T {
+        let (a, b) = self.closest_points(other);
+        (a - b).length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_point() {
+        let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+        assert_eq!(segment.length(), 4.0);
+        assert_eq!(
+            segment.closest_point(Vector3::new(1.0, 2.0, 0.0)),
+            Vector3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(segment.distance_to_point(Vector3::new(7.0, 4.0, 0.0)), 5.0);
+        assert_eq!(segment.distance_to_point(Vector3::new(-1.0, 0.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn closest_points() {
+        let segment = Segment::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0));
+        let crossing = Segment::new(Vector3::new(1.0, -1.0, 2.0), Vector3::new(1.0, 1.0, 2.0));
+        assert_eq!(
+            segment.closest_points(&crossing),
+            (Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 2.0))
+        );
+        let parallel = Segment::new(Vector3::new(6.0, 3.0, 0.0), Vector3::new(9.0, 3.0, 0.0));
+        assert_eq!(
+            segment.closest_points(&parallel),
+            (Vector3::new(4.0, 0.0, 0.0), Vector3::new(6.0, 3.0, 0.0))
+        );
+        let point = Segment::new(Vector3::new(2.0, 3.0, 0.0), Vector3::new(2.0, 3.0, 0.0));
+        assert_eq!(segment.distance_to_segment(&point), 3.0);
+        assert_eq!(point.distance_to_segment(&segment), 3.0);
+    }
+}
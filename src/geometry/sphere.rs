@@ -0,0 +1,61 @@
+use super::aabb::Aabb;
+use super::plane::Plane;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Sphere<T> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+impl<T: Real> Sphere<T> {
+    pub fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        if self.contains_point(point) {
+            return point;
+        }
+        self.center + (point - self.center).normalize() * self.radius
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        ((point - self.center).length() - self.radius).max(T::zero())
+    }
+    pub fn intersects_sphere(&self, other: &Sphere<T>) -> bool {
+        let radius = self.radius + other.radius;
+        (self.center - other.center).length_squared() <= radius * radius
+    }
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        aabb.intersects_sphere(self)
+    }
+    pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+        plane.distance_to_point(self.center) <= self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries() {
+        let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 2.0);
+        assert!(sphere.contains_point(Vector3::new(3.0, 0.0, 0.0)));
+        assert!(!sphere.contains_point(Vector3::new(3.0, 0.1, 0.0)));
+        assert_eq!(
+            sphere.closest_point(Vector3::new(1.0, 5.0, 0.0)),
+            Vector3::new(1.0, 2.0, 0.0)
+        );
+        assert_eq!(sphere.distance_to_point(Vector3::new(1.0, 0.0, -5.0)), 3.0);
+        assert_eq!(sphere.distance_to_point(Vector3::new(1.0, 0.0, 1.0)), 0.0);
+
+        assert!(sphere.intersects_sphere(&Sphere::new(Vector3::new(4.0, 0.0, 0.0), 1.0)));
+        assert!(!sphere.intersects_sphere(&Sphere::new(Vector3::new(4.0, 0.0, 0.0), 0.9)));
+        assert!(sphere.intersects_plane(&Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0)));
+        assert!(!sphere.intersects_plane(&Plane::new(Vector3::new(0.0, 1.0, 0.0), -2.5)));
+    }
+}
@@ -0,0 +1,105 @@
+use super::aabb::Aabb;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::num::Real;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Triangle<T> {
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub c: Vector3<T>,
+}
+
+impl<T: Real> Triangle<T> {
+    pub fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self { a, b, c }
+    }
+    /// The unit normal, facing the side from which the vertices appear counterclockwise.
+    pub fn normal(&self) -> Vector3<T> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a).length() / (T::one() + T::one())
+    }
+    pub fn centroid(&self) -> Vector3<T> {
+        (self.a + self.b + self.c) / (T::one() + T::one() + T::one())
+    }
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        // Ericson, "Real-Time Collision Detection", section 5.1.5.
+        let zero = T::zero();
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = point - self.a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= zero && d2 <= zero {
+            return self.a;
+        }
+        let bp = point - self.b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= zero && d4 <= d3 {
+            return self.b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return self.a + ab * (d1 / (d1 - d3));
+        }
+        let cp = point - self.c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= zero && d5 <= d6 {
+            return self.c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return self.a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return self.b + (self.c - self.b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denominator = T::one() / (va + vb + vc);
+        self.a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+    pub fn distance_to_point(&self, point: Vector3<T>) -> T {
+        (self.closest_point(point) - point).length()
+    }
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        aabb.intersects_triangle(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_point() {
+        let triangle = Triangle::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(4.0, 0.0, 0.0),
+            Vector3::new(0.0, 4.0, 0.0),
+        );
+        assert_eq!(triangle.normal(), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(triangle.area(), 8.0);
+        let cases = [
+            ([1.0, 1.0, 3.0], [1.0, 1.0, 0.0]),
+            ([-1.0, -1.0, 0.0], [0.0, 0.0, 0.0]),
+            ([6.0, -1.0, 0.0], [4.0, 0.0, 0.0]),
+            ([-1.0, 6.0, 0.0], [0.0, 4.0, 0.0]),
+            ([2.0, -3.0, 1.0], [2.0, 0.0, 0.0]),
+            ([-3.0, 2.0, 0.0], [0.0, 2.0, 0.0]),
+            ([3.0, 3.0, 0.0], [2.0, 2.0, 0.0]),
+        ];
+        for (point, closest) in cases {
+            assert_eq!(
+                triangle.closest_point(Vector3::from_array(point)),
+                Vector3::from_array(closest)
+            );
+        }
+        assert_eq!(
+            triangle.distance_to_point(Vector3::new(-3.0, -4.0, 0.0)),
+            5.0
+        );
+    }
+}
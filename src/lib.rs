@@ -1,3 +1,16 @@
-#![cfg_attr(feature = "nightly", feature(stmt_expr_attributes))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(stmt_expr_attributes, portable_simd))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod error;
+pub mod geometry;
 pub mod linear_algebra;
+#[cfg(feature = "nightly")]
+mod nightly;
+pub mod num;
+#[cfg(test)]
+mod test_util;
+
+pub use error::Error;
@@ -0,0 +1,143 @@
+macro_rules! componentwise_methods {
+    (signed) => {
+        pub fn abs(self) -> Self {
+            self.map(|x| x.abs())
+        }
+        pub fn signum(self) -> Self {
+            self.map(|x| x.signum())
+        }
+    };
+    (real) => {
+        pub fn floor(self) -> Self {
+            self.map(|x| x.floor())
+        }
+        pub fn ceil(self) -> Self {
+            self.map(|x| x.ceil())
+        }
+        pub fn round(self) -> Self {
+            self.map(|x| x.round())
+        }
+        pub fn sqrt(self) -> Self {
+            self.map(|x| x.sqrt())
+        }
+        pub fn powf(self, n: T) -> Self {
+            self.map(|x| x.powf(n))
+        }
+        /// Linear interpolation from `self` at `t = 0` to `other` at `t = 1`.
+        pub fn lerp(self, other: Self, t: T) -> Self {
+            self.zip_map(other, |a, b| a + (b - a) * t)
+        }
+        /// Hermite interpolation of every element from 0 at `edge0` to 1 at `edge1`, as in GLSL.
+        pub fn smoothstep(self, edge0: T, edge1: T) -> Self {
+            let (zero, one) = (T::zero(), T::one());
+            self.map(|x| {
+                let t = (x - edge0) / (edge1 - edge0);
+                let t = if t < zero {
+                    zero
+                } else if t > one {
+                    one
+                } else {
+                    t
+                };
+                t * t * (one + one + one - (one + one) * t)
+            })
+        }
+    };
+    (ordered) => {
+        /// The smaller of each pair of corresponding elements.
+        pub fn min(self, other: Self) -> Self {
+            self.zip_map(other, |a, b| if b < a { b } else { a })
+        }
+        /// The larger of each pair of corresponding elements.
+        pub fn max(self, other: Self) -> Self {
+            self.zip_map(other, |a, b| if b > a { b } else { a })
+        }
+        /// Clamps every element between `min` and `max`.
+        pub fn clamp(self, min: T, max: T) -> Self {
+            self.map(|x| {
+                if x < min {
+                    min
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            })
+        }
+    };
+}
+
+/// Adds element-wise math functions built on `map` and `zip_map`, and reductions over
+/// `as_slice`. Matrices report element positions as `(row, column)`.
+macro_rules! componentwise_impl {
+    (vector $vector:ident) => {
+        impl<T: $crate::num::Signed + Copy, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(signed);
+        }
+        impl<T: $crate::num::Real, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(real);
+        }
+        impl<T: Copy + PartialOrd, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(ordered);
+            $crate::linear_algebra::componentwise::componentwise_impl!(@reductions usize, |_: &Self, index: usize| index);
+        }
+    };
+    (matrix $matrix:ident, $columns:expr) => {
+        impl<T: $crate::num::Signed + Copy> $matrix<T> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(signed);
+        }
+        impl<T: $crate::num::Real> $matrix<T> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(real);
+        }
+        impl<T: Copy + PartialOrd> $matrix<T> {
+            $crate::linear_algebra::componentwise::componentwise_methods!(ordered);
+            $crate::linear_algebra::componentwise::componentwise_impl!(
+                @reductions (usize, usize),
+                |matrix: &Self, index: usize| {
+                    let columns = ($columns)(matrix);
+                    (index / columns, index % columns)
+                }
+            );
+        }
+    };
+    (@reductions $position:ty, $to_position:expr) => {
+        pub fn sum(&self) -> T
+        where
+            T: $crate::num::Num,
+        {
+            self.as_slice().iter().fold(T::zero(), |sum, &x| sum + x)
+        }
+        pub fn product(&self) -> T
+        where
+            T: $crate::num::Num,
+        {
+            self.as_slice().iter().fold(T::one(), |product, &x| product * x)
+        }
+        pub fn min_element(&self) -> T {
+            self.as_slice()[self.position_by(|a, b| a < b)]
+        }
+        pub fn max_element(&self) -> T {
+            self.as_slice()[self.position_by(|a, b| a > b)]
+        }
+        /// The position of the first smallest element.
+        pub fn argmin(&self) -> $position {
+            ($to_position)(self, self.position_by(|a, b| a < b))
+        }
+        /// The position of the first largest element.
+        pub fn argmax(&self) -> $position {
+            ($to_position)(self, self.position_by(|a, b| a > b))
+        }
+        fn position_by(&self, better: impl Fn(&T, &T) -> bool) -> usize {
+            let elements = self.as_slice();
+            (1..elements.len()).fold(0, |best, i| {
+                if better(&elements[i], &elements[best]) {
+                    i
+                } else {
+                    best
+                }
+            })
+        }
+    };
+}
+pub(crate) use componentwise_impl;
+pub(crate) use componentwise_methods;
@@ -0,0 +1,307 @@
+use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+use crate::linear_algebra::quaternion::Quaternion;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::Orientation;
+use crate::num::Real;
+
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Add, Mul, Neg};
+
+/// A dual quaternion `real + ε dual` representing a rigid transform.
+///
+/// A rotation `r` followed by a translation `t` is stored as `real = r` and `dual = t r / 2`,
+/// with `t` as a pure quaternion. Like matrices acting on column vectors, `a * b` applies `b`
+/// first.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DualQuaternion<T> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    pub const fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+}
+
+impl<T: Real> DualQuaternion<T> {
+    pub fn identity() -> Self {
+        Self::new(
+            Quaternion::identity(),
+            Quaternion::new(T::zero(), T::zero(), T::zero(), T::zero()),
+        )
+    }
+    /// The transform rotating by the unit quaternion `rotation`, then translating by
+    /// `translation`.
+    pub fn from_rotation_translation<O: Orientation>(
+        rotation: Quaternion<T>,
+        translation: Vector3<T, O>,
+    ) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        Self::new(
+            rotation,
+            Quaternion::from_parts(T::zero(), translation) * rotation * half,
+        )
+    }
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self::from_rotation_translation(rotation, Vector3::new(T::zero(), T::zero(), T::zero()))
+    }
+    pub fn from_translation<O: Orientation>(translation: Vector3<T, O>) -> Self {
+        Self::from_rotation_translation(Quaternion::identity(), translation)
+    }
+    /// The rotation of a normalized dual quaternion.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+    /// The translation of a normalized dual quaternion.
+    pub fn translation(&self) -> Vector3<T> {
+        (self.dual * self.real.conjugate() * (T::one() + T::one())).vector()
+    }
+    pub fn conjugate(self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate())
+    }
+    /// Returns the multiplicative inverse, or `None` if the real part is zero.
+    pub fn inverse(self) -> Option<Self> {
+        let real = self.real.inverse()?;
+        Some(Self::new(real, -(real * self.dual * real)))
+    }
+    /// Scales the real part to unit length and makes the dual part orthogonal to it, so the dual
+    /// quaternion represents a rigid transform.
+    pub fn normalize(self) -> Self {
+        let norm = self.real.norm();
+        let real = self.real * (T::one() / norm);
+        let dual = self.dual * (T::one() / norm);
+        Self::new(real, dual - real * real.dot(dual))
+    }
+    pub fn transform_point<O: Orientation>(&self, point: Vector3<T, O>) -> Vector3<T, O> {
+        let [x, y, z] = self.translation().storage;
+        let rotated = self.real.rotate(point);
+        Vector3::from_array([
+            rotated.storage[0] + x,
+            rotated.storage[1] + y,
+            rotated.storage[2] + z,
+        ])
+    }
+    /// Transforms a direction, which is only rotated.
+    pub fn transform_vector<O: Orientation>(&self, vector: Vector3<T, O>) -> Vector3<T, O> {
+        self.real.rotate(vector)
+    }
+    pub fn to_matrix4x4(&self) -> Matrix4x4<T> {
+        let mut rows = self.real.to_matrix4x4().into_rows();
+        for (row, translation) in rows.iter_mut().zip(self.translation().storage) {
+            row[3] = translation;
+        }
+        Matrix4x4::from_rows(rows)
+    }
+    /// The rigid transform of a matrix acting on column vectors, ignoring any scale or
+    /// projection.
+    pub fn from_matrix4x4(matrix: &Matrix4x4<T>) -> Self {
+        let m = matrix.as_rows();
+        Self::from_rotation_translation(
+            Quaternion::from_matrix4x4(matrix),
+            Vector3::new(m[0][3], m[1][3], m[2][3]),
+        )
+    }
+    /// Raises a normalized dual quaternion to the power `t` by scaling the angle and distance
+    /// of its screw motion.
+    pub fn powf(self, t: T) -> Self {
+        let two = T::one() + T::one();
+        let sin = self.real.vector().length();
+        if sin <= T::epsilon() {
+            // A pure translation, whose screw axis is undefined.
+            return Self::new(Quaternion::identity(), self.dual * t);
+        }
+        let axis = self.real.vector() / sin;
+        let angle = two * sin.atan2(self.real.w);
+        let pitch = -two * self.dual.w / sin;
+        let moment = self.dual.vector().zip_map(axis, |dual, axis| {
+            (dual - axis * pitch / two * self.real.w) / sin
+        });
+
+        let (angle, pitch) = (angle * t, pitch * t);
+        let (sin, cos) = (angle / two).sin_cos();
+        Self::new(
+            Quaternion::from_parts(cos, axis * sin),
+            Quaternion::from_parts(
+                -pitch / two * sin,
+                moment.zip_map(axis, |moment, axis| moment * sin + axis * pitch / two * cos),
+            ),
+        )
+    }
+    /// Screw linear interpolation, moving at constant speed along the screw motion from `self`
+    /// at `t = 0` to `other` at `t = 1`, both normalized.
+    pub fn sclerp(self, other: Self, t: T) -> Self {
+        let other = if self.real.dot(other.real) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        self * (self.conjugate() * other).powf(t)
+    }
+    /// Dual quaternion linear blending of normalized transforms, as used for skinning.
+    ///
+    /// Each transform is flipped onto the hemisphere of the first one before the weighted sum is
+    /// normalized. Returns `None` if there are no transforms or the weights cancel out.
+    pub fn blend(weighted: &[(Self, T)]) -> Option<Self> {
+        let ((first, _), _) = weighted.split_first()?;
+        let sum = weighted.iter().fold(
+            Self::new(first.real * T::zero(), first.dual * T::zero()),
+            |sum, &(transform, weight)| {
+                let weight = if first.real.dot(transform.real) < T::zero() {
+                    -weight
+                } else {
+                    weight
+                };
+                sum + transform * weight
+            },
+        );
+        if sum.real.norm_squared() <= T::epsilon() {
+            return None;
+        }
+        Some(sum.normalize())
+    }
+}
+
+impl<T: Real> Add for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl<T: Real> Neg for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.real, -self.dual)
+    }
+}
+
+impl<T: Real> Mul for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+
+impl<T: Real> Mul<T> for DualQuaternion<T> {
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.real * rhs, self.dual * rhs)
+    }
+}
+
+impl<T: Display> Display for DualQuaternion<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(fmt, "({}) + ε({})", self.real, self.dual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_transform_close(a: DualQuaternion<f64>, b: DualQuaternion<f64>) {
+        let (a, b) = (a.to_matrix4x4(), b.to_matrix4x4());
+        assert!(
+            a.as_slice()
+                .iter()
+                .zip(b.as_slice())
+                .all(|(a, b)| (a - b).abs() < 1e-12),
+            "{a} is not close to {b}"
+        );
+    }
+
+    fn z_axis() -> Vector3<f64> {
+        Vector3::new(0.0, 0.0, 1.0)
+    }
+
+    #[test]
+    fn transforms() {
+        let rotation = Quaternion::from_axis_angle(z_axis(), FRAC_PI_2);
+        let transform =
+            DualQuaternion::from_rotation_translation(rotation, Vector3::new(1.0, 2.0, 3.0));
+        assert_close(transform.translation(), Vector3::new(1.0, 2.0, 3.0));
+        assert_close(
+            transform.transform_point(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(1.0, 3.0, 3.0),
+        );
+        assert_close(
+            transform.transform_vector(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+
+        let other = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0), 1.0),
+            Vector3::new(-2.0, 0.5, 0.0),
+        );
+        let point = Vector3::new(0.3, -0.7, 2.0);
+        assert_close(
+            (transform * other).transform_point(point),
+            transform.transform_point(other.transform_point(point)),
+        );
+        assert_transform_close(
+            DualQuaternion::from_matrix4x4(&(transform.to_matrix4x4() * other.to_matrix4x4())),
+            transform * other,
+        );
+        assert_transform_close(
+            transform * transform.inverse().unwrap(),
+            DualQuaternion::identity(),
+        );
+        assert_transform_close(transform.inverse().unwrap(), transform.conjugate());
+        assert_transform_close((transform * 3.0).normalize(), transform);
+    }
+
+    #[test]
+    fn sclerp() {
+        let start = DualQuaternion::from_translation(Vector3::new(1.0, 0.0, 0.0));
+        let end = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(z_axis(), PI),
+            Vector3::new(-1.0, 0.0, 4.0),
+        );
+        // A screw around the Z axis: half a turn while rising 4 units.
+        let halfway = start.sclerp(end, 0.5);
+        assert_close(halfway.translation(), Vector3::new(0.0, 1.0, 2.0));
+        assert_close(
+            halfway.transform_vector(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert_transform_close(start.sclerp(end, 0.0), start);
+        assert_transform_close(start.sclerp(end, 1.0), end);
+        assert_transform_close(start.sclerp(-end, 0.5), halfway);
+
+        let translation = DualQuaternion::from_translation(Vector3::new(2.0, 4.0, 0.0));
+        assert_close(
+            DualQuaternion::identity()
+                .sclerp(translation, 0.25)
+                .translation(),
+            Vector3::new(0.5, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn blend() {
+        let a = DualQuaternion::from_translation(Vector3::new(0.0, 1.0, 0.0));
+        let b = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(z_axis(), FRAC_PI_2),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        let blended = DualQuaternion::blend(&[(a, 0.5), (-b, 0.5)]).unwrap();
+        assert_close(
+            blended.transform_vector(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(FRAC_PI_4.cos(), FRAC_PI_4.sin(), 0.0),
+        );
+        assert_close(blended.translation(), Vector3::new(0.0, 1.0, 0.0));
+        assert_transform_close(DualQuaternion::blend(&[(b, 2.0)]).unwrap(), b);
+        assert_eq!(DualQuaternion::<f64>::blend(&[]), None);
+    }
+}
@@ -0,0 +1,381 @@
+use core::fmt::{Alignment, Display, Error, Formatter, LowerExp, UpperExp, Write};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DisplayStyle {
+    /// Rows between box drawing brackets.
+    Unicode,
+    /// Every row between `[` and `]`, using only ASCII characters.
+    Ascii,
+    /// The whole matrix on a single line, as in `[[1, 2], [3, 4]]`.
+    Compact,
+}
+
+/// Configurable pretty-printer shared by every matrix type.
+///
+/// Columns are aligned to their widest element, and the format spec of the surrounding
+/// `format!` call (precision, sign, width, fill and alignment) is applied to every element
+/// rather than to the matrix as a whole. Matrices with more than `max_rows` rows or
+/// `max_columns` columns have their middle elided.
+#[derive(Copy, Clone, Debug)]
+pub struct MatrixDisplay<'a, T> {
+    elements: &'a [T],
+    columns: usize,
+    style: DisplayStyle,
+    max_rows: usize,
+    max_columns: usize,
+}
+
+type ElementFormatter<T> = fn(&T, &mut dyn Write, Option<usize>, bool) -> Result<(), Error>;
+
+macro_rules! element_formatter {
+    ($name:ident, $trait:ident, $spec:literal, $precision_spec:literal, $plus_spec:literal, $plus_precision_spec:literal) => {
+        fn $name<T: $trait>(
+            element: &T,
+            writer: &mut dyn Write,
+            precision: Option<usize>,
+            plus: bool,
+        ) -> Result<(), Error> {
+            match (precision, plus) {
+                (None, false) => write!(writer, $spec, element),
+                (Some(precision), false) => write!(writer, $precision_spec, precision, element),
+                (None, true) => write!(writer, $plus_spec, element),
+                (Some(precision), true) => write!(writer, $plus_precision_spec, precision, element),
+            }
+        }
+    };
+}
+
+element_formatter!(display, Display, "{}", "{:.*}", "{:+}", "{:+.*}");
+element_formatter!(lower_exp, LowerExp, "{:e}", "{:.*e}", "{:+e}", "{:+.*e}");
+element_formatter!(upper_exp, UpperExp, "{:E}", "{:.*E}", "{:+E}", "{:+.*E}");
+
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl<'a, T> MatrixDisplay<'a, T> {
+    /// Displays `elements` as a row-major matrix with `columns` columns. Panics if the elements
+    /// do not fill whole rows.
+    pub fn new(elements: &'a [T], columns: usize) -> Self {
+        Self::try_new(elements, columns).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_new(elements: &'a [T], columns: usize) -> Result<Self, crate::Error> {
+        if columns == 0 || !elements.len().is_multiple_of(columns) {
+            return Err(crate::Error::InvalidArgument(
+                "Elements do not fill whole rows",
+            ));
+        }
+        Ok(Self {
+            elements,
+            columns,
+            style: DisplayStyle::Unicode,
+            max_rows: 12,
+            max_columns: 12,
+        })
+    }
+    pub fn style(mut self, style: DisplayStyle) -> Self {
+        self.style = style;
+        self
+    }
+    pub fn ascii(self) -> Self {
+        self.style(DisplayStyle::Ascii)
+    }
+    pub fn compact(self) -> Self {
+        self.style(DisplayStyle::Compact)
+    }
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows.max(1);
+        self
+    }
+    pub fn max_columns(mut self, max_columns: usize) -> Self {
+        self.max_columns = max_columns.max(1);
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.elements.len() / self.columns
+    }
+
+    /// The marker written in place of elided elements; ASCII output marks elided rows with `:` so
+    /// that they do not widen every column.
+    fn ellipsis(&self, column: Option<usize>) -> &'static str {
+        match (self.style, column) {
+            (DisplayStyle::Ascii, None) => "...",
+            (DisplayStyle::Ascii, Some(_)) => ":",
+            (DisplayStyle::Unicode | DisplayStyle::Compact, _) => "…",
+        }
+    }
+
+    /// Maps the visible positions along one axis to indices, with `None` standing for the
+    /// elided middle.
+    fn visible(count: usize, max: usize) -> impl Iterator<Item = Option<usize>> {
+        let (head, tail) = if count > max {
+            (max - max / 2, max / 2)
+        } else {
+            (count, 0)
+        };
+        (0..head)
+            .map(Some)
+            .chain((count > max).then_some(None))
+            .chain((count - tail..count).map(Some))
+    }
+
+    fn cell_width(
+        &self,
+        row: Option<usize>,
+        column: Option<usize>,
+        format: ElementFormatter<T>,
+        fmt: &Formatter<'_>,
+    ) -> Result<usize, Error> {
+        match (row, column) {
+            (Some(row), Some(column)) => {
+                let mut counter = Counter(0);
+                format(
+                    &self.elements[row * self.columns + column],
+                    &mut counter,
+                    fmt.precision(),
+                    fmt.sign_plus(),
+                )?;
+                Ok(counter.0)
+            }
+            _ => Ok(self.ellipsis(column).chars().count()),
+        }
+    }
+
+    fn column_width(
+        &self,
+        column: Option<usize>,
+        format: ElementFormatter<T>,
+        fmt: &Formatter<'_>,
+    ) -> Result<usize, Error> {
+        let mut width = fmt.width().unwrap_or(0);
+        for row in Self::visible(self.rows(), self.max_rows) {
+            width = width.max(self.cell_width(row, column, format, fmt)?);
+        }
+        Ok(width)
+    }
+
+    fn write_cell(
+        &self,
+        row: Option<usize>,
+        column: Option<usize>,
+        width: usize,
+        format: ElementFormatter<T>,
+        fmt: &mut Formatter<'_>,
+    ) -> Result<(), Error> {
+        let padding = width.saturating_sub(self.cell_width(row, column, format, fmt)?);
+        let (before, after) = match fmt.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+        let fill = fmt.fill();
+        for _ in 0..before {
+            fmt.write_char(fill)?;
+        }
+        match (row, column) {
+            (Some(row), Some(column)) => {
+                let (precision, plus) = (fmt.precision(), fmt.sign_plus());
+                format(
+                    &self.elements[row * self.columns + column],
+                    fmt,
+                    precision,
+                    plus,
+                )?;
+            }
+            _ => fmt.write_str(self.ellipsis(column))?,
+        }
+        for _ in 0..after {
+            fmt.write_char(fill)?;
+        }
+        Ok(())
+    }
+
+    fn write_row(
+        &self,
+        row: Option<usize>,
+        aligned: bool,
+        format: ElementFormatter<T>,
+        fmt: &mut Formatter<'_>,
+    ) -> Result<(), Error> {
+        let separator = if self.style == DisplayStyle::Compact {
+            ", "
+        } else {
+            " "
+        };
+        for (i, column) in Self::visible(self.columns, self.max_columns).enumerate() {
+            if i > 0 {
+                fmt.write_str(separator)?;
+            }
+            let width = if aligned {
+                self.column_width(column, format, fmt)?
+            } else {
+                fmt.width().unwrap_or(0)
+            };
+            self.write_cell(row, column, width, format, fmt)?;
+        }
+        Ok(())
+    }
+
+    fn inner_width(
+        &self,
+        format: ElementFormatter<T>,
+        fmt: &Formatter<'_>,
+    ) -> Result<usize, Error> {
+        let mut width = 0;
+        for (i, column) in Self::visible(self.columns, self.max_columns).enumerate() {
+            width += self.column_width(column, format, fmt)? + usize::from(i > 0);
+        }
+        Ok(width)
+    }
+
+    fn write(&self, format: ElementFormatter<T>, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let rows = Self::visible(self.rows(), self.max_rows);
+        match self.style {
+            DisplayStyle::Unicode => {
+                let width = self.inner_width(format, fmt)?;
+                writeln!(fmt, "┌ {:width$} ┐", "")?;
+                for row in rows {
+                    fmt.write_str("│ ")?;
+                    self.write_row(row, true, format, fmt)?;
+                    fmt.write_str(" │\n")?;
+                }
+                write!(fmt, "└ {:width$} ┘", "")
+            }
+            DisplayStyle::Ascii => {
+                for (i, row) in rows.enumerate() {
+                    if i > 0 {
+                        fmt.write_char('\n')?;
+                    }
+                    fmt.write_str("[ ")?;
+                    self.write_row(row, true, format, fmt)?;
+                    fmt.write_str(" ]")?;
+                }
+                Ok(())
+            }
+            DisplayStyle::Compact => {
+                fmt.write_char('[')?;
+                for (i, row) in rows.enumerate() {
+                    if i > 0 {
+                        fmt.write_str(", ")?;
+                    }
+                    match row {
+                        Some(_) => {
+                            fmt.write_char('[')?;
+                            self.write_row(row, false, format, fmt)?;
+                            fmt.write_char(']')?;
+                        }
+                        None => fmt.write_str(self.ellipsis(None))?,
+                    }
+                }
+                fmt.write_char(']')
+            }
+        }
+    }
+}
+
+impl<T: Display> Display for MatrixDisplay<'_, T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        self.write(display, fmt)
+    }
+}
+
+impl<T: LowerExp> LowerExp for MatrixDisplay<'_, T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        self.write(lower_exp, fmt)
+    }
+}
+
+impl<T: UpperExp> UpperExp for MatrixDisplay<'_, T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        self.write(upper_exp, fmt)
+    }
+}
+
+macro_rules! display_impl {
+    ($matrix:ident) => {
+        impl<T: ::core::fmt::Display> ::core::fmt::Display for $matrix<T> {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+                ::core::fmt::Display::fmt(&self.display(), fmt)
+            }
+        }
+        impl<T: ::core::fmt::LowerExp> ::core::fmt::LowerExp for $matrix<T> {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+                ::core::fmt::LowerExp::fmt(&self.display(), fmt)
+            }
+        }
+        impl<T: ::core::fmt::UpperExp> ::core::fmt::UpperExp for $matrix<T> {
+            fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+                ::core::fmt::UpperExp::fmt(&self.display(), fmt)
+            }
+        }
+    };
+}
+pub(crate) use display_impl;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode() {
+        let elements = [1, 20, -3, 4];
+        assert_eq!(
+            format!("{}", MatrixDisplay::new(&elements, 2)),
+            "┌       ┐\n│  1 20 │\n│ -3  4 │\n└       ┘"
+        );
+    }
+
+    #[test]
+    fn format_spec() {
+        let elements = [1.0, 2.5, 30.0, 4.125];
+        assert_eq!(
+            format!("{:.2}", MatrixDisplay::new(&elements, 2).ascii()),
+            "[  1.00 2.50 ]\n[ 30.00 4.12 ]"
+        );
+        assert_eq!(
+            format!("{:<6}", MatrixDisplay::new(&elements, 2).ascii()),
+            "[ 1      2.5    ]\n[ 30     4.125  ]"
+        );
+        assert_eq!(
+            format!("{:e}", MatrixDisplay::new(&elements, 2).compact()),
+            "[[1e0, 2.5e0], [3e1, 4.125e0]]"
+        );
+        assert_eq!(
+            format!("{:+}", MatrixDisplay::new(&elements[..2], 2).compact()),
+            "[[+1, +2.5]]"
+        );
+        assert!(MatrixDisplay::try_new(&elements, 3).is_err());
+        assert!(MatrixDisplay::try_new(&elements, 0).is_err());
+    }
+
+    #[test]
+    fn elision() {
+        let elements = (0..36).collect::<Vec<_>>();
+        assert_eq!(
+            format!(
+                "{}",
+                MatrixDisplay::new(&elements, 6)
+                    .max_rows(3)
+                    .max_columns(4)
+                    .ascii()
+            ),
+            "[  0  1 ...  4  5 ]\n[  6  7 ... 10 11 ]\n[  :  : ...  :  : ]\n[ 30 31 ... 34 35 ]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                MatrixDisplay::new(&elements, 6)
+                    .max_rows(2)
+                    .max_columns(2)
+                    .compact()
+            ),
+            "[[0, …, 5], …, [30, …, 35]]"
+        );
+    }
+}
@@ -1,83 +1,81 @@
+use super::{adjoint_impl, display_impl, echelon_impl, layout_impl, product_impl};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
 use crate::linear_algebra::vector::vector_2::Vector2;
-use crate::linear_algebra::vector::VectorType;
+use crate::linear_algebra::vector::{Orientation, VectorType};
+use crate::num::Num;
 
-use std::fmt::{Display, Error, Formatter};
+use core::ops::Neg;
 
+/// A 2x2 matrix.
+///
+/// Elements are stored row-major with the layout of `[[T; 2]; 2]`, so the matrix can be handed
+/// directly to APIs expecting 4 contiguous elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct Matrix2x2<T> {
     vectors: [Vector2<T>; 2],
 }
 
 impl<T> Matrix2x2<T> {
-    pub fn new(v1: Vector2<T>, v2: Vector2<T>) -> Self {
-        assert_eq!(v1.vector_type, v2.vector_type);
-        Self { vectors: [v1, v2] }
+    /// Builds a matrix from its rows, or from its columns when given column vectors.
+    pub fn new<O: Orientation>(v1: Vector2<T, O>, v2: Vector2<T, O>) -> Self {
+        let matrix = Self::from_rows([v1.storage, v2.storage]);
+        match O::VECTOR_TYPE {
+            VectorType::Row => matrix,
+            VectorType::Column => matrix.transpose(),
+        }
+    }
+    pub fn transpose(self) -> Self {
+        let [[a, b], [c, d]] = self.into_rows();
+        Self::from_rows([[a, c], [b, d]])
     }
 }
 
-impl<T: Display> Display for Matrix2x2<T> {
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
-        fmt.write_str("┌─\n")?;
-        fmt.write_str("│")?;
-        if self.vectors[0].vector_type == VectorType::Row {
-            fmt.write_str(
-                format!(
-                    "{} {}\n",
-                    self.vectors[0].storage[0], self.vectors[0].storage[1]
-                )
-                .as_str(),
-            )?;
-            fmt.write_str(
-                format!(
-                    " {} {}│\n",
-                    self.vectors[1].storage[0], self.vectors[1].storage[1]
-                )
-                .as_str(),
-            )?;
-        } else {
-            fmt.write_str(
-                format!(
-                    "{} {}\n",
-                    self.vectors[0].storage[0], self.vectors[1].storage[0]
-                )
-                .as_str(),
-            )?;
-            fmt.write_str(
-                format!(
-                    " {} {}│\n",
-                    self.vectors[0].storage[1], self.vectors[1].storage[1]
-                )
-                .as_str(),
-            )?;
+impl<T: Num + Neg<Output = T> + Copy> Matrix2x2<T> {
+    pub fn determinant(&self) -> T {
+        let [[a, b], [c, d]] = *self.as_rows();
+        a * d - b * c
+    }
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant.is_zero() {
+            return None;
         }
-        let length = {
-            let first = format!(
-                " {} {}\n",
-                self.vectors[1].storage[0], self.vectors[1].storage[1]
-            )
-            .len();
-            let second = format!(
-                " {} {}\n",
-                self.vectors[0].storage[0], self.vectors[1].storage[0]
-            )
-            .len();
-            if first > second {
-                first
-            } else {
-                second
-            }
-        };
-        fmt.write_str(format!("{}─┘", " ".repeat(length - 2)).as_str())?;
-
-        Ok(())
+        let [[a, b], [c, d]] = *self.as_rows();
+        let inverse_determinant = T::one() / determinant;
+        Some(Self::from_rows([[d, -b], [-c, a]]).map(|x| x * inverse_determinant))
+    }
+    pub fn try_inverse(&self) -> Result<Self, crate::Error> {
+        self.inverse().ok_or(crate::Error::SingularMatrix)
     }
 }
 
+layout_impl!(Matrix2x2, Vector2, 2);
+
+product_impl!(Matrix2x2, Vector2, 2);
+
+display_impl!(Matrix2x2);
+
+adjoint_impl!(Matrix2x2);
+
+echelon_impl!(Matrix2x2, |_: &Self| 2);
+
+#[cfg(feature = "alloc")]
+render_impl!(matrix Matrix2x2, |_: &Self| 2);
+
+componentwise_impl!(matrix Matrix2x2, |_: &Self| 2);
+
+overflow_impl!(matrix Matrix2x2, |_: &Self| 2);
+
 macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Matrix2x2<T> {
+                impl<T: Copy + ::core::ops::$op<T, Output = T>> ::core::ops::$op<T> for Matrix2x2<T> {
                     type Output = Matrix2x2<T>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
@@ -97,14 +95,14 @@ cfg_if::cfg_if! {
         macro_rules! matrix2x2 {
             ($i1:tt $i2:tt; $i3:tt $i4:tt) => {
                 #[allow(unused_parens)]
-                Matrix2x2::new(Vector2::new($i1, $i2, VectorType::Row), Vector2::new($i3, $i4, VectorType::Row))
+                Matrix2x2::new(Vector2::new($i1, $i2), Vector2::new($i3, $i4))
             }
         }
     } else {
         #[macro_export]
         macro_rules! matrix2x2 {
             ($i1:tt $i2:tt; $i3:tt $i4:tt) => {
-                Matrix2x2::new(Vector2::new($i1, $i2, VectorType::Row), Vector2::new($i3, $i4, VectorType::Row))
+                Matrix2x2::new(Vector2::new($i1, $i2), Vector2::new($i3, $i4))
             }
         }
     }
@@ -113,70 +111,175 @@ cfg_if::cfg_if! {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linear_algebra::vector::VectorType;
 
     #[test]
     fn operators() {
-        let matrix = Matrix2x2::new(
-            Vector2::new(10, 20, VectorType::Row),
-            Vector2::new(30, 40, VectorType::Row),
-        );
+        let matrix = Matrix2x2::new(Vector2::new(10, 20), Vector2::new(30, 40));
         assert_eq!(
             matrix + 10,
-            Matrix2x2::new(
-                Vector2::new(20, 30, VectorType::Row),
-                Vector2::new(40, 50, VectorType::Row)
-            )
+            Matrix2x2::new(Vector2::new(20, 30), Vector2::new(40, 50))
         );
         assert_eq!(
             matrix - 10,
-            Matrix2x2::new(
-                Vector2::new(0, 10, VectorType::Row),
-                Vector2::new(20, 30, VectorType::Row)
-            )
+            Matrix2x2::new(Vector2::new(0, 10), Vector2::new(20, 30))
         );
         assert_eq!(
             matrix * 10,
-            Matrix2x2::new(
-                Vector2::new(100, 200, VectorType::Row),
-                Vector2::new(300, 400, VectorType::Row)
-            )
+            Matrix2x2::new(Vector2::new(100, 200), Vector2::new(300, 400))
         );
         assert_eq!(
             matrix / 10,
-            Matrix2x2::new(
-                Vector2::new(1, 2, VectorType::Row),
-                Vector2::new(3, 4, VectorType::Row)
-            )
+            Matrix2x2::new(Vector2::new(1, 2), Vector2::new(3, 4))
         );
     }
 
     #[test]
+    #[allow(unused_parens)]
     fn matrix_macro() {
         assert_eq!(
             matrix2x2!((20 - 10) 20; 30 40),
-            Matrix2x2::new(
-                Vector2::new(10, 20, VectorType::Row),
-                Vector2::new(30, 40, VectorType::Row)
-            )
+            Matrix2x2::new(Vector2::new(10, 20), Vector2::new(30, 40))
         );
     }
 
     #[test]
-    #[should_panic]
-    fn diff_vector_types() {
-        Matrix2x2::new(
-            Vector2::new(10, 20, VectorType::Row),
-            Vector2::new(10, 20, VectorType::Column),
+    fn column_vectors() {
+        assert_eq!(
+            Matrix2x2::new(Vector2::new_column(10, 30), Vector2::new_column(20, 40)),
+            matrix2x2!(10 20; 30 40)
         );
     }
 
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Matrix2x2<f32>>(), 4 * 4);
+        let mut matrix = matrix2x2!(1 2; 3 4);
+        assert_eq!(matrix.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(Matrix2x2::from_slice(matrix.as_slice()), matrix);
+        matrix.as_mut_slice()[1] = 5;
+        assert_eq!(matrix, matrix2x2!(1 5; 3 4));
+        assert_eq!(matrix.transpose(), matrix2x2!(1 3; 5 4));
+    }
+
     #[test]
     fn display() {
-        let matrix = Matrix2x2::new(
-            Vector2::new(10, 20, VectorType::Row),
-            Vector2::new(30, 40, VectorType::Row),
+        let matrix = Matrix2x2::new(Vector2::new(10, 2), Vector2::new(3, 40));
+        assert_eq!(
+            format!("{matrix}"),
+            "┌       ┐\n│ 10  2 │\n│  3 40 │\n└       ┘"
+        );
+        assert_eq!(
+            format!("{:>3}", matrix.display().ascii()),
+            "[  10   2 ]\n[   3  40 ]"
+        );
+        assert_eq!(
+            format!("{}", matrix.display().compact()),
+            "[[10, 2], [3, 40]]"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn render() {
+        use crate::linear_algebra::render::{Delimiter, RenderOptions};
+
+        let matrix = matrix2x2!(1.0 0.5; 0.25 2.0);
+        assert_eq!(
+            matrix.to_latex_with(
+                RenderOptions::new()
+                    .delimiter(Delimiter::Parenthesis)
+                    .precision(2)
+            ),
+            "\\left( \\begin{array}{cc} 1.00 & 0.50 \\\\ 0.25 & 2.00 \\end{array} \\right)"
         );
-        println!("{matrix}");
+        assert_eq!(matrix.to_typst(), "mat(delim: \"[\", 1, 0.5; 0.25, 2)");
+    }
+
+    #[test]
+    fn componentwise() {
+        let matrix = Matrix2x2::from_rows([[1.5, -2.0], [0.5, 3.0]]);
+        assert_eq!(matrix.abs(), Matrix2x2::from_rows([[1.5, 2.0], [0.5, 3.0]]));
+        assert_eq!(
+            matrix.max(Matrix2x2::identity()),
+            Matrix2x2::from_rows([[1.5, 0.0], [0.5, 3.0]])
+        );
+        assert_eq!(matrix.map(|x| x * 2.0).sum(), 6.0);
+        assert_eq!(matrix.product(), -4.5);
+        assert_eq!((matrix.argmin(), matrix.argmax()), ((0, 1), (1, 1)));
+        assert_eq!(matrix.min_element(), -2.0);
+    }
+
+    #[test]
+    fn overflow() {
+        let matrix = Matrix2x2::from_rows([[200u8, 10], [1, 2]]);
+        assert_eq!(matrix.checked_add(60), None);
+        assert_eq!(
+            matrix.checked_sub(1),
+            Some(Matrix2x2::from_rows([[199, 9], [0, 1]]))
+        );
+        assert_eq!(
+            matrix.saturating_add(60),
+            Matrix2x2::from_rows([[255, 70], [61, 62]])
+        );
+        assert_eq!(
+            matrix.wrapping_mul_elementwise(Matrix2x2::from_rows([[2, 2], [2, 2]])),
+            Matrix2x2::from_rows([[144, 20], [2, 4]])
+        );
+        assert_eq!(matrix.checked_product(&matrix), None);
+        let small = Matrix2x2::from_rows([[1u8, 2], [3, 4]]);
+        assert_eq!(small.checked_product(&small), Some(small * small));
+        assert_eq!(
+            matrix.saturating_product(&small),
+            Matrix2x2::from_rows([[230, 255], [7, 10]])
+        );
+        assert_eq!(
+            matrix.wrapping_product(&small),
+            Matrix2x2::from_rows([[230, 184], [7, 10]])
+        );
+
+        // Only the final sums are clamped, so overflowing partial sums can cancel.
+        let signed = Matrix2x2::from_rows([[100i8, 100], [-100, 1]]);
+        let rhs = Matrix2x2::from_rows([[2i8, 0], [-1, 0]]);
+        assert_eq!(
+            signed.saturating_product(&rhs),
+            Matrix2x2::from_rows([[100, 0], [-128, 0]])
+        );
+        let wide = Matrix2x2::from_rows([[i128::MAX, i128::MAX], [i128::MIN, 3]]);
+        let rhs = Matrix2x2::from_rows([[i128::MAX, 0], [-i128::MAX, 0]]);
+        assert_eq!(
+            wide.saturating_product(&rhs),
+            Matrix2x2::from_rows([[0, 0], [i128::MIN, 0]])
+        );
+        let unsigned = Matrix2x2::from_rows([[u128::MAX, 1], [1, 1]]);
+        assert_eq!(
+            unsigned.saturating_product(&unsigned),
+            Matrix2x2::from_rows([[u128::MAX, u128::MAX], [u128::MAX, 2]])
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = matrix2x2!(4.0f64 7.0; 2.0 6.0);
+        assert_eq!(matrix.determinant(), 10.0);
+        let inverse = matrix.inverse().unwrap();
+        let expected = Matrix2x2::from_rows([[0.6, -0.7], [-0.2, 0.4]]);
+        assert!(inverse
+            .as_slice()
+            .iter()
+            .zip(expected.as_slice())
+            .all(|(a, b)| (a - b).abs() < 1e-15));
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.rref(), Matrix2x2::identity());
+
+        let singular = matrix2x2!(1.0 2.0; 2.0 4.0);
+        assert_eq!(singular.inverse(), None);
+        assert!(matches!(
+            singular.try_inverse(),
+            Err(crate::Error::SingularMatrix)
+        ));
+        assert_eq!(singular.rank(), 1);
+        assert_eq!(singular.rref(), matrix2x2!(1.0 2.0; 0.0 0.0));
+        // Tiny elements still pivot, since the tolerance is relative to the largest.
+        assert_eq!(matrix2x2!(1e-20 0.0; 0.0 1e-20).rank(), 2);
     }
 }
@@ -1,25 +1,155 @@
+use super::{adjoint_impl, display_impl, echelon_impl, layout_impl, product_impl};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
+use crate::linear_algebra::vector::vector_2::Vector2;
 use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::{Orientation, VectorType};
+use crate::num::{Num, Real};
 
+use core::ops::Neg;
+
+/// A 3x3 matrix.
+///
+/// Elements are stored row-major with the layout of `[[T; 3]; 3]`, so the matrix can be handed
+/// directly to APIs expecting 9 contiguous elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct Matrix3x3<T> {
     vectors: [Vector3<T>; 3],
 }
 
 impl<T> Matrix3x3<T> {
-    pub fn new(v1: Vector3<T>, v2: Vector3<T>, v3: Vector3<T>) -> Self {
-        assert_eq!(v1.vector_type, v2.vector_type);
-        assert_eq!(v2.vector_type, v3.vector_type);
-        Self {
-            vectors: [v1, v2, v3],
+    /// Builds a matrix from its rows, or from its columns when given column vectors.
+    pub fn new<O: Orientation>(v1: Vector3<T, O>, v2: Vector3<T, O>, v3: Vector3<T, O>) -> Self {
+        let matrix = Self::from_rows([v1.storage, v2.storage, v3.storage]);
+        match O::VECTOR_TYPE {
+            VectorType::Row => matrix,
+            VectorType::Column => matrix.transpose(),
+        }
+    }
+    pub fn transpose(self) -> Self {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.into_rows();
+        Self::from_rows([[a, d, g], [b, e, h], [c, f, i]])
+    }
+}
+
+impl<T: Num + Neg<Output = T> + Copy> Matrix3x3<T> {
+    pub fn determinant(&self) -> T {
+        let [[a, b, c], [d, e, f], [g, h, i]] = *self.as_rows();
+        a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+    }
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant.is_zero() {
+            return None;
         }
+        let [[a, b, c], [d, e, f], [g, h, i]] = *self.as_rows();
+        let inverse_determinant = T::one() / determinant;
+        let adjugate = Self::from_rows([
+            [e * i - f * h, -(b * i - c * h), b * f - c * e],
+            [-(d * i - f * g), a * i - c * g, -(a * f - c * d)],
+            [d * h - e * g, -(a * h - b * g), a * e - b * d],
+        ]);
+        Some(adjugate.map(|x| x * inverse_determinant))
+    }
+    pub fn try_inverse(&self) -> Result<Self, crate::Error> {
+        self.inverse().ok_or(crate::Error::SingularMatrix)
+    }
+}
+
+/// 2D affine transform builders for column vectors in homogeneous coordinates, so transforms are
+/// applied right to left and `(a * b).transform_point(p)` applies `b` first.
+impl<T: Real> Matrix3x3<T> {
+    pub fn translation<O: Orientation>(offset: Vector2<T, O>) -> Self {
+        let [x, y] = offset.storage;
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[l, o, x], [o, l, y], [o, o, l]])
+    }
+    /// The counterclockwise rotation by `angle` radians around the origin.
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[cos, -sin, o], [sin, cos, o], [o, o, l]])
+    }
+    pub fn scaling<O: Orientation>(factors: Vector2<T, O>) -> Self {
+        let [x, y] = factors.storage;
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[x, o, o], [o, y, o], [o, o, l]])
+    }
+    /// Shears X by `x` times Y and Y by `y` times X.
+    pub fn shear(x: T, y: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[l, x, o], [y, l, o], [o, o, l]])
+    }
+    /// The reflection across the line through the origin along `axis`, which does not need to be
+    /// normalized.
+    pub fn reflection<O: Orientation>(axis: Vector2<T, O>) -> Self {
+        let [x, y] = axis.normalize().storage;
+        let (o, l) = (T::zero(), T::one());
+        let two = l + l;
+        Self::from_rows([
+            [two * x * x - l, two * x * y, o],
+            [two * x * y, two * y * y - l, o],
+            [o, o, l],
+        ])
+    }
+    /// Splits an affine transform without shear into the translation, rotation angle and scale
+    /// that rebuild it as `translation * rotation * scaling`.
+    ///
+    /// A reflection is returned as a negative Y scale.
+    pub fn decompose(&self) -> (Vector2<T>, T, Vector2<T>) {
+        let [[a, b, x], [c, d, y], _] = *self.as_rows();
+        let scale_x = (a * a + c * c).sqrt();
+        let scale_y = (a * d - b * c) / scale_x;
+        (
+            Vector2::new(x, y),
+            c.atan2(a),
+            Vector2::new(scale_x, scale_y),
+        )
+    }
+    /// Transforms a point, dividing by the resulting homogeneous coordinate.
+    pub fn transform_point<O: Orientation>(&self, point: Vector2<T, O>) -> Vector2<T, O> {
+        let [x, y] = point.storage;
+        let [x, y, w] = self.transform([x, y, T::one()]);
+        Vector2::from_array([x / w, y / w])
+    }
+    /// Transforms a direction, which is unaffected by translation and needs no division.
+    pub fn transform_vector<O: Orientation>(&self, vector: Vector2<T, O>) -> Vector2<T, O> {
+        let [x, y] = vector.storage;
+        let [x, y, _] = self.transform([x, y, T::zero()]);
+        Vector2::from_array([x, y])
+    }
+    fn transform(&self, vector: [T; 3]) -> [T; 3] {
+        self.as_rows()
+            .map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
     }
 }
 
+layout_impl!(Matrix3x3, Vector3, 3);
+
+product_impl!(Matrix3x3, Vector3, 3);
+
+display_impl!(Matrix3x3);
+
+adjoint_impl!(Matrix3x3);
+
+echelon_impl!(Matrix3x3, |_: &Self| 3);
+
+#[cfg(feature = "alloc")]
+render_impl!(matrix Matrix3x3, |_: &Self| 3);
+
+componentwise_impl!(matrix Matrix3x3, |_: &Self| 3);
+
+overflow_impl!(matrix Matrix3x3, |_: &Self| 3);
+
 macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Matrix3x3<T> {
+                impl<T: Copy + ::core::ops::$op<T, Output = T>> ::core::ops::$op<T> for Matrix3x3<T> {
                     type Output = Matrix3x3<T>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
@@ -39,14 +169,14 @@ cfg_if::cfg_if! {
         macro_rules! matrix3x3 {
             ($i1:tt $i2:tt $i3:tt; $i4:tt $i5:tt $i6:tt; $i7:tt $i8:tt $i9:tt) => {
                 #[allow(unused_parens)]
-                Matrix3x3::new(Vector3::new($i1, $i2, $i3, VectorType::Row), Vector3::new($i4, $i5, $i6, VectorType::Row), Vector3::new($i7, $i8, $i9, VectorType::Row))
+                Matrix3x3::new(Vector3::new($i1, $i2, $i3), Vector3::new($i4, $i5, $i6), Vector3::new($i7, $i8, $i9))
             }
         }
     } else {
         #[macro_export]
         macro_rules! matrix3x3 {
             ($i1:tt $i2:tt $i3:tt; $i4:tt $i5:tt $i6:tt; $i7:tt $i8:tt $i9:tt) => {
-                Matrix3x3::new(Vector3::new($i1, $i2, $i3, VectorType::Row), Vector3::new($i4, $i5, $i6, VectorType::Row), Vector3::new($i7, $i8, $i9, VectorType::Row))
+                Matrix3x3::new(Vector3::new($i1, $i2, $i3), Vector3::new($i4, $i5, $i6), Vector3::new($i7, $i8, $i9))
             }
         }
     }
@@ -55,45 +185,45 @@ cfg_if::cfg_if! {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linear_algebra::vector::VectorType;
+    use crate::test_util::assert_close;
 
     #[test]
     fn operators() {
         let matrix = Matrix3x3::new(
-            Vector3::new(10, 20, 30, VectorType::Row),
-            Vector3::new(30, 40, 50, VectorType::Row),
-            Vector3::new(60, 70, 80, VectorType::Row),
+            Vector3::new(10, 20, 30),
+            Vector3::new(30, 40, 50),
+            Vector3::new(60, 70, 80),
         );
         assert_eq!(
             matrix + 10,
             Matrix3x3::new(
-                Vector3::new(20, 30, 40, VectorType::Row),
-                Vector3::new(40, 50, 60, VectorType::Row),
-                Vector3::new(70, 80, 90, VectorType::Row)
+                Vector3::new(20, 30, 40),
+                Vector3::new(40, 50, 60),
+                Vector3::new(70, 80, 90)
             )
         );
         assert_eq!(
             matrix - 10,
             Matrix3x3::new(
-                Vector3::new(0, 10, 20, VectorType::Row),
-                Vector3::new(20, 30, 40, VectorType::Row),
-                Vector3::new(50, 60, 70, VectorType::Row)
+                Vector3::new(0, 10, 20),
+                Vector3::new(20, 30, 40),
+                Vector3::new(50, 60, 70)
             )
         );
         assert_eq!(
             matrix * 10,
             Matrix3x3::new(
-                Vector3::new(100, 200, 300, VectorType::Row),
-                Vector3::new(300, 400, 500, VectorType::Row),
-                Vector3::new(600, 700, 800, VectorType::Row)
+                Vector3::new(100, 200, 300),
+                Vector3::new(300, 400, 500),
+                Vector3::new(600, 700, 800)
             )
         );
         assert_eq!(
             matrix / 10,
             Matrix3x3::new(
-                Vector3::new(1, 2, 3, VectorType::Row),
-                Vector3::new(3, 4, 5, VectorType::Row),
-                Vector3::new(6, 7, 8, VectorType::Row)
+                Vector3::new(1, 2, 3),
+                Vector3::new(3, 4, 5),
+                Vector3::new(6, 7, 8)
             )
         );
     }
@@ -103,20 +233,104 @@ mod tests {
         assert_eq!(
             matrix3x3!(10 20 30; 40 50 60; 70 80 90),
             Matrix3x3::new(
-                Vector3::new(10, 20, 30, VectorType::Row),
-                Vector3::new(40, 50, 60, VectorType::Row),
-                Vector3::new(70, 80, 90, VectorType::Row)
+                Vector3::new(10, 20, 30),
+                Vector3::new(40, 50, 60),
+                Vector3::new(70, 80, 90)
             )
         );
     }
 
     #[test]
-    #[should_panic]
-    fn diff_vector_types() {
-        Matrix3x3::new(
-            Vector3::new(10, 20, 30, VectorType::Row),
-            Vector3::new(10, 20, 30, VectorType::Column),
-            Vector3::new(10, 20, 30, VectorType::Row),
+    fn column_vectors() {
+        assert_eq!(
+            Matrix3x3::new(
+                Vector3::new_column(1, 4, 7),
+                Vector3::new_column(2, 5, 8),
+                Vector3::new_column(3, 6, 9)
+            ),
+            matrix3x3!(1 2 3; 4 5 6; 7 8 9)
         );
     }
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Matrix3x3<f64>>(), 9 * 8);
+        let matrix = matrix3x3!(1 2 3; 4 5 6; 7 8 9);
+        assert_eq!(matrix.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(Matrix3x3::from_slice(matrix.as_slice()), matrix);
+        assert_eq!(unsafe { *matrix.as_ptr().add(5) }, 6);
+        assert_eq!(matrix.transpose(), matrix3x3!(1 4 7; 2 5 8; 3 6 9));
+    }
+
+    #[test]
+    #[allow(unused_parens)]
+    fn display() {
+        let matrix = matrix3x3!(1.5 (-2.0) 3.0; 4.0 5.25 6.0; 7.0 8.0 900.0);
+        assert_eq!(
+            format!("{:.1}", matrix),
+            "┌                ┐\n│ 1.5 -2.0   3.0 │\n│ 4.0  5.2   6.0 │\n│ 7.0  8.0 900.0 │\n└                ┘"
+        );
+        assert_eq!(
+            format!("{:.1e}", matrix.display().compact()),
+            "[[1.5e0, -2.0e0, 3.0e0], [4.0e0, 5.2e0, 6.0e0], [7.0e0, 8.0e0, 9.0e2]]"
+        );
+    }
+
+    #[test]
+    fn rank() {
+        // Elimination leaves a rounding error of about 1e-16 where the last pivot would be.
+        let singular = Matrix3x3::from_rows([[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(singular.rank(), 2);
+        assert!(singular.rref().as_rows()[2].iter().all(|x| x.abs() < 1e-15));
+    }
+
+    #[test]
+    fn affine() {
+        use core::f64::consts::FRAC_PI_2;
+
+        let point = Vector2::new(1.0, 2.0);
+        let translation = Matrix3x3::translation(Vector2::new(10.0, 20.0));
+        assert_eq!(translation.transform_point(point), Vector2::new(11.0, 22.0));
+        assert_eq!(translation.transform_vector(point), point);
+        assert_close(
+            Matrix3x3::rotation(FRAC_PI_2).transform_point(point),
+            Vector2::new(-2.0, 1.0),
+        );
+        assert_eq!(
+            Matrix3x3::scaling(Vector2::new(2.0, -3.0)).transform_point(point),
+            Vector2::new(2.0, -6.0)
+        );
+        assert_eq!(
+            Matrix3x3::shear(1.0, 0.5).transform_point(point),
+            Vector2::new(3.0, 2.5)
+        );
+        assert_close(
+            Matrix3x3::reflection(Vector2::new(1.0, 1.0)).transform_point(point),
+            Vector2::new(2.0, 1.0),
+        );
+        assert_close(
+            Matrix3x3::reflection(Vector2::new(3.0, 0.0)).transform_vector(point),
+            Vector2::new(1.0, -2.0),
+        );
+        let projective = Matrix3x3::from_rows([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0]]);
+        assert_eq!(
+            projective.transform_point(Vector2::new_column(3.0, 1.0)),
+            Vector2::new_column(1.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn decompose() {
+        let transform = Matrix3x3::translation(Vector2::new(5.0, -1.0))
+            * Matrix3x3::rotation(0.75)
+            * Matrix3x3::scaling(Vector2::new(2.0, 0.5));
+        let (translation, angle, scale) = transform.decompose();
+        assert_close(translation, Vector2::new(5.0, -1.0));
+        assert!((angle - 0.75).abs() < 1e-12);
+        assert_close(scale, Vector2::new(2.0, 0.5));
+
+        let (_, angle, scale) = Matrix3x3::scaling(Vector2::new(1.0, -1.0)).decompose();
+        assert_eq!(angle, 0.0);
+        assert_eq!(scale, Vector2::new(1.0, -1.0));
+    }
 }
@@ -1,26 +1,360 @@
+use super::{adjoint_impl, display_impl, echelon_impl, layout_impl, product_impl};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+use crate::linear_algebra::quaternion::Quaternion;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
+use crate::linear_algebra::vector::vector_3::Vector3;
 use crate::linear_algebra::vector::vector_4::Vector4;
+use crate::linear_algebra::vector::{Orientation, VectorType};
+use crate::linear_algebra::Element;
+use crate::num::{Num, Real};
 
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A 4x4 matrix.
+///
+/// Elements are stored row-major with the layout of `[[T; 4]; 4]`, so the matrix can be handed
+/// directly to APIs expecting 16 contiguous elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct Matrix4x4<T> {
     vectors: [Vector4<T>; 4],
 }
 
+impl<T: Element> Matrix4x4<T> {
+    /// Builds a matrix from its rows, or from its columns when given column vectors.
+    pub fn new<O: Orientation>(
+        v1: Vector4<T, O>,
+        v2: Vector4<T, O>,
+        v3: Vector4<T, O>,
+        v4: Vector4<T, O>,
+    ) -> Self {
+        let matrix = Self::from_rows([v1.storage, v2.storage, v3.storage, v4.storage]);
+        match O::VECTOR_TYPE {
+            VectorType::Row => matrix,
+            VectorType::Column => matrix.transpose(),
+        }
+    }
+    pub fn transpose(self) -> Self {
+        #[cfg(feature = "nightly")]
+        if let Some(rows) = crate::nightly::matrix4x4_transpose(self.as_rows()) {
+            return Self::from_rows(rows);
+        }
+        self.transpose_scalar()
+    }
+}
+
 impl<T> Matrix4x4<T> {
-    pub fn new(v1: Vector4<T>, v2: Vector4<T>, v3: Vector4<T>, v4: Vector4<T>) -> Self {
-        assert_eq!(v1.vector_type, v2.vector_type);
-        assert_eq!(v2.vector_type, v3.vector_type);
-        assert_eq!(v3.vector_type, v4.vector_type);
-        Self {
-            vectors: [v1, v2, v3, v4],
+    pub(crate) fn transpose_scalar(self) -> Self {
+        let [[a, b, c, d], [e, f, g, h], [i, j, k, l], [m, n, o, p]] = self.into_rows();
+        Self::from_rows([[a, e, i, m], [b, f, j, n], [c, g, k, o], [d, h, l, p]])
+    }
+}
+
+/// Where the elements and 2x2 minors making up each cofactor of the inverse come from, laid out
+/// so that every row of the inverse can be computed lane by lane.
+pub(crate) mod inverse_tables {
+    pub(crate) const LANE_ROWS: [usize; 4] = [1, 0, 3, 2];
+    pub(crate) const X_COLUMNS: [usize; 4] = [1, 0, 0, 0];
+    pub(crate) const Y_COLUMNS: [usize; 4] = [2, 2, 1, 1];
+    pub(crate) const Z_COLUMNS: [usize; 4] = [3, 3, 3, 2];
+    pub(crate) const P_MINORS: [usize; 4] = [5, 5, 4, 3];
+    pub(crate) const Q_MINORS: [usize; 4] = [4, 2, 2, 1];
+    pub(crate) const R_MINORS: [usize; 4] = [3, 1, 0, 0];
+}
+
+/// The 2x2 minors of the top two rows and of the bottom two rows.
+pub(crate) fn minors<T: Copy + Sub<Output = T> + Mul<Output = T>>(
+    a: &[[T; 4]; 4],
+) -> ([T; 6], [T; 6]) {
+    let s = [
+        a[0][0] * a[1][1] - a[1][0] * a[0][1],
+        a[0][0] * a[1][2] - a[1][0] * a[0][2],
+        a[0][0] * a[1][3] - a[1][0] * a[0][3],
+        a[0][1] * a[1][2] - a[1][1] * a[0][2],
+        a[0][1] * a[1][3] - a[1][1] * a[0][3],
+        a[0][2] * a[1][3] - a[1][2] * a[0][3],
+    ];
+    let c = [
+        a[2][0] * a[3][1] - a[3][0] * a[2][1],
+        a[2][0] * a[3][2] - a[3][0] * a[2][2],
+        a[2][0] * a[3][3] - a[3][0] * a[2][3],
+        a[2][1] * a[3][2] - a[3][1] * a[2][2],
+        a[2][1] * a[3][3] - a[3][1] * a[2][3],
+        a[2][2] * a[3][3] - a[3][2] * a[2][3],
+    ];
+    (s, c)
+}
+
+pub(crate) fn determinant_from_minors<
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+>(
+    s: &[T; 6],
+    c: &[T; 6],
+) -> T {
+    s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+}
+
+impl<T: Num + Neg<Output = T> + Copy + Element> Matrix4x4<T> {
+    pub fn determinant(&self) -> T {
+        let (s, c) = minors(self.as_rows());
+        determinant_from_minors(&s, &c)
+    }
+    /// Returns the inverse, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        #[cfg(feature = "nightly")]
+        if let Some(inverse) = crate::nightly::matrix4x4_inverse(self.as_rows()) {
+            return inverse.map(Self::from_rows);
+        }
+        self.inverse_scalar()
+    }
+    pub fn try_inverse(&self) -> Result<Self, crate::Error> {
+        self.inverse().ok_or(crate::Error::SingularMatrix)
+    }
+    pub(crate) fn inverse_scalar(&self) -> Option<Self> {
+        use inverse_tables::*;
+
+        let a = self.as_rows();
+        let (s, c) = minors(a);
+        let determinant = determinant_from_minors(&s, &c);
+        if determinant.is_zero() {
+            return None;
         }
+        let inverse_determinant = T::one() / determinant;
+        Some(Self::from_rows(core::array::from_fn(|row| {
+            core::array::from_fn(|lane| {
+                let minors = if lane < 2 { &c } else { &s };
+                let cofactor = a[LANE_ROWS[lane]][X_COLUMNS[row]] * minors[P_MINORS[row]]
+                    - a[LANE_ROWS[lane]][Y_COLUMNS[row]] * minors[Q_MINORS[row]]
+                    + a[LANE_ROWS[lane]][Z_COLUMNS[row]] * minors[R_MINORS[row]];
+                let cofactor = if (row + lane) % 2 == 1 {
+                    -cofactor
+                } else {
+                    cofactor
+                };
+                cofactor * inverse_determinant
+            })
+        })))
+    }
+}
+
+/// The depth range of clip space after the perspective divide.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum ClipDepth {
+    /// `-1..=1`, as used by OpenGL.
+    #[default]
+    NegativeOneToOne,
+    /// `0..=1`, as used by Direct3D, Metal and Vulkan.
+    ZeroToOne,
+}
+
+/// Transform builders for column vectors, so transforms are applied right to left and
+/// `(a * b).transform_point(p)` applies `b` first.
+///
+/// The projections expect a right-handed view space looking down the negative Z axis, such as the
+/// one produced by `look_at_rh`.
+impl<T: Real> Matrix4x4<T> {
+    pub fn translation<O: Orientation>(offset: Vector3<T, O>) -> Self {
+        let [x, y, z] = offset.storage;
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[l, o, o, x], [o, l, o, y], [o, o, l, z], [o, o, o, l]])
+    }
+    pub fn scaling<O: Orientation>(factors: Vector3<T, O>) -> Self {
+        let [x, y, z] = factors.storage;
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([[x, o, o, o], [o, y, o, o], [o, o, z, o], [o, o, o, l]])
+    }
+    /// The counterclockwise rotation by `angle` radians around the X axis.
+    pub fn rotation_x(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([
+            [l, o, o, o],
+            [o, cos, -sin, o],
+            [o, sin, cos, o],
+            [o, o, o, l],
+        ])
+    }
+    pub fn rotation_y(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([
+            [cos, o, sin, o],
+            [o, l, o, o],
+            [-sin, o, cos, o],
+            [o, o, o, l],
+        ])
+    }
+    pub fn rotation_z(angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([
+            [cos, -sin, o, o],
+            [sin, cos, o, o],
+            [o, o, l, o],
+            [o, o, o, l],
+        ])
+    }
+    /// The counterclockwise rotation by `angle` radians around `axis`, which does not need to be
+    /// normalized.
+    pub fn rotation_axis<O: Orientation>(axis: Vector3<T, O>, angle: T) -> Self {
+        Quaternion::from_axis_angle(axis, angle).to_matrix4x4()
+    }
+    /// A right-handed view matrix with the camera at `eye` looking at `target`, so that the camera
+    /// looks down the negative Z axis.
+    pub fn look_at_rh<O: Orientation>(
+        eye: Vector3<T, O>,
+        target: Vector3<T, O>,
+        up: Vector3<T, O>,
+    ) -> Self {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+        Self::view(side, up, forward * -T::one(), eye)
+    }
+    /// A left-handed view matrix with the camera at `eye` looking at `target`, so that the camera
+    /// looks down the positive Z axis.
+    pub fn look_at_lh<O: Orientation>(
+        eye: Vector3<T, O>,
+        target: Vector3<T, O>,
+        up: Vector3<T, O>,
+    ) -> Self {
+        let forward = (target - eye).normalize();
+        let side = up.cross(forward).normalize();
+        let up = forward.cross(side);
+        Self::view(side, up, forward, eye)
+    }
+    fn view<O: Orientation>(
+        x: Vector3<T, O>,
+        y: Vector3<T, O>,
+        z: Vector3<T, O>,
+        eye: Vector3<T, O>,
+    ) -> Self {
+        let row = |axis: Vector3<T, O>| {
+            let [a, b, c] = axis.storage;
+            [a, b, c, -axis.dot(eye)]
+        };
+        let (o, l) = (T::zero(), T::one());
+        Self::from_rows([row(x), row(y), row(z), [o, o, o, l]])
+    }
+    /// A perspective projection with a vertical field of view of `fov_y` radians, mapping the
+    /// `near` plane to the near end of `depth` and the `far` plane to its far end.
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T, depth: ClipDepth) -> Self {
+        let (z, w) = match depth {
+            ClipDepth::NegativeOneToOne => (
+                (far + near) / (near - far),
+                (T::one() + T::one()) * far * near / (near - far),
+            ),
+            ClipDepth::ZeroToOne => (far / (near - far), far * near / (near - far)),
+        };
+        Self::projection(fov_y, aspect, z, w)
+    }
+    /// A perspective projection with the far plane at infinity.
+    pub fn perspective_infinite(fov_y: T, aspect: T, near: T, depth: ClipDepth) -> Self {
+        let w = match depth {
+            ClipDepth::NegativeOneToOne => -(T::one() + T::one()) * near,
+            ClipDepth::ZeroToOne => -near,
+        };
+        Self::projection(fov_y, aspect, -T::one(), w)
+    }
+    /// A zero to one perspective projection mapping the `near` plane to 1 and the `far` plane to
+    /// 0, which spreads floating point depth precision evenly.
+    pub fn perspective_reversed(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::projection(
+            fov_y,
+            aspect,
+            near / (far - near),
+            far * near / (far - near),
+        )
+    }
+    /// A reversed zero to one perspective projection with the far plane at infinity.
+    pub fn perspective_infinite_reversed(fov_y: T, aspect: T, near: T) -> Self {
+        Self::projection(fov_y, aspect, T::zero(), near)
+    }
+    fn projection(fov_y: T, aspect: T, z: T, w: T) -> Self {
+        let focal = T::one() / (fov_y / (T::one() + T::one())).tan();
+        let o = T::zero();
+        Self::from_rows([
+            [focal / aspect, o, o, o],
+            [o, focal, o, o],
+            [o, o, z, w],
+            [o, o, -T::one(), o],
+        ])
+    }
+    /// An orthographic projection of the box between the given planes, where `near` and `far`
+    /// are distances along the negative Z axis.
+    pub fn orthographic(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let two = l + l;
+        let (z, w) = match depth {
+            ClipDepth::NegativeOneToOne => (-two / (far - near), -(far + near) / (far - near)),
+            ClipDepth::ZeroToOne => (-l / (far - near), -near / (far - near)),
+        };
+        Self::from_rows([
+            [two / (right - left), o, o, -(right + left) / (right - left)],
+            [o, two / (top - bottom), o, -(top + bottom) / (top - bottom)],
+            [o, o, z, w],
+            [o, o, o, l],
+        ])
+    }
+    /// Transforms a point, dividing by the resulting homogeneous `w` coordinate.
+    pub fn transform_point<O: Orientation>(&self, point: Vector3<T, O>) -> Vector3<T, O> {
+        let [x, y, z, w] = self.transform([
+            point.storage[0],
+            point.storage[1],
+            point.storage[2],
+            T::one(),
+        ]);
+        Vector3::from_array([x / w, y / w, z / w])
+    }
+    /// Transforms a direction, which is unaffected by translation and needs no division.
+    pub fn transform_vector<O: Orientation>(&self, vector: Vector3<T, O>) -> Vector3<T, O> {
+        let [x, y, z, _] = self.transform([
+            vector.storage[0],
+            vector.storage[1],
+            vector.storage[2],
+            T::zero(),
+        ]);
+        Vector3::from_array([x, y, z])
+    }
+    fn transform(&self, vector: [T; 4]) -> [T; 4] {
+        self.as_rows().map(|row| {
+            row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2] + row[3] * vector[3]
+        })
     }
 }
 
+layout_impl!(Matrix4x4, Vector4, 4);
+
+product_impl!(Matrix4x4, Vector4, 4);
+
+display_impl!(Matrix4x4);
+
+adjoint_impl!(Matrix4x4);
+
+echelon_impl!(Matrix4x4, |_: &Self| 4);
+
+#[cfg(feature = "alloc")]
+render_impl!(matrix Matrix4x4, |_: &Self| 4);
+
+componentwise_impl!(matrix Matrix4x4, |_: &Self| 4);
+
+overflow_impl!(matrix Matrix4x4, |_: &Self| 4);
+
 macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Matrix4x4<T> {
+                impl<T: Copy + ::core::ops::$op<T, Output = T> + Element> ::core::ops::$op<T> for Matrix4x4<T> {
                     type Output = Matrix4x4<T>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
@@ -41,10 +375,10 @@ cfg_if::cfg_if! {
             ($i1:tt $i2:tt $i3:tt $i4:tt; $i5:tt $i6:tt $i7:tt $i8:tt; $i9:tt $i10:tt $i11:tt $i12:tt; $i13:tt $i14:tt $i15:tt $i16:tt) => {
                 #[allow(unused_parens)]
                 Matrix4x4::new(
-                    Vector4::new($i1, $i2, $i3, $i4, VectorType::Row),
-                    Vector4::new($i5, $i6, $i7, $i8, VectorType::Row),
-                    Vector4::new($i9, $i10, $i11, $i12, VectorType::Row),
-                    Vector4::new($i13, $i14, $i15, $i16, VectorType::Row)
+                    Vector4::new($i1, $i2, $i3, $i4),
+                    Vector4::new($i5, $i6, $i7, $i8),
+                    Vector4::new($i9, $i10, $i11, $i12),
+                    Vector4::new($i13, $i14, $i15, $i16)
                 )
             }
         }
@@ -53,10 +387,10 @@ cfg_if::cfg_if! {
         macro_rules! matrix4x4 {
             ($i1:tt $i2:tt $i3:tt $i4:tt; $i5:tt $i6:tt $i7:tt $i8:tt; $i9:tt $i10:tt $i11:tt $i12:tt; $i13:tt $i14:tt $i15:tt $i16:tt) => {
                 Matrix4x4::new(
-                    Vector4::new($i1, $i2, $i3, $i4, VectorType::Row),
-                    Vector4::new($i5, $i6, $i7, $i8, VectorType::Row),
-                    Vector4::new($i9, $i10, $i11, $i12, VectorType::Row),
-                    Vector4::new($i13, $i14, $i15, $i16, VectorType::Row)
+                    Vector4::new($i1, $i2, $i3, $i4),
+                    Vector4::new($i5, $i6, $i7, $i8),
+                    Vector4::new($i9, $i10, $i11, $i12),
+                    Vector4::new($i13, $i14, $i15, $i16)
                 )
             }
         }
@@ -67,7 +401,7 @@ cfg_if::cfg_if! {
 mod tests {
     use super::*;
     use crate::linear_algebra::vector::vector_4::vec4;
-    use crate::linear_algebra::vector::VectorType;
+    use crate::test_util::assert_close;
 
     #[test]
     fn operators() {
@@ -121,13 +455,171 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn diff_vector_types() {
-        Matrix4x4::new(
-            vec4![10 20 30 40],
-            Vector4::new(10, 20, 30, 40, VectorType::Column),
-            vec4![10 20 30 40],
-            vec4![10 20 30 40],
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Matrix4x4<f32>>(), 16 * 4);
+        let matrix = matrix4x4!(1 2 3 4; 5 6 7 8; 9 10 11 12; 13 14 15 16);
+        assert_eq!(matrix.as_slice(), (1..=16).collect::<Vec<_>>());
+        assert_eq!(Matrix4x4::from_slice(matrix.as_slice()), matrix);
+        assert_eq!(
+            matrix.transpose(),
+            matrix4x4!(1 5 9 13; 2 6 10 14; 3 7 11 15; 4 8 12 16)
+        );
+        assert_eq!(
+            Matrix4x4::new(
+                Vector4::new_column(1, 5, 9, 13),
+                Vector4::new_column(2, 6, 10, 14),
+                Vector4::new_column(3, 7, 11, 15),
+                Vector4::new_column(4, 8, 12, 16)
+            ),
+            matrix
+        );
+    }
+
+    #[test]
+    fn display() {
+        let matrix = matrix4x4!(1 0 0 10; 0 1 0 20; 0 0 1 30; 0 0 0 1);
+        assert_eq!(
+            format!("{:^3}", matrix.display().ascii()),
+            "[  1   0   0  10  ]\n[  0   1   0  20  ]\n[  0   0   1  30  ]\n[  0   0   0   1  ]"
+        );
+    }
+
+    #[test]
+    fn product() {
+        let matrix = matrix4x4!(1 2 3 4; 5 6 7 8; 9 10 11 12; 13 14 15 16);
+        assert_eq!(matrix * Matrix4x4::identity(), matrix);
+        assert_eq!(
+            matrix * matrix,
+            matrix4x4!(90 100 110 120; 202 228 254 280; 314 356 398 440; 426 484 542 600)
+        );
+        assert_eq!(
+            matrix * Vector4::new_column(1, 0, 0, 1),
+            Vector4::new_column(5, 13, 21, 29)
+        );
+        assert_eq!(vec4![1 0 0 1] * matrix, vec4![14 16 18 20]);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = matrix4x4!(2.0 0.0 0.0 1.0; 0.0 4.0 0.0 0.0; 0.0 0.0 8.0 0.0; 0.0 0.0 1.0 1.0);
+        assert_eq!(matrix.determinant(), 64.0);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix * inverse, Matrix4x4::identity());
+        assert_eq!(inverse * matrix, Matrix4x4::identity());
+
+        let matrix = matrix4x4!(3 1 4 1; 5 9 2 6; 5 3 5 8; 9 7 9 3);
+        assert_eq!(matrix.determinant(), 98);
+        let matrix = matrix4x4!(1 2 3 4; 5 6 7 8; 9 10 11 12; 13 14 15 16);
+        assert_eq!(matrix.determinant(), 0);
+        assert_eq!(matrix.inverse(), None);
+        assert!(matches!(
+            matrix.try_inverse(),
+            Err(crate::Error::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn transforms() {
+        use core::f64::consts::FRAC_PI_2;
+
+        let point = Vector3::new(1.0, 2.0, 3.0);
+        let translation = Matrix4x4::translation(Vector3::new(10.0, 20.0, 30.0));
+        assert_eq!(
+            translation.transform_point(point),
+            Vector3::new(11.0, 22.0, 33.0)
+        );
+        assert_eq!(translation.transform_vector(point), point);
+        let scaling = Matrix4x4::scaling(Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!(
+            (translation * scaling).transform_point(point),
+            Vector3::new(12.0, 26.0, 42.0)
+        );
+
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        assert_close(Matrix4x4::rotation_x(FRAC_PI_2).transform_vector(y), z);
+        assert_close(Matrix4x4::rotation_y(FRAC_PI_2).transform_vector(z), x);
+        assert_close(Matrix4x4::rotation_z(FRAC_PI_2).transform_vector(x), y);
+        let axis = Matrix4x4::rotation_axis(Vector3::new(0.0, 0.0, 5.0), 0.7f64);
+        let rotation = Matrix4x4::rotation_z(0.7);
+        for (a, b) in axis.as_slice().iter().zip(rotation.as_slice()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert_close(
+            Matrix4x4::rotation_z(FRAC_PI_2)
+                .transform_point(Vector3::new_column(1.0, 0.0, 5.0))
+                .transpose(),
+            Vector3::new(0.0, 1.0, 5.0),
+        );
+    }
+
+    #[test]
+    fn look_at() {
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        let target = Vector3::new(1.0, 2.0, -7.0);
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let view = Matrix4x4::look_at_rh(eye, target, up);
+        assert_close(view.transform_point(eye), Vector3::new(0.0, 0.0, 0.0));
+        assert_close(view.transform_point(target), Vector3::new(0.0, 0.0, -10.0));
+        assert_close(view.transform_vector(up), up);
+        let view = Matrix4x4::look_at_lh(eye, target, up);
+        assert_close(view.transform_point(target), Vector3::new(0.0, 0.0, 10.0));
+        assert_close(
+            view.transform_point(Vector3::new(2.0, 2.0, 3.0)),
+            Vector3::new(-1.0, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn projections() {
+        use core::f64::consts::FRAC_PI_2;
+
+        let depth = |projection: Matrix4x4<f64>, distance: f64| {
+            projection
+                .transform_point(Vector3::new(0.0, 0.0, -distance))
+                .storage[2]
+        };
+        let close = |a: f64, b: f64| assert!((a - b).abs() < 1e-9, "{a} is not close to {b}");
+
+        let gl = Matrix4x4::perspective(FRAC_PI_2, 2.0, 1.0, 100.0, ClipDepth::NegativeOneToOne);
+        close(depth(gl, 1.0), -1.0);
+        close(depth(gl, 100.0), 1.0);
+        assert_close(
+            gl.transform_point(Vector3::new(2.0, 1.0, -1.0)),
+            Vector3::new(1.0, 1.0, -1.0),
+        );
+        let zo = Matrix4x4::perspective(FRAC_PI_2, 2.0, 1.0, 100.0, ClipDepth::ZeroToOne);
+        close(depth(zo, 1.0), 0.0);
+        close(depth(zo, 100.0), 1.0);
+        let reversed = Matrix4x4::perspective_reversed(FRAC_PI_2, 2.0, 1.0, 100.0);
+        close(depth(reversed, 1.0), 1.0);
+        close(depth(reversed, 100.0), 0.0);
+
+        let infinite =
+            Matrix4x4::perspective_infinite(FRAC_PI_2, 1.0, 0.5, ClipDepth::NegativeOneToOne);
+        close(depth(infinite, 0.5), -1.0);
+        close(depth(infinite, 1e12), 1.0);
+        let infinite = Matrix4x4::perspective_infinite(FRAC_PI_2, 1.0, 0.5, ClipDepth::ZeroToOne);
+        close(depth(infinite, 0.5), 0.0);
+        close(depth(infinite, 1e12), 1.0);
+        let infinite = Matrix4x4::perspective_infinite_reversed(FRAC_PI_2, 1.0, 0.5);
+        close(depth(infinite, 0.5), 1.0);
+        close(depth(infinite, 1e12), 0.0);
+
+        let orthographic =
+            Matrix4x4::orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 11.0, ClipDepth::NegativeOneToOne);
+        assert_close(
+            orthographic.transform_point(Vector3::new(-2.0, -1.0, -1.0)),
+            Vector3::new(-1.0, -1.0, -1.0),
+        );
+        assert_close(
+            orthographic.transform_point(Vector3::new(2.0, 3.0, -11.0)),
+            Vector3::new(1.0, 1.0, 1.0),
         );
+        let orthographic =
+            Matrix4x4::orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 11.0, ClipDepth::ZeroToOne);
+        close(depth(orthographic, 1.0), 0.0);
+        close(depth(orthographic, 11.0), 1.0);
     }
 }
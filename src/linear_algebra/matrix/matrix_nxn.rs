@@ -1,80 +1,456 @@
-use crate::linear_algebra::vector::vector_2::Vector2;
+use super::display::{display_impl, MatrixDisplay};
+use super::{adjoint_impl, echelon_impl, row_reduce};
+use crate::error::check_shape;
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+use crate::linear_algebra::render::render_impl;
+use crate::linear_algebra::vector::vector_n::VectorN;
+use crate::linear_algebra::vector::{Orientation, VectorType};
+use crate::num::Pivot;
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+use alloc::vec::Vec;
+
+/// A heap allocated square matrix of size 5x5 or larger.
+///
+/// Elements are stored row-major and contiguously in a single allocation.
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub struct MatrixNxN<T> {
-    vectors: Vec<VectorN<T>>>
+    size: usize,
+    storage: Vec<T>,
 }
 
-impl<T> Matrix2x2<T> {
-    pub fn new(rows: Vec<VectorN<T>>, v2: Vector2<T>) -> Self {
-        rows.iter().flat_map(|x| assert_eq!(x.vector_type))
-        Self {
-            vectors: [v1, v2]
+impl<T> MatrixNxN<T> {
+    /// Builds a matrix from its rows, or from its columns when given column vectors.
+    pub fn new<O: Orientation>(vectors: Vec<VectorN<T, O>>) -> Self {
+        Self::try_new(vectors).unwrap_or_else(|error| panic!("{}", error))
+    }
+    /// Fails if there are fewer than 5 vectors or any vector does not have one item per vector.
+    pub fn try_new<O: Orientation>(vectors: Vec<VectorN<T, O>>) -> Result<Self, crate::Error> {
+        let size = vectors.len();
+        let mut storage = Vec::with_capacity(size * size);
+        for vector in vectors {
+            check_shape(size, vector.as_slice().len())?;
+            storage.extend(vector.into_vec());
+        }
+
+        let matrix = Self::try_from_vec(size, storage)?;
+        Ok(match O::VECTOR_TYPE {
+            VectorType::Row => matrix,
+            VectorType::Column => matrix.transpose(),
+        })
+    }
+    /// Builds a `size`x`size` matrix from row-major elements.
+    pub fn from_vec(size: usize, storage: Vec<T>) -> Self {
+        Self::try_from_vec(size, storage).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_from_vec(size: usize, storage: Vec<T>) -> Result<Self, crate::Error> {
+        if size < 5 {
+            return Err(crate::Error::DimensionTooSmall {
+                minimum: 5,
+                found: size,
+            });
         }
+        check_shape(size * size, storage.len())?;
+
+        Ok(Self { size, storage })
+    }
+    pub fn from_slice(size: usize, slice: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_vec(size, slice.to_vec())
+    }
+    pub fn try_from_slice(size: usize, slice: &[T]) -> Result<Self, crate::Error>
+    where
+        T: Copy,
+    {
+        Self::try_from_vec(size, slice.to_vec())
+    }
+    pub fn into_vec(self) -> Vec<T> {
+        self.storage
+    }
+    /// Applies `f` to every element.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> MatrixNxN<U> {
+        MatrixNxN::from_vec(self.size, self.storage.into_iter().map(f).collect())
+    }
+    /// Combines corresponding elements of two matrices with `f`.
+    ///
+    /// Panics if the matrices have different sizes.
+    pub fn zip_map<U, V>(self, other: MatrixNxN<U>, mut f: impl FnMut(T, U) -> V) -> MatrixNxN<V> {
+        assert_eq!(
+            self.size, other.size,
+            "Cannot combine matrices of different sizes"
+        );
+        MatrixNxN::from_vec(
+            self.size,
+            self.storage
+                .into_iter()
+                .zip(other.storage)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        )
+    }
+    pub fn try_zip_map<U, V>(
+        self,
+        other: MatrixNxN<U>,
+        f: impl FnMut(T, U) -> V,
+    ) -> Result<MatrixNxN<V>, crate::Error> {
+        check_shape(self.size, other.size)?;
+        Ok(self.zip_map(other, f))
+    }
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+    pub fn transpose(self) -> Self {
+        let size = self.size;
+        let mut cells = self.storage.into_iter().map(Some).collect::<Vec<_>>();
+        let storage = (0..size * size)
+            .map(|i| cells[(i % size) * size + i / size].take().unwrap())
+            .collect();
+        Self { size, storage }
+    }
+    /// Returns the elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.storage
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.storage
+    }
+    pub fn as_ptr(&self) -> *const T {
+        self.storage.as_ptr()
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.storage.as_mut_ptr()
+    }
+    pub fn row(&self, index: usize) -> &[T] {
+        &self.storage[index * self.size..(index + 1) * self.size]
+    }
+    pub fn display(&self) -> MatrixDisplay<'_, T> {
+        MatrixDisplay::new(&self.storage, self.size)
     }
 }
 
-macro_rules! op_impl {
-    ($($op:ident $operator:tt),*) => {
-        ::paste::paste! {
-            $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Matrix2x2<T> {
-                    type Output = Matrix2x2<T>;
-            
-                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
-                        Matrix2x2::new(self.vectors[0] $operator rhs, self.vectors[1] $operator rhs)
-                    }
-                }
-            )*
+impl<T: Pivot> MatrixNxN<T> {
+    /// The determinant, by fraction-free Bareiss elimination for exact types and by LU
+    /// decomposition with partial pivoting otherwise.
+    ///
+    /// Every division in Bareiss elimination is exact, so integer elements such as `BigInt` give
+    /// exact results without needing `Copy` or a field.
+    pub fn determinant(&self) -> T {
+        let mut elements = self.storage.clone();
+        if T::EXACT {
+            bareiss_determinant(&mut elements, self.size)
+        } else {
+            lu_determinant(&mut elements, self.size)
         }
     }
 }
 
-op_impl!(Add +, Sub -, Mul *, Div /);
+impl<T: Pivot + Copy> MatrixNxN<T> {
+    /// The inverse by Gauss-Jordan elimination of `[A | I]`, exact for exact element types.
+    /// Panics if the matrix is singular.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_inverse(&self) -> Result<Self, crate::Error> {
+        let size = self.size;
+        let mut augmented = Vec::with_capacity(2 * size * size);
+        for (row, elements) in self.storage.chunks(size).enumerate() {
+            augmented.extend_from_slice(elements);
+            augmented.extend((0..size).map(|column| match column == row {
+                true => T::one(),
+                false => T::zero(),
+            }));
+        }
+        let mut left_pivots = 0;
+        row_reduce(&mut augmented, 2 * size, |column| {
+            if column < size {
+                left_pivots += 1;
+            }
+        });
+        if left_pivots < size {
+            return Err(crate::Error::SingularMatrix);
+        }
+        Ok(Self::from_vec(
+            size,
+            augmented
+                .chunks(2 * size)
+                .flat_map(|row| row[size..].iter().copied())
+                .collect(),
+        ))
+    }
+}
 
+/// Swaps row `k` of the `n` by `n` `elements` with the best pivot row for column `k` at or below
+/// it, returning whether they differed, or `None` if the column has no pivot.
+fn choose_pivot<T: Pivot>(elements: &mut [T], n: usize, k: usize) -> Option<bool> {
+    let row = (k..n)
+        .filter(|&row| elements[row * n + k].is_pivot(&T::zero()))
+        .reduce(|best, row| {
+            if elements[row * n + k].is_better_pivot(&elements[best * n + k]) {
+                row
+            } else {
+                best
+            }
+        })?;
+    for column in 0..n {
+        elements.swap(k * n + column, row * n + column);
+    }
+    Some(row != k)
+}
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "nightly")] {
-        #[macro_export]
-        macro_rules! matrix2x2 {
-            ($i1:tt $i2:tt; $i3:tt $i4:tt) => {
-                #[allow(unused_parens)]
-                Matrix2x2::new(Vector2::new($i1, $i2, VectorType::Row), Vector2::new($i3, $i4, VectorType::Row))
+fn bareiss_determinant<T: Pivot>(elements: &mut [T], n: usize) -> T {
+    let mut negate = false;
+    let mut previous = T::one();
+    for k in 0..n - 1 {
+        match choose_pivot(elements, n, k) {
+            Some(swapped) => negate ^= swapped,
+            None => return T::zero(),
+        }
+        let pivot = elements[k * n + k].clone();
+        for i in k + 1..n {
+            for j in k + 1..n {
+                elements[i * n + j] = (elements[i * n + j].clone() * pivot.clone()
+                    - elements[i * n + k].clone() * elements[k * n + j].clone())
+                    / previous.clone();
             }
         }
+        previous = pivot;
+    }
+    let determinant = elements[n * n - 1].clone();
+    if negate {
+        T::zero() - determinant
     } else {
-        #[macro_export]
-        macro_rules! matrix2x2 {
-            ($i1:tt $i2:tt; $i3:tt $i4:tt) => {
-                Matrix2x2::new(Vector2::new($i1, $i2, VectorType::Row), Vector2::new($i3, $i4, VectorType::Row))
+        determinant
+    }
+}
+
+fn lu_determinant<T: Pivot>(elements: &mut [T], n: usize) -> T {
+    let mut determinant = T::one();
+    for k in 0..n {
+        match choose_pivot(elements, n, k) {
+            Some(true) => determinant = T::zero() - determinant,
+            Some(false) => {}
+            None => return T::zero(),
+        }
+        let pivot = elements[k * n + k].clone();
+        for i in k + 1..n {
+            let factor = elements[i * n + k].clone() / pivot.clone();
+            for j in k + 1..n {
+                elements[i * n + j] =
+                    elements[i * n + j].clone() - factor.clone() * elements[k * n + j].clone();
             }
         }
+        determinant = determinant * pivot;
+    }
+    determinant
+}
+
+display_impl!(MatrixNxN);
+
+adjoint_impl!(MatrixNxN);
+
+echelon_impl!(MatrixNxN, MatrixNxN::size);
+
+render_impl!(matrix MatrixNxN, MatrixNxN::size);
+
+componentwise_impl!(matrix MatrixNxN, MatrixNxN::size);
+
+overflow_impl!(matrix MatrixNxN, MatrixNxN::size);
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Copy + ::core::ops::$op<T, Output = T>> ::core::ops::$op<T> for MatrixNxN<T> {
+                    type Output = MatrixNxN<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        MatrixNxN::from_vec(self.size, self.storage.iter().map(|x| *x $operator rhs).collect::<Vec<_>>())
+                    }
+                }
+            )*
+        }
     }
 }
 
+op_impl!(Add +, Sub -, Mul *, Div /);
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::linear_algebra::vector::VectorType;
+    use crate::num::rational::Rational;
+    use crate::num::Zero;
+
+    fn matrix() -> MatrixNxN<i32> {
+        MatrixNxN::new(
+            (0..5)
+                .map(|row| VectorN::new((0..5).map(|column| row * 5 + column).collect()))
+                .collect(),
+        )
+    }
 
     #[test]
     fn operators() {
-        let matrix = Matrix2x2::new(Vector2::new(10, 20, VectorType::Row), Vector2::new(30, 40, VectorType::Row));
-        assert_eq!(matrix + 10, Matrix2x2::new(Vector2::new(20, 30, VectorType::Row), Vector2::new(40, 50, VectorType::Row)));
-        assert_eq!(matrix - 10, Matrix2x2::new(Vector2::new(0, 10, VectorType::Row), Vector2::new(20, 30, VectorType::Row)));
-        assert_eq!(matrix * 10, Matrix2x2::new(Vector2::new(100, 200, VectorType::Row), Vector2::new(300, 400, VectorType::Row)));
-        assert_eq!(matrix / 10, Matrix2x2::new(Vector2::new(1, 2, VectorType::Row), Vector2::new(3, 4, VectorType::Row)));
+        let matrix = matrix();
+        assert_eq!(
+            (matrix.clone() + 10).as_slice(),
+            (10..35).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (matrix.clone() - 10).as_slice(),
+            (-10..15).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (matrix.clone() * 10).as_slice(),
+            (0..25).map(|x| x * 10).collect::<Vec<_>>()
+        );
+        assert_eq!((matrix / 5).row(4), &[4, 4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(matrix().determinant(), 0);
+        // The rows of diag(1, 2, 3, 4, 5) rotated by one, so the leading element is zero.
+        let rotated = MatrixNxN::from_vec(
+            5,
+            (0..25)
+                .map(|k| {
+                    let (row, column) = (k / 5, k % 5);
+                    if column == (row + 1) % 5 {
+                        (column + 1) as f64
+                    } else {
+                        0.0
+                    }
+                })
+                .collect(),
+        );
+        assert_eq!(rotated.determinant(), 120.0);
+        assert_eq!(rotated.clone().map(|x| x as i64).determinant(), 120);
+        let hilbert = MatrixNxN::from_vec(
+            5,
+            (0..25).map(|k| 1.0 / (k / 5 + k % 5 + 1) as f64).collect(),
+        );
+        assert!((hilbert.determinant() / 3.749295132515087e-12 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inverse() {
+        let hilbert = MatrixNxN::from_vec(
+            5,
+            (0..25)
+                .map(|k| Rational::new(1i64, (k / 5 + k % 5 + 1) as i64))
+                .collect(),
+        );
+        let inverse = hilbert.inverse();
+        assert_eq!(inverse.row(0)[0], Rational::from(25));
+        assert_eq!(inverse.row(4)[4], Rational::from(44100));
+        for i in 0..5 {
+            for j in 0..5 {
+                let product = (0..5).fold(Rational::zero(), |sum, k| {
+                    sum + hilbert.row(i)[k] * inverse.row(k)[j]
+                });
+                assert_eq!(product, Rational::from((i == j) as i64));
+            }
+        }
+        let singular = matrix().map(|x| Rational::from(x as i64));
+        assert!(matches!(
+            singular.try_inverse(),
+            Err(crate::Error::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn less_than_5() {
+        MatrixNxN::from_vec(2, vec![1, 2, 3, 4]);
     }
 
     #[test]
-    fn matrix_macro() {
-        assert_eq!(matrix2x2!((20 - 10) 20; 30 40), Matrix2x2::new(Vector2::new(10, 20, VectorType::Row), Vector2::new(30, 40, VectorType::Row)));
+    fn fallible() {
+        assert!(matches!(
+            MatrixNxN::try_from_vec(2, vec![1, 2, 3, 4]),
+            Err(crate::Error::DimensionTooSmall { .. })
+        ));
+        assert!(matches!(
+            MatrixNxN::try_new(vec![VectorN::new(vec![1, 2, 3, 4, 5, 6]); 5]),
+            Err(crate::Error::ShapeMismatch {
+                expected: 5,
+                found: 6
+            })
+        ));
+        assert_eq!(
+            MatrixNxN::try_from_slice(5, matrix().as_slice()).unwrap(),
+            matrix()
+        );
     }
 
     #[test]
     #[should_panic]
-    fn diff_vector_types() {
-        Matrix2x2::new(Vector2::new(10, 20, VectorType::Row), Vector2::new(10, 20, VectorType::Column));
+    fn not_square() {
+        MatrixNxN::new(vec![VectorN::new(vec![1, 2, 3, 4, 5, 6]); 5]);
+    }
+
+    #[test]
+    fn layout() {
+        let matrix = matrix();
+        assert_eq!(matrix.row(1), &[5, 6, 7, 8, 9]);
+        let transposed = matrix.clone().transpose();
+        assert_eq!(transposed.row(1), &[1, 6, 11, 16, 21]);
+        assert_eq!(
+            MatrixNxN::new(
+                (0..5)
+                    .map(|column| VectorN::new_column(transposed.row(column).to_vec()))
+                    .collect()
+            ),
+            matrix
+        );
+        assert_eq!(MatrixNxN::from_slice(5, matrix.as_slice()), matrix);
+    }
+
+    #[test]
+    fn display() {
+        let matrix = MatrixNxN::from_vec(20, (0..400).collect());
+        assert_eq!(
+            format!("{}", matrix.display().max_rows(2).max_columns(2)),
+            "┌           ┐\n│   0 …  19 │\n│   … …   … │\n│ 380 … 399 │\n└           ┘"
+        );
+    }
+
+    #[test]
+    fn render() {
+        let latex = matrix().to_latex();
+        assert!(latex.starts_with("\\left[ \\begin{array}{ccccc} 0 & 1 & 2 & 3 & 4 \\\\ 5 &"));
+        assert_eq!(latex.matches("\\\\").count(), 4);
+    }
+
+    #[test]
+    fn componentwise() {
+        let matrix = matrix();
+        assert_eq!(matrix.sum(), 300);
+        assert_eq!((matrix.argmin(), matrix.argmax()), ((0, 0), (4, 4)));
+        let floats = matrix.clone().map(|x| x as f64 - 12.0);
+        assert_eq!(floats.clone().abs().max_element(), 12.0);
+        assert_eq!(floats.clamp(0.0, 1.0).sum(), 12.0);
+        assert_eq!(matrix.clone().zip_map(matrix, |a, b| a - b).sum(), 0);
+    }
+
+    #[test]
+    fn overflow() {
+        let matrix = matrix().map(|x| x as i8);
+        assert_eq!(matrix.checked_product(&matrix), None);
+        let identity = MatrixNxN::from_vec(5, (0..25).map(|i| (i % 6 == 0) as i8).collect());
+        assert_eq!(matrix.checked_product(&identity), Some(matrix.clone()));
+        assert_eq!(matrix.saturating_product(&matrix).max_element(), i8::MAX);
+        assert_eq!(
+            matrix.wrapping_product(&matrix).as_slice()[0],
+            (0..5).fold(0i8, |sum, k| sum.wrapping_add((k * k * 5) as i8))
+        );
+        assert_eq!(
+            matrix.clone().checked_mul(0),
+            Some(matrix.clone().map(|_| 0))
+        );
+        assert_eq!(matrix.clone().wrapping_div(-1).as_slice()[24], -24);
     }
-}
\ No newline at end of file
+}
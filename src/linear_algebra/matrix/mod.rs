@@ -1,4 +1,242 @@
+pub mod display;
 pub mod matrix_2x2;
 pub mod matrix_3x3;
 pub mod matrix_4x4;
-// pub mod matrix_nxn;
+#[cfg(feature = "alloc")]
+pub mod matrix_nxn;
+
+macro_rules! layout_impl {
+    ($matrix:ident, $vector:ident, $n:literal) => {
+        impl<T> $matrix<T> {
+            pub fn display(&self) -> $crate::linear_algebra::matrix::display::MatrixDisplay<'_, T> {
+                $crate::linear_algebra::matrix::display::MatrixDisplay::new(self.as_slice(), $n)
+            }
+            pub fn from_rows(rows: [[T; $n]; $n]) -> Self {
+                Self {
+                    vectors: rows.map($vector::from_array),
+                }
+            }
+            pub fn into_rows(self) -> [[T; $n]; $n] {
+                self.vectors.map(|row| row.storage)
+            }
+            /// Applies `f` to every element.
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $matrix<U> {
+                $matrix::from_rows(self.into_rows().map(|row| row.map(&mut f)))
+            }
+            /// Combines corresponding elements of two matrices with `f`.
+            pub fn zip_map<U, V>(
+                self,
+                other: $matrix<U>,
+                mut f: impl FnMut(T, U) -> V,
+            ) -> $matrix<V> {
+                let mut other = other.into_rows().into_iter().flatten();
+                $matrix::from_rows(
+                    self.into_rows()
+                        .map(|row| row.map(|x| f(x, other.next().unwrap()))),
+                )
+            }
+            pub fn rows(&self) -> &[$vector<T>; $n] {
+                &self.vectors
+            }
+            pub fn as_rows(&self) -> &[[T; $n]; $n] {
+                // SAFETY: the matrix has the layout of `[[T; N]; N]`.
+                unsafe { &*(self as *const Self).cast() }
+            }
+            /// Returns the elements in row-major order.
+            pub fn as_slice(&self) -> &[T] {
+                $vector::flatten_slice(&self.vectors)
+            }
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                $vector::flatten_slice_mut(&mut self.vectors)
+            }
+            pub const fn as_ptr(&self) -> *const T {
+                self.vectors.as_ptr().cast()
+            }
+            pub fn as_mut_ptr(&mut self) -> *mut T {
+                self.vectors.as_mut_ptr().cast()
+            }
+            /// Copies row-major elements out of `slice` into a new matrix.
+            ///
+            /// Panics if `slice` does not hold exactly as many elements as the matrix.
+            pub fn from_slice(slice: &[T]) -> Self
+            where
+                T: Copy,
+            {
+                Self::try_from_slice(slice).unwrap_or_else(|error| panic!("{}", error))
+            }
+            pub fn try_from_slice(slice: &[T]) -> Result<Self, $crate::Error>
+            where
+                T: Copy,
+            {
+                $crate::error::check_shape($n * $n, slice.len())?;
+                Ok(Self {
+                    vectors: ::core::array::from_fn(|i| {
+                        $vector::from_slice(&slice[i * $n..(i + 1) * $n])
+                    }),
+                })
+            }
+        }
+    };
+}
+pub(crate) use display::display_impl;
+pub(crate) use layout_impl;
+
+macro_rules! adjoint_impl {
+    ($matrix:ident) => {
+        impl<T: $crate::num::Conjugate + $crate::linear_algebra::Element> $matrix<T> {
+            /// The conjugate transpose, which is the transpose for real elements.
+            pub fn adjoint(self) -> Self {
+                self.transpose().map(|x| x.conjugate())
+            }
+        }
+    };
+}
+pub(crate) use adjoint_impl;
+
+/// Reduces row-major `elements` with `columns` columns to reduced row echelon form by
+/// Gauss–Jordan elimination, choosing pivots as `Pivot` directs. Calls `on_pivot` with each
+/// pivot column in turn and returns the rank.
+pub(crate) fn row_reduce<T: crate::num::Pivot + Copy>(
+    elements: &mut [T],
+    columns: usize,
+    mut on_pivot: impl FnMut(usize),
+) -> usize {
+    let rows = elements.len() / columns;
+    let tolerance = T::tolerance(elements, columns);
+    let mut rank = 0;
+    for column in 0..columns {
+        if rank == rows {
+            break;
+        }
+        let Some(pivot) = (rank..rows)
+            .filter(|&row| elements[row * columns + column].is_pivot(&tolerance))
+            .reduce(|best, row| {
+                if elements[row * columns + column]
+                    .is_better_pivot(&elements[best * columns + column])
+                {
+                    row
+                } else {
+                    best
+                }
+            })
+        else {
+            continue;
+        };
+        for k in 0..columns {
+            elements.swap(pivot * columns + k, rank * columns + k);
+        }
+        let value = elements[rank * columns + column];
+        for k in 0..columns {
+            elements[rank * columns + k] = elements[rank * columns + k] / value;
+        }
+        for row in (0..rows).filter(|&row| row != rank) {
+            let factor = elements[row * columns + column];
+            if !factor.is_zero() {
+                for k in 0..columns {
+                    elements[row * columns + k] =
+                        elements[row * columns + k] - factor * elements[rank * columns + k];
+                }
+            }
+        }
+        on_pivot(column);
+        rank += 1;
+    }
+    rank
+}
+
+/// Adds row reduction, which is exact for exact element types such as `Rational`.
+macro_rules! echelon_impl {
+    ($matrix:ident, $size:expr) => {
+        impl<T: $crate::num::Pivot + Copy> $matrix<T> {
+            /// The reduced row echelon form.
+            pub fn rref(&self) -> Self {
+                let mut reduced = self.clone();
+                let size = $size(self);
+                $crate::linear_algebra::matrix::row_reduce(reduced.as_mut_slice(), size, |_| {});
+                reduced
+            }
+            /// The number of linearly independent rows.
+            pub fn rank(&self) -> usize {
+                let size = $size(self);
+                $crate::linear_algebra::matrix::row_reduce(
+                    self.clone().as_mut_slice(),
+                    size,
+                    |_| {},
+                )
+            }
+        }
+    };
+}
+pub(crate) use echelon_impl;
+
+macro_rules! product_impl {
+    ($matrix:ident, $vector:ident, $n:literal) => {
+        impl<T: $crate::num::Num> $matrix<T> {
+            pub fn identity() -> Self {
+                Self::from_rows(::core::array::from_fn(|i| {
+                    ::core::array::from_fn(|j| if i == j { T::one() } else { T::zero() })
+                }))
+            }
+        }
+
+        impl<T: Copy + ::core::ops::Add<Output = T> + ::core::ops::Mul<Output = T>> $matrix<T> {
+            pub(crate) fn mul_scalar(&self, rhs: &Self) -> Self {
+                Self::from_rows(::core::array::from_fn(|i| {
+                    ::core::array::from_fn(|j| {
+                        (1..$n).fold(
+                            self.vectors[i].storage[0] * rhs.vectors[0].storage[j],
+                            |sum, k| sum + self.vectors[i].storage[k] * rhs.vectors[k].storage[j],
+                        )
+                    })
+                }))
+            }
+        }
+
+        impl<
+                T: Copy
+                    + ::core::ops::Add<Output = T>
+                    + ::core::ops::Mul<Output = T>
+                    + $crate::linear_algebra::Element,
+            > ::core::ops::Mul<$matrix<T>> for $matrix<T>
+        {
+            type Output = $matrix<T>;
+
+            fn mul(self, rhs: $matrix<T>) -> Self::Output {
+                #[cfg(feature = "nightly")]
+                if let Some(rows) = $crate::nightly::matrix_mul(self.as_rows(), rhs.as_rows()) {
+                    return Self::from_rows(rows);
+                }
+                self.mul_scalar(&rhs)
+            }
+        }
+
+        impl<T: Copy + ::core::ops::Add<Output = T> + ::core::ops::Mul<Output = T>>
+            ::core::ops::Mul<$vector<T, $crate::linear_algebra::vector::Column>> for $matrix<T>
+        {
+            type Output = $vector<T, $crate::linear_algebra::vector::Column>;
+
+            fn mul(self, rhs: $vector<T, $crate::linear_algebra::vector::Column>) -> Self::Output {
+                $vector::from_array(::core::array::from_fn(|i| {
+                    (1..$n).fold(self.vectors[i].storage[0] * rhs.storage[0], |sum, k| {
+                        sum + self.vectors[i].storage[k] * rhs.storage[k]
+                    })
+                }))
+            }
+        }
+
+        impl<T: Copy + ::core::ops::Add<Output = T> + ::core::ops::Mul<Output = T>>
+            ::core::ops::Mul<$matrix<T>> for $vector<T>
+        {
+            type Output = $vector<T>;
+
+            fn mul(self, rhs: $matrix<T>) -> Self::Output {
+                $vector::from_array(::core::array::from_fn(|j| {
+                    (1..$n).fold(self.storage[0] * rhs.vectors[0].storage[j], |sum, k| {
+                        sum + self.storage[k] * rhs.vectors[k].storage[j]
+                    })
+                }))
+            }
+        }
+    };
+}
+pub(crate) use product_impl;
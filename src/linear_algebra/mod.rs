@@ -1,2 +1,27 @@
+mod componentwise;
+pub mod dual_quaternion;
 pub mod matrix;
+mod overflow;
+pub mod quaternion;
+#[cfg(feature = "alloc")]
+pub mod render;
+pub mod rotation;
 pub mod vector;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "nightly")] {
+        /// Element types of the operations with SIMD kernels. With the `nightly` feature it
+        /// requires `'static`, so that kernels can be selected by type; otherwise every type
+        /// implements it.
+        pub trait Element: 'static {}
+
+        impl<T: 'static> Element for T {}
+    } else {
+        /// Element types of the operations with SIMD kernels. With the `nightly` feature it
+        /// requires `'static`, so that kernels can be selected by type; otherwise every type
+        /// implements it.
+        pub trait Element {}
+
+        impl<T> Element for T {}
+    }
+}
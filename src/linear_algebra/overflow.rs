@@ -0,0 +1,128 @@
+/// Adds the `checked_*`, `saturating_*` and `wrapping_*` families for every operator. The plain
+/// methods take a scalar like the operators, the `_elementwise` methods combine corresponding
+/// elements, and on matrices the `_product` methods compute the matrix product.
+macro_rules! overflow_impl {
+    (vector $vector:ident) => {
+        impl<T: $crate::num::Checked + Copy, O: $crate::linear_algebra::vector::Orientation>
+            $vector<T, O>
+        {
+            $crate::linear_algebra::overflow::overflow_impl!(@checked add sub mul div);
+        }
+        impl<T: $crate::num::Saturating + Copy, O: $crate::linear_algebra::vector::Orientation>
+            $vector<T, O>
+        {
+            $crate::linear_algebra::overflow::overflow_impl!(@total saturating);
+        }
+        impl<T: $crate::num::Wrapping + Copy, O: $crate::linear_algebra::vector::Orientation>
+            $vector<T, O>
+        {
+            $crate::linear_algebra::overflow::overflow_impl!(@total wrapping);
+        }
+    };
+    (matrix $matrix:ident, $columns:expr) => {
+        impl<T: $crate::num::Checked + Copy> $matrix<T> {
+            $crate::linear_algebra::overflow::overflow_impl!(@checked add sub mul div);
+            /// The matrix product, or `None` if any step overflows.
+            pub fn checked_product(&self, rhs: &Self) -> Option<Self> {
+                let n = ($columns)(self);
+                let (lhs, right) = (self.as_slice(), rhs.as_slice());
+                let mut product = self.clone();
+                for (index, element) in product.as_mut_slice().iter_mut().enumerate() {
+                    let (i, j) = (index / n, index % n);
+                    *element = (1..n).try_fold(lhs[i * n].checked_mul(&right[j])?, |sum, k| {
+                        sum.checked_add(&lhs[i * n + k].checked_mul(&right[k * n + j])?)
+                    })?;
+                }
+                Some(product)
+            }
+        }
+        impl<T: $crate::num::Saturating + Copy> $matrix<T> {
+            $crate::linear_algebra::overflow::overflow_impl!(@total saturating);
+            /// The matrix product, with each element clamped once after summing exactly.
+            pub fn saturating_product(&self, rhs: &Self) -> Self {
+                let n = ($columns)(self);
+                let (lhs, right) = (self.as_slice(), rhs.as_slice());
+                let mut product = self.clone();
+                for (index, element) in product.as_mut_slice().iter_mut().enumerate() {
+                    let (i, j) = (index / n, index % n);
+                    *element = T::saturating_dot((0..n).map(|k| (lhs[i * n + k], right[k * n + j])));
+                }
+                product
+            }
+        }
+        impl<T: $crate::num::Wrapping + Copy> $matrix<T> {
+            $crate::linear_algebra::overflow::overflow_impl!(@total wrapping);
+            /// The matrix product, wrapping at every step.
+            pub fn wrapping_product(&self, rhs: &Self) -> Self {
+                let n = ($columns)(self);
+                let (lhs, right) = (self.as_slice(), rhs.as_slice());
+                let mut product = self.clone();
+                for (index, element) in product.as_mut_slice().iter_mut().enumerate() {
+                    let (i, j) = (index / n, index % n);
+                    *element = (1..n).fold(lhs[i * n].wrapping_mul(&right[j]), |sum, k| {
+                        sum.wrapping_add(&lhs[i * n + k].wrapping_mul(&right[k * n + j]))
+                    });
+                }
+                product
+            }
+        }
+    };
+    (@checked $($op:ident)*) => {
+        ::paste::paste! {
+            $(
+                pub fn [<checked_ $op>](self, rhs: T) -> Option<Self> {
+                    self.checked_map(|x| x.[<checked_ $op>](&rhs))
+                }
+                pub fn [<checked_ $op _elementwise>](self, rhs: Self) -> Option<Self> {
+                    let mut overflowed = false;
+                    let result = self.zip_map(rhs, |a, b| {
+                        a.[<checked_ $op>](&b).unwrap_or_else(|| {
+                            overflowed = true;
+                            a
+                        })
+                    });
+                    (!overflowed).then_some(result)
+                }
+            )*
+        }
+        fn checked_map(self, f: impl Fn(T) -> Option<T>) -> Option<Self> {
+            let mut overflowed = false;
+            let result = self.map(|x| {
+                f(x).unwrap_or_else(|| {
+                    overflowed = true;
+                    x
+                })
+            });
+            (!overflowed).then_some(result)
+        }
+    };
+    (@total $prefix:ident) => {
+        ::paste::paste! {
+            pub fn [<$prefix _add>](self, rhs: T) -> Self {
+                self.map(|x| x.[<$prefix _add>](&rhs))
+            }
+            pub fn [<$prefix _sub>](self, rhs: T) -> Self {
+                self.map(|x| x.[<$prefix _sub>](&rhs))
+            }
+            pub fn [<$prefix _mul>](self, rhs: T) -> Self {
+                self.map(|x| x.[<$prefix _mul>](&rhs))
+            }
+            pub fn [<$prefix _div>](self, rhs: T) -> Self {
+                self.map(|x| x.[<$prefix _div>](&rhs))
+            }
+            pub fn [<$prefix _add_elementwise>](self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a.[<$prefix _add>](&b))
+            }
+            pub fn [<$prefix _sub_elementwise>](self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a.[<$prefix _sub>](&b))
+            }
+            pub fn [<$prefix _mul_elementwise>](self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a.[<$prefix _mul>](&b))
+            }
+            pub fn [<$prefix _div_elementwise>](self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a.[<$prefix _div>](&b))
+            }
+        }
+    };
+}
+pub(crate) use overflow_impl;
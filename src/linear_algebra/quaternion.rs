@@ -0,0 +1,384 @@
+use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::Orientation;
+use crate::num::{Num, Real};
+
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A quaternion `w + xi + yj + zk`.
+///
+/// Rotations are represented by unit quaternions and act on vectors as `q v q⁻¹`. The matrix
+/// conversions use the column vector convention, so `q.rotate(v)` equals `q.to_matrix3x3() * v`
+/// for column vectors.
+#[repr(C)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T> {
+    pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+    pub fn from_parts<O: Orientation>(scalar: T, vector: Vector3<T, O>) -> Self {
+        let [x, y, z] = vector.storage;
+        Self::new(scalar, x, y, z)
+    }
+}
+
+impl<T: Num + Copy> Quaternion<T> {
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+    pub fn scalar(self) -> T {
+        self.w
+    }
+    pub fn vector(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+    pub fn dot(self, rhs: Self) -> T {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Num + Neg<Output = T> + Copy> Quaternion<T> {
+    pub fn conjugate(self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+    /// Returns the multiplicative inverse, or `None` for the zero quaternion.
+    pub fn inverse(self) -> Option<Self> {
+        let norm_squared = self.norm_squared();
+        if norm_squared.is_zero() {
+            return None;
+        }
+        let conjugate = self.conjugate();
+        Some(Self::new(
+            conjugate.w / norm_squared,
+            conjugate.x / norm_squared,
+            conjugate.y / norm_squared,
+            conjugate.z / norm_squared,
+        ))
+    }
+}
+
+impl<T: Real> Quaternion<T> {
+    pub fn norm(self) -> T {
+        self.norm_squared().sqrt()
+    }
+    pub fn normalize(self) -> Self {
+        self * (T::one() / self.norm())
+    }
+    /// The rotation by `angle` radians around `axis`, which does not need to be normalized.
+    pub fn from_axis_angle<O: Orientation>(axis: Vector3<T, O>, angle: T) -> Self {
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        Self::from_parts(cos, axis.normalize() * sin)
+    }
+    /// The shortest rotation taking the direction of `from` to the direction of `to`.
+    ///
+    /// When the directions are opposite, the rotation is half a turn around an arbitrary axis
+    /// perpendicular to `from`.
+    pub fn from_rotation_arc<O: Orientation>(from: Vector3<T, O>, to: Vector3<T, O>) -> Self {
+        let (from, to) = (from.normalize(), to.normalize());
+        let dot = from.dot(to);
+        let tolerance = T::from_f64(1e-6);
+        if dot >= T::one() - tolerance {
+            return Self::identity();
+        }
+        if dot <= tolerance - T::one() {
+            let (zero, one) = (T::zero(), T::one());
+            let mut axis = Vector3::from_array([one, zero, zero]).cross(from);
+            if axis.length_squared() < tolerance {
+                axis = Vector3::from_array([zero, one, zero]).cross(from);
+            }
+            return Self::from_parts(zero, axis.normalize());
+        }
+        Self::from_parts(T::one() + dot, from.cross(to)).normalize()
+    }
+    /// Rotates `vector` by this quaternion, which must be normalized.
+    pub fn rotate<O: Orientation>(self, vector: Vector3<T, O>) -> Vector3<T, O> {
+        let u = Vector3::<T, O>::from_array([self.x, self.y, self.z]);
+        let t = u.cross(vector) * (T::one() + T::one());
+        let ut = u.cross(t);
+        Vector3::from_array(core::array::from_fn(|i| {
+            vector.storage[i] + self.w * t.storage[i] + ut.storage[i]
+        }))
+    }
+    /// Normalized linear interpolation along the shorter arc.
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        (self * (T::one() - t) + other * t).normalize()
+    }
+    /// Spherical linear interpolation along the shorter arc, at constant angular velocity.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let (other, cos) = match self.dot(other) {
+            cos if cos < T::zero() => (-other, -cos),
+            cos => (other, cos),
+        };
+        if cos > T::one() - T::from_f64(1e-6) {
+            return self.nlerp(other, t);
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        self * (((T::one() - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+    }
+    /// The rotation matrix of this quaternion, which does not need to be normalized.
+    pub fn to_matrix3x3(self) -> Matrix3x3<T> {
+        let Self { w, x, y, z } = self;
+        let one = T::one();
+        let s = (one + one) / self.norm_squared();
+        Matrix3x3::from_rows([
+            [
+                one - s * (y * y + z * z),
+                s * (x * y - w * z),
+                s * (x * z + w * y),
+            ],
+            [
+                s * (x * y + w * z),
+                one - s * (x * x + z * z),
+                s * (y * z - w * x),
+            ],
+            [
+                s * (x * z - w * y),
+                s * (y * z + w * x),
+                one - s * (x * x + y * y),
+            ],
+        ])
+    }
+    pub fn to_matrix4x4(self) -> Matrix4x4<T> {
+        let [r0, r1, r2] = self.to_matrix3x3().into_rows();
+        let (zero, one) = (T::zero(), T::one());
+        Matrix4x4::from_rows([
+            [r0[0], r0[1], r0[2], zero],
+            [r1[0], r1[1], r1[2], zero],
+            [r2[0], r2[1], r2[2], zero],
+            [zero, zero, zero, one],
+        ])
+    }
+    /// The unit quaternion of a rotation matrix, with a non-negative `w`.
+    pub fn from_matrix3x3(matrix: &Matrix3x3<T>) -> Self {
+        let m = matrix.as_rows();
+        let (one, two) = (T::one(), T::one() + T::one());
+        let four = two + two;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let quaternion = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                s / four,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                s / four,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Self::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / four,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Self::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / four,
+            )
+        };
+        if quaternion.w < T::zero() {
+            -quaternion
+        } else {
+            quaternion
+        }
+    }
+    /// The unit quaternion of the rotation in the upper left 3x3 block of `matrix`.
+    pub fn from_matrix4x4(matrix: &Matrix4x4<T>) -> Self {
+        let m = matrix.as_rows();
+        Self::from_matrix3x3(&Matrix3x3::from_rows(core::array::from_fn(|i| {
+            [m[i][0], m[i][1], m[i][2]]
+        })))
+    }
+}
+
+impl<T: Num + Copy> Add for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl<T: Num + Copy> Sub for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl<T: Num + Neg<Output = T> + Copy> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+/// The Hamilton product.
+impl<T: Num + Copy> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Display> Display for Quaternion<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(fmt, "{} + {}i + {}j + {}k", self.w, self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn hamilton_product() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let k = Quaternion::new(0, 0, 0, 1);
+        let minus_one = Quaternion::new(-1, 0, 0, 0);
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(k * k, minus_one);
+        assert_eq!(i * j * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+    }
+
+    #[test]
+    fn inverse() {
+        let q = Quaternion::new(1.0, 2.0, -2.0, 4.0);
+        assert_eq!(q.conjugate(), Quaternion::new(1.0, -2.0, 2.0, -4.0));
+        assert_eq!(q.norm(), 5.0);
+        assert_close(q * q.inverse().unwrap(), Quaternion::identity());
+        assert_close(q.normalize(), Quaternion::new(0.2, 0.4, -0.4, 0.8));
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+    }
+
+    #[test]
+    fn rotation() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+        assert_close(
+            q.rotate(Vector3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert_close(
+            (q * q).rotate(Vector3::new(1.0, 2.0, 3.0)),
+            Vector3::new(-1.0, -2.0, 3.0),
+        );
+        let column = Vector3::new_column(0.5, -1.0, 2.0);
+        let rotated = q.to_matrix3x3() * column;
+        assert_close(q.rotate(column).transpose(), rotated.transpose());
+    }
+
+    #[test]
+    fn rotation_arc() {
+        let from = Vector3::new(1.0, 1.0, 0.0);
+        let to = Vector3::new(0.0, 0.0, 3.0);
+        let q = Quaternion::from_rotation_arc(from, to);
+        assert_close(q.rotate(from.normalize()), to.normalize());
+
+        let opposite = Quaternion::from_rotation_arc(from, from * -1.0);
+        assert_close(opposite.rotate(from), Vector3::new(-1.0, -1.0, 0.0));
+        let x = Vector3::new(2.0, 0.0, 0.0);
+        assert_close(
+            Quaternion::from_rotation_arc(x, x * -1.0).rotate(x),
+            Vector3::new(-2.0, 0.0, 0.0),
+        );
+        assert_close(Quaternion::from_rotation_arc(x, x), Quaternion::identity());
+    }
+
+    #[test]
+    fn interpolation() {
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), PI / 2.0);
+        let halfway = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), PI / 4.0);
+        assert_close(a.slerp(b, 0.5), halfway);
+        assert_close(a.nlerp(b, 0.5), halfway);
+        assert_close(a.slerp(b, 0.0), a);
+        assert_close(a.slerp(b, 1.0), b);
+        assert_close(a.slerp(-b, 0.5), halfway);
+
+        let quarter = a.slerp(b, 0.25);
+        assert_close(
+            quarter,
+            Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), PI / 8.0),
+        );
+    }
+
+    #[test]
+    fn matrix_conversion() {
+        let axes = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, -2.0, 3.0),
+        ];
+        for axis in axes {
+            for angle in [0.0, 0.3, 1.0, 2.0, 3.0, PI] {
+                let q = Quaternion::from_axis_angle(axis, angle);
+                let q = if q.w < 0.0 { -q } else { q };
+                assert_close(Quaternion::from_matrix3x3(&q.to_matrix3x3()), q);
+                assert_close(Quaternion::from_matrix4x4(&q.to_matrix4x4()), q);
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum Delimiter {
+    /// `[ ]`
+    #[default]
+    Bracket,
+    /// `( )`
+    Parenthesis,
+    /// `| |`
+    Bar,
+}
+
+/// Options for rendering vectors and matrices as LaTeX, Typst or MathML.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct RenderOptions {
+    pub delimiter: Delimiter,
+    pub precision: Option<usize>,
+}
+
+impl RenderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn delimiter(mut self, delimiter: Delimiter) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    fn element<T: Display>(&self, element: &T) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, element),
+            None => element.to_string(),
+        }
+    }
+}
+
+fn rows<T>(elements: &[T], columns: usize) -> impl Iterator<Item = &[T]> {
+    elements.chunks(columns)
+}
+
+/// Escapes the characters that are special in LaTeX math mode.
+fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\\' => escaped.push_str("\\backslash{}"),
+            '~' => escaped.push_str("\\sim{}"),
+            '^' => escaped.push_str("\\hat{}"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders an `array` between `\left` and `\right` delimiters rather than amsmath's `bmatrix`
+/// and friends, which fail on more than ten columns unless `MaxMatrixCols` is raised.
+pub(crate) fn latex<T: Display>(elements: &[T], columns: usize, options: RenderOptions) -> String {
+    let (open, close) = match options.delimiter {
+        Delimiter::Bracket => ("[", "]"),
+        Delimiter::Parenthesis => ("(", ")"),
+        Delimiter::Bar => ("|", "|"),
+    };
+    let alignment = "c".repeat(columns);
+    let body = rows(elements, columns)
+        .map(|row| {
+            row.iter()
+                .map(|element| escape_latex(&options.element(element)))
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect::<Vec<_>>()
+        .join(" \\\\ ");
+    format!("\\left{open} \\begin{{array}}{{{alignment}}} {body} \\end{{array}} \\right{close}")
+}
+
+pub(crate) fn typst<T: Display>(elements: &[T], columns: usize, options: RenderOptions) -> String {
+    let delimiter = match options.delimiter {
+        Delimiter::Bracket => "[",
+        Delimiter::Parenthesis => "(",
+        Delimiter::Bar => "|",
+    };
+    let body = rows(elements, columns)
+        .map(|row| {
+            row.iter()
+                .map(|element| options.element(element))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    format!("mat(delim: \"{delimiter}\", {body})")
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(crate) fn mathml<T: Display>(elements: &[T], columns: usize, options: RenderOptions) -> String {
+    let (open, close) = match options.delimiter {
+        Delimiter::Bracket => ("[", "]"),
+        Delimiter::Parenthesis => ("(", ")"),
+        Delimiter::Bar => ("|", "|"),
+    };
+    let mut mathml = format!("<math><mrow><mo>{open}</mo><mtable>");
+    for row in rows(elements, columns) {
+        mathml.push_str("<mtr>");
+        for element in row {
+            write!(
+                mathml,
+                "<mtd><mn>{}</mn></mtd>",
+                escape_xml(&options.element(element))
+            )
+            .unwrap();
+        }
+        mathml.push_str("</mtr>");
+    }
+    write!(mathml, "</mtable><mo>{close}</mo></mrow></math>").unwrap();
+    mathml
+}
+
+macro_rules! render_methods {
+    ($columns:expr) => {
+        pub fn to_latex(&self) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            self.to_latex_with($crate::linear_algebra::render::RenderOptions::default())
+        }
+        pub fn to_latex_with(
+            &self,
+            options: $crate::linear_algebra::render::RenderOptions,
+        ) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            $crate::linear_algebra::render::latex(self.as_slice(), ($columns)(self), options)
+        }
+        pub fn to_typst(&self) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            self.to_typst_with($crate::linear_algebra::render::RenderOptions::default())
+        }
+        pub fn to_typst_with(
+            &self,
+            options: $crate::linear_algebra::render::RenderOptions,
+        ) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            $crate::linear_algebra::render::typst(self.as_slice(), ($columns)(self), options)
+        }
+        pub fn to_mathml(&self) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            self.to_mathml_with($crate::linear_algebra::render::RenderOptions::default())
+        }
+        pub fn to_mathml_with(
+            &self,
+            options: $crate::linear_algebra::render::RenderOptions,
+        ) -> ::alloc::string::String
+        where
+            T: ::core::fmt::Display,
+        {
+            $crate::linear_algebra::render::mathml(self.as_slice(), ($columns)(self), options)
+        }
+    };
+}
+
+/// Adds `to_latex`, `to_typst` and `to_mathml` to a vector or matrix type. Column vectors are
+/// rendered as a single column.
+macro_rules! render_impl {
+    (vector $vector:ident) => {
+        impl<T, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            $crate::linear_algebra::render::render_methods!(|vector: &Self| match O::VECTOR_TYPE {
+                $crate::linear_algebra::vector::VectorType::Row => vector.as_slice().len(),
+                $crate::linear_algebra::vector::VectorType::Column => 1,
+            });
+        }
+    };
+    (matrix $matrix:ident, $columns:expr) => {
+        impl<T> $matrix<T> {
+            $crate::linear_algebra::render::render_methods!($columns);
+        }
+    };
+}
+pub(crate) use render_impl;
+pub(crate) use render_methods;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latex() {
+        assert_eq!(
+            super::latex(&[1, 2, 3, 4], 2, RenderOptions::new()),
+            "\\left[ \\begin{array}{cc} 1 & 2 \\\\ 3 & 4 \\end{array} \\right]"
+        );
+        assert_eq!(
+            super::latex(
+                &[1.0, 2.5],
+                1,
+                RenderOptions::new().delimiter(Delimiter::Bar).precision(2)
+            ),
+            "\\left| \\begin{array}{c} 1.00 \\\\ 2.50 \\end{array} \\right|"
+        );
+        let wide = super::latex(&[0; 12], 12, RenderOptions::new());
+        assert!(wide.starts_with("\\left[ \\begin{array}{cccccccccccc} 0 & 0"));
+        assert_eq!(
+            super::latex(&["50%", "a_1", "{x}", "\\"], 4, RenderOptions::new()),
+            "\\left[ \\begin{array}{cccc} 50\\% & a\\_1 & \\{x\\} & \\backslash{} \\end{array} \\right]"
+        );
+    }
+
+    #[test]
+    fn typst() {
+        assert_eq!(
+            super::typst(
+                &[1, -2, 3, 4],
+                2,
+                RenderOptions::new().delimiter(Delimiter::Parenthesis)
+            ),
+            "mat(delim: \"(\", 1, -2; 3, 4)"
+        );
+    }
+
+    #[test]
+    fn mathml() {
+        assert_eq!(
+            super::mathml(&["a<b", "c"], 2, RenderOptions::new()),
+            "<math><mrow><mo>[</mo><mtable><mtr><mtd><mn>a&lt;b</mn></mtd><mtd><mn>c</mn></mtd></mtr></mtable><mo>]</mo></mrow></math>"
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+use crate::linear_algebra::quaternion::Quaternion;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::Orientation;
+use crate::num::Real;
+
+/// The axes of an Euler angle sequence, in the order the angles are applied.
+///
+/// The first six are Tait-Bryan sequences using every axis once, the last six are proper Euler
+/// sequences repeating the first axis.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+/// Whether each Euler angle rotates around the axes of the rotating body or of the fixed frame.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EulerFrame {
+    /// Each rotation is around an axis of the body as already rotated by the previous angles, so
+    /// `XYZ` angles `[a, b, c]` give `Rx(a) * Ry(b) * Rz(c)`.
+    Intrinsic,
+    /// Each rotation is around an axis of the fixed frame, so `XYZ` angles `[a, b, c]` give
+    /// `Rz(c) * Ry(b) * Rx(a)`.
+    Extrinsic,
+}
+
+impl<T: Real> Quaternion<T> {
+    fn from_axis_index(axis: usize, angle: T) -> Self {
+        let (sin, cos) = (angle / (T::one() + T::one())).sin_cos();
+        let mut vector = [T::zero(); 3];
+        vector[axis] = sin;
+        Self::from_parts(cos, Vector3::new(vector[0], vector[1], vector[2]))
+    }
+    /// The rotation given by Euler angles in radians, applied in the order of `order`.
+    pub fn from_euler(angles: [T; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        let [a, b, c] = order.axes();
+        let [a, b, c] = [
+            Self::from_axis_index(a, angles[0]),
+            Self::from_axis_index(b, angles[1]),
+            Self::from_axis_index(c, angles[2]),
+        ];
+        match frame {
+            EulerFrame::Intrinsic => a * b * c,
+            EulerFrame::Extrinsic => c * b * a,
+        }
+    }
+    /// The Euler angles in radians of this rotation, which does not need to be normalized.
+    ///
+    /// The first and last angles lie in `-π..=π`. The middle angle lies in `-π/2..=π/2` for
+    /// Tait-Bryan orders and in `0..=π` for proper Euler orders. In gimbal lock, when the first
+    /// and last axes line up, only their combined rotation is determined: it is returned as the
+    /// last angle for intrinsic rotations and the first angle for extrinsic rotations, and the
+    /// other angle is zero.
+    pub fn to_euler(self, order: EulerOrder, frame: EulerFrame) -> [T; 3] {
+        // Bernardes and Viollet, "Quaternion to Euler angles conversion: A direct, general and
+        // computationally efficient method", 2022. Intrinsic angles are extrinsic angles around
+        // the reversed sequence of axes.
+        let extrinsic = frame == EulerFrame::Extrinsic;
+        let [i, j, k] = match frame {
+            EulerFrame::Extrinsic => order.axes(),
+            EulerFrame::Intrinsic => {
+                let [i, j, k] = order.axes();
+                [k, j, i]
+            }
+        };
+        let symmetric = i == k;
+        let k = if symmetric { 3 - i - j } else { k };
+        let sign = if (i + 1) % 3 == j {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let q = [self.x, self.y, self.z];
+        let (a, b, c, d) = if symmetric {
+            (self.w, q[i], q[j], q[k] * sign)
+        } else {
+            (
+                self.w - q[j],
+                q[i] + q[k] * sign,
+                q[j] + self.w,
+                q[k] * sign - q[i],
+            )
+        };
+
+        let (zero, two) = (T::zero(), T::one() + T::one());
+        let pi = T::pi();
+        let hypot = |x: T, y: T| (x * x + y * y).sqrt();
+        let mut angles = [zero; 3];
+        angles[1] = two * hypot(c, d).atan2(hypot(a, b));
+        let half_sum = b.atan2(a);
+        let half_difference = d.atan2(c);
+        let tolerance = T::epsilon().sqrt();
+        if angles[1].abs() <= tolerance {
+            angles[0] = two * half_sum;
+        } else if (angles[1] - pi).abs() <= tolerance {
+            angles[0] = -two * half_difference;
+        } else {
+            angles[0] = half_sum - half_difference;
+            angles[2] = half_sum + half_difference;
+        }
+        if !symmetric {
+            angles[2] = angles[2] * sign;
+            angles[1] = angles[1] - pi / two;
+        }
+        if !extrinsic {
+            angles.swap(0, 2);
+        }
+        angles.map(|angle| {
+            if angle < -pi {
+                angle + two * pi
+            } else if angle > pi {
+                angle - two * pi
+            } else {
+                angle
+            }
+        })
+    }
+    /// The normalized rotation axis and the angle in `0..=π` radians of this rotation, which does
+    /// not need to be normalized.
+    ///
+    /// The identity rotation has no axis of its own and returns the X axis.
+    pub fn to_axis_angle(self) -> (Vector3<T>, T) {
+        let q = if self.w < T::zero() { -self } else { self };
+        let vector = q.vector();
+        let sin = vector.length();
+        let angle = (T::one() + T::one()) * sin.atan2(q.w);
+        if sin <= T::epsilon() * q.w {
+            return (Vector3::new(T::one(), T::zero(), T::zero()), angle);
+        }
+        (vector / sin, angle)
+    }
+}
+
+impl<T: Real> Matrix3x3<T> {
+    /// The rotation by `angle` radians around `axis`, which does not need to be normalized.
+    pub fn from_axis_angle<O: Orientation>(axis: Vector3<T, O>, angle: T) -> Self {
+        Quaternion::from_axis_angle(axis, angle).to_matrix3x3()
+    }
+    /// The normalized rotation axis and the angle in `0..=π` radians of this rotation matrix.
+    pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+        Quaternion::from_matrix3x3(self).to_axis_angle()
+    }
+    /// The rotation matrix given by Euler angles in radians, applied in the order of `order`.
+    pub fn from_euler(angles: [T; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        Quaternion::from_euler(angles, order, frame).to_matrix3x3()
+    }
+    /// The Euler angles in radians of this rotation matrix, with the conventions of
+    /// `Quaternion::to_euler`.
+    pub fn to_euler(&self, order: EulerOrder, frame: EulerFrame) -> [T; 3] {
+        Quaternion::from_matrix3x3(self).to_euler(order, frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_matrix_close(a: &Matrix3x3<f64>, b: &Matrix3x3<f64>) {
+        assert!(
+            a.as_slice()
+                .iter()
+                .zip(b.as_slice())
+                .all(|(a, b)| (a - b).abs() < 1e-9),
+            "{a} is not close to {b}"
+        );
+    }
+
+    fn assert_angles_close(a: [f64; 3], b: [f64; 3]) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-9),
+            "{a:?} is not close to {b:?}"
+        );
+    }
+
+    fn elementary(axis: usize, angle: f64) -> Matrix3x3<f64> {
+        let mut vector = [0.0; 3];
+        vector[axis] = 1.0;
+        Matrix3x3::from_axis_angle(Vector3::<f64>::from_array(vector), angle)
+    }
+
+    #[test]
+    fn conventions() {
+        let angles = [0.1, 0.2, 0.3];
+        for order in EulerOrder::ALL {
+            let [i, j, k] = order.axes();
+            let [a, b, c] = [
+                elementary(i, angles[0]),
+                elementary(j, angles[1]),
+                elementary(k, angles[2]),
+            ];
+            assert_matrix_close(
+                &Matrix3x3::from_euler(angles, order, EulerFrame::Intrinsic),
+                &(a * b * c),
+            );
+            assert_matrix_close(
+                &Matrix3x3::from_euler(angles, order, EulerFrame::Extrinsic),
+                &(c * b * a),
+            );
+        }
+        assert_matrix_close(
+            &Matrix3x3::from_euler(
+                [0.0, 0.0, FRAC_PI_2],
+                EulerOrder::XYZ,
+                EulerFrame::Intrinsic,
+            ),
+            &Matrix3x3::from_rows([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]),
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let samples: [[f64; 3]; 4] = [
+            [0.1, 0.2, 0.3],
+            [-2.5, 1.0, 3.0],
+            [3.0, -1.2, -0.4],
+            [0.0, 0.7, 0.0],
+        ];
+        for order in EulerOrder::ALL {
+            let symmetric = order.axes()[0] == order.axes()[2];
+            for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic] {
+                for angles in samples {
+                    let angles = if symmetric {
+                        [angles[0], angles[1].abs() + 0.5, angles[2]]
+                    } else {
+                        angles
+                    };
+                    let matrix = Matrix3x3::from_euler(angles, order, frame);
+                    assert_angles_close(matrix.to_euler(order, frame), angles);
+                    let quaternion = Quaternion::from_euler(angles, order, frame);
+                    assert_angles_close((-quaternion).to_euler(order, frame), angles);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gimbal_lock() {
+        for order in EulerOrder::ALL {
+            let symmetric = order.axes()[0] == order.axes()[2];
+            let locked = if symmetric {
+                [0.0, PI]
+            } else {
+                [-FRAC_PI_2, FRAC_PI_2]
+            };
+            for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic] {
+                for middle in locked {
+                    let matrix = Matrix3x3::from_euler([0.4, middle, 0.9], order, frame);
+                    let angles = matrix.to_euler(order, frame);
+                    assert!((angles[1] - middle).abs() < 1e-9);
+                    match frame {
+                        EulerFrame::Intrinsic => assert_eq!(angles[0], 0.0),
+                        EulerFrame::Extrinsic => assert_eq!(angles[2], 0.0),
+                    }
+                    assert_matrix_close(&Matrix3x3::from_euler(angles, order, frame), &matrix);
+                }
+            }
+        }
+
+        // Yaw and roll collapse into a single angle once the pitch reaches a quarter turn.
+        let angles = Matrix3x3::from_euler(
+            [0.3, FRAC_PI_2, 0.2],
+            EulerOrder::ZYX,
+            EulerFrame::Intrinsic,
+        )
+        .to_euler(EulerOrder::ZYX, EulerFrame::Intrinsic);
+        assert_angles_close(angles, [0.0, FRAC_PI_2, -0.1]);
+    }
+
+    #[test]
+    fn axis_angle() {
+        let axis = Vector3::new(1.0f64, -2.0, 2.0);
+        let matrix = Matrix3x3::from_axis_angle(axis, 2.0);
+        let (result, angle) = matrix.to_axis_angle();
+        assert!((angle - 2.0).abs() < 1e-12);
+        assert!(result
+            .storage
+            .iter()
+            .zip([1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0])
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+        let (result, angle) = Quaternion::from_axis_angle(axis, -2.0).to_axis_angle();
+        assert!((angle - 2.0).abs() < 1e-12);
+        assert!((result.storage[0] + 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(
+            Quaternion::<f64>::identity().to_axis_angle(),
+            (Vector3::new(1.0, 0.0, 0.0), 0.0)
+        );
+        assert_matrix_close(
+            &Matrix3x3::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.5),
+            &Matrix3x3::from_euler([0.0, 0.0, 0.5], EulerOrder::XYZ, EulerFrame::Extrinsic),
+        );
+    }
+}
@@ -1,10 +1,224 @@
 pub mod vector_2;
 pub mod vector_3;
 pub mod vector_4;
+#[cfg(feature = "alloc")]
 pub mod vector_n;
 
+use core::fmt::Debug;
+use core::hash::Hash;
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum VectorType {
     Row,
     Column,
 }
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Row {}
+    impl Sealed for super::Column {}
+}
+
+/// Type-level orientation of a vector.
+///
+/// Orientation is carried as a zero-sized type parameter rather than a field so
+/// that vectors have exactly the layout of their element array.
+pub trait Orientation: sealed::Sealed + Copy + Default + Eq + Hash + Debug + 'static {
+    const VECTOR_TYPE: VectorType;
+
+    type Transposed: Orientation<Transposed = Self>;
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Row;
+
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Column;
+
+impl Orientation for Row {
+    const VECTOR_TYPE: VectorType = VectorType::Row;
+
+    type Transposed = Column;
+}
+
+impl Orientation for Column {
+    const VECTOR_TYPE: VectorType = VectorType::Column;
+
+    type Transposed = Row;
+}
+
+macro_rules! layout_impl {
+    ($vector:ident, $n:literal) => {
+        impl<T, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            pub const fn from_array(storage: [T; $n]) -> Self {
+                Self {
+                    storage,
+                    orientation: ::core::marker::PhantomData,
+                }
+            }
+            /// Applies `f` to every element.
+            pub fn map<U>(self, f: impl FnMut(T) -> U) -> $vector<U, O> {
+                $vector::from_array(self.storage.map(f))
+            }
+            /// Combines corresponding elements of two vectors with `f`.
+            pub fn zip_map<U, V>(
+                self,
+                other: $vector<U, O>,
+                mut f: impl FnMut(T, U) -> V,
+            ) -> $vector<V, O> {
+                let mut other = other.storage.into_iter();
+                $vector::from_array(self.storage.map(|x| f(x, other.next().unwrap())))
+            }
+            pub const fn vector_type(&self) -> $crate::linear_algebra::vector::VectorType {
+                O::VECTOR_TYPE
+            }
+            pub fn transpose(self) -> $vector<T, O::Transposed> {
+                $vector::from_array(self.storage)
+            }
+            pub fn as_slice(&self) -> &[T] {
+                &self.storage
+            }
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.storage
+            }
+            pub const fn as_ptr(&self) -> *const T {
+                self.storage.as_ptr()
+            }
+            pub fn as_mut_ptr(&mut self) -> *mut T {
+                self.storage.as_mut_ptr()
+            }
+            /// Copies the elements of `slice` into a new vector.
+            ///
+            /// Panics if `slice` does not hold exactly as many elements as the vector.
+            pub fn from_slice(slice: &[T]) -> Self
+            where
+                T: Copy,
+            {
+                Self::try_from_slice(slice).unwrap_or_else(|error| panic!("{}", error))
+            }
+            pub fn try_from_slice(slice: &[T]) -> Result<Self, $crate::Error>
+            where
+                T: Copy,
+            {
+                $crate::error::check_shape($n, slice.len())?;
+                Ok(Self::from_array(slice.try_into().unwrap()))
+            }
+            /// Reinterprets a flat slice of elements as a slice of vectors without copying.
+            ///
+            /// Panics if the length of `slice` is not a multiple of the vector length.
+            pub fn cast_slice(slice: &[T]) -> &[Self] {
+                Self::try_cast_slice(slice).unwrap_or_else(|error| panic!("{}", error))
+            }
+            /// Fails with [`Error::ShapeMismatch`](crate::Error::ShapeMismatch) giving the next
+            /// multiple of the vector length if the length of `slice` is not one.
+            pub fn try_cast_slice(slice: &[T]) -> Result<&[Self], $crate::Error> {
+                $crate::error::check_shape(slice.len().next_multiple_of($n), slice.len())?;
+                // SAFETY: `Self` is `repr(C)` over `[T; N]` and a zero-sized marker, so it has
+                // the size and alignment of `[T; N]`, and the length was checked above.
+                Ok(unsafe {
+                    ::core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len() / $n)
+                })
+            }
+            pub fn cast_slice_mut(slice: &mut [T]) -> &mut [Self] {
+                Self::try_cast_slice_mut(slice).unwrap_or_else(|error| panic!("{}", error))
+            }
+            pub fn try_cast_slice_mut(slice: &mut [T]) -> Result<&mut [Self], $crate::Error> {
+                $crate::error::check_shape(slice.len().next_multiple_of($n), slice.len())?;
+                // SAFETY: see `cast_slice`.
+                Ok(unsafe {
+                    ::core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len() / $n)
+                })
+            }
+            /// Reinterprets a slice of vectors as a flat slice of their elements without copying.
+            pub fn flatten_slice(vectors: &[Self]) -> &[T] {
+                // SAFETY: see `cast_slice`.
+                unsafe {
+                    ::core::slice::from_raw_parts(vectors.as_ptr().cast(), vectors.len() * $n)
+                }
+            }
+            pub fn flatten_slice_mut(vectors: &mut [Self]) -> &mut [T] {
+                // SAFETY: see `cast_slice`.
+                unsafe {
+                    ::core::slice::from_raw_parts_mut(
+                        vectors.as_mut_ptr().cast(),
+                        vectors.len() * $n,
+                    )
+                }
+            }
+        }
+    };
+}
+pub(crate) use layout_impl;
+
+macro_rules! norm_impl {
+    ($vector:ident) => {
+        impl<T: $crate::num::Real, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            pub fn length_squared(self) -> T {
+                let [first, rest @ ..] = self.storage;
+                rest.into_iter().fold(first * first, |sum, x| sum + x * x)
+            }
+            pub fn length(self) -> T {
+                self.length_squared().sqrt()
+            }
+            /// Returns the vector scaled to unit length.
+            pub fn normalize(self) -> Self {
+                let length = self.length();
+                self.map(|x| x / length)
+            }
+        }
+    };
+}
+pub(crate) use norm_impl;
+
+/// Adds named component accessors and every GLSL-style swizzle of two to four components, such as
+/// `xy()`, `zyx()` and `xxyy()`.
+macro_rules! swizzle_impl {
+    ($vector:ident, $(($component:ident $index:literal))*) => {
+        ::paste::paste! {
+            impl<T: Copy, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+                $(
+                    pub fn $component(&self) -> T {
+                        self.storage[$index]
+                    }
+                    pub fn [<set_ $component>](&mut self, value: T) {
+                        self.storage[$index] = value;
+                    }
+                )*
+            }
+        }
+        impl<T: Copy, O: $crate::linear_algebra::vector::Orientation> $vector<T, O> {
+            $crate::linear_algebra::vector::swizzle_impl!(
+                @expand vector_2 Vector2 [$(($component $index))*] [$(($component $index))*] [] [_ _]
+            );
+            $crate::linear_algebra::vector::swizzle_impl!(
+                @expand vector_3 Vector3 [$(($component $index))*] [$(($component $index))*] [] [_ _ _]
+            );
+            $crate::linear_algebra::vector::swizzle_impl!(
+                @expand vector_4 Vector4 [$(($component $index))*] [$(($component $index))*] [] [_ _ _ _]
+            );
+        }
+    };
+    (@expand $module:ident $output:ident $components:tt $next:tt [$(($component:ident $index:literal))*] []) => {
+        ::paste::paste! {
+            pub fn [<$($component)*>](&self) -> $crate::linear_algebra::vector::$module::$output<T, O> {
+                $crate::linear_algebra::vector::$module::$output::from_array([
+                    $(self.storage[$index]),*
+                ])
+            }
+        }
+    };
+    (@expand $module:ident $output:ident $components:tt [$($next:tt)*] $prefix:tt $depth:tt) => {
+        $(
+            $crate::linear_algebra::vector::swizzle_impl!(
+                @push $module $output $components $prefix $next $depth
+            );
+        )*
+    };
+    (@push $module:ident $output:ident $components:tt [$($prefix:tt)*] $next:tt [_ $($depth:tt)*]) => {
+        $crate::linear_algebra::vector::swizzle_impl!(
+            @expand $module $output $components $components [$($prefix)* $next] [$($depth)*]
+        );
+    };
+}
+pub(crate) use swizzle_impl;
@@ -1,64 +1,102 @@
-use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+use super::vector_3::Vector3;
+use super::{layout_impl, norm_impl, swizzle_impl, Column, Orientation, Row, VectorType};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
+use crate::num::Conjugate;
 
-use super::VectorType;
-
-use std::fmt::{Display, Error, Formatter};
+use core::fmt::{Display, Error, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
 
+/// A two element vector.
+///
+/// The layout is guaranteed to be identical to `[T; 2]`: the orientation is a zero-sized
+/// type parameter, so slices of vectors can be reinterpreted as flat slices of elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct Vector2<T> {
+pub struct Vector2<T, O = Row> {
     pub storage: [T; 2],
-    pub vector_type: VectorType,
+    orientation: PhantomData<O>,
 }
 
-impl<T: Copy> Vector2<T> {
-    pub const fn new(x: T, y: T, vector_type: VectorType) -> Self {
-        Self {
-            storage: [x, y],
-            vector_type,
-        }
+impl<T> Vector2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self::from_array([x, y])
     }
-    pub fn transpose(&mut self) {
-        self.vector_type = match self.vector_type {
-            VectorType::Row => VectorType::Column,
-            VectorType::Column => VectorType::Row,
-        }
+}
+
+impl<T> Vector2<T, Column> {
+    pub const fn new_column(x: T, y: T) -> Self {
+        Self::from_array([x, y])
     }
 }
 
-impl<T: Display> Display for Vector2<T> {
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
-        fmt.write_str(
-            (match self.vector_type {
-                VectorType::Row => format!("[{} {}]", self.storage[0], self.storage[1]),
-                VectorType::Column => format!("┌{}┐\n└{}┘", self.storage[0], self.storage[1]),
-            })
-            .as_str(),
-        )
+layout_impl!(Vector2, 2);
+
+#[cfg(feature = "alloc")]
+render_impl!(vector Vector2);
+
+componentwise_impl!(vector Vector2);
+
+overflow_impl!(vector Vector2);
+
+norm_impl!(Vector2);
+
+swizzle_impl!(Vector2, (x 0) (y 1));
+
+impl<T, O: Orientation> Vector2<T, O> {
+    /// Appends a component, like `vec3(v, z)` in GLSL, so `v.yx().extend(0.0)` matches
+    /// `vec3(v.yx, 0.0)`.
+    pub fn extend(self, z: T) -> Vector3<T, O> {
+        let [x, y] = self.storage;
+        Vector3::from_array([x, y, z])
     }
 }
 
-macro_rules! vec2 {
-    ($i1:tt $i2:tt) => {
-        Vector2::new($i1, $i2, VectoryType::Row)
-    };
+impl<T: Copy + Add<Output = T> + Mul<Output = T>, O: Orientation> Vector2<T, O> {
+    pub fn dot(self, rhs: Self) -> T {
+        self.storage[0] * rhs.storage[0] + self.storage[1] * rhs.storage[1]
+    }
+    /// The Hermitian inner product, conjugating the elements of `self`. For real elements it
+    /// equals `dot`.
+    pub fn hdot(self, rhs: Self) -> T
+    where
+        T: Conjugate,
+    {
+        self.map(|x| x.conjugate()).dot(rhs)
+    }
 }
+
+impl<T: Display, O: Orientation> Display for Vector2<T, O> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        match O::VECTOR_TYPE {
+            VectorType::Row => write!(fmt, "[{} {}]", self.storage[0], self.storage[1]),
+            VectorType::Column => write!(fmt, "┌{}┐\n└{}┘", self.storage[0], self.storage[1]),
+        }
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "nightly")] {
         #[macro_export]
         macro_rules! vec2 {
             ($i1:tt $i2:tt) => {
                 #[allow(unused_parens)]
-                Vector2::new($i1, $i2, VectorType::Row)
+                Vector2::new($i1, $i2)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec2;
     } else {
         #[macro_export]
         macro_rules! vec2 {
             ($i1:tt $i2:tt) => {
-                Vector2::new($i1, $i2, VectorType::Row)
+                Vector2::new($i1, $i2)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec2;
     }
 }
@@ -67,18 +105,19 @@ macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Vector2<T> {
-                    type Output = Vector2<T>;
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<T> for Vector2<T, O> {
+                    type Output = Vector2<T, O>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
-                        Vector2::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.vector_type)
+                        Vector2::from_array([self.storage[0] $operator rhs, self.storage[1] $operator rhs])
                     }
                 }
-                impl<T: Copy + ::std::ops::$op<Vector2<T>, Output = Vector2<T>>> ::std::ops::$op<Vector2<T>> for Vector2<T> {
-                    type Output = Matrix2x2<T>;
+                /// Applies the operator component by component.
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<Vector2<T, O>> for Vector2<T, O> {
+                    type Output = Vector2<T, O>;
 
-                    fn [<$op:lower>](self, rhs: Vector2<T>) -> Self::Output {
-                        Matrix2x2::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs)
+                    fn [<$op:lower>](self, rhs: Vector2<T, O>) -> Self::Output {
+                        Vector2::from_array([self.storage[0] $operator rhs.storage[0], self.storage[1] $operator rhs.storage[1]])
                     }
                 }
             )*
@@ -94,18 +133,25 @@ mod tests {
 
     #[test]
     fn operators() {
-        let vec = Vector2::new(10, 20, VectorType::Row);
-        assert_eq!(vec + 10, Vector2::new(20, 30, VectorType::Row));
-        assert_eq!(vec - 10, Vector2::new(0, 10, VectorType::Row));
-        assert_eq!(vec * 10, Vector2::new(100, 200, VectorType::Row));
-        assert_eq!(vec / 10, Vector2::new(1, 2, VectorType::Row));
+        let vec = Vector2::new(10, 20);
+        assert_eq!(vec + 10, Vector2::new(20, 30));
+        assert_eq!(vec - 10, Vector2::new(0, 10));
+        assert_eq!(vec * 10, Vector2::new(100, 200));
+        assert_eq!(vec / 10, Vector2::new(1, 2));
+        assert_eq!(vec + Vector2::new(1, 2), Vector2::new(11, 22));
+        assert_eq!(vec / Vector2::new(2, 5), Vector2::new(5, 4));
     }
 
     #[test]
     fn display() {
-        let vec = Vector2::new(10, 20, VectorType::Row);
+        let vec = Vector2::new(10, 20);
         println!("{}", vec);
-        let vec = Vector2::new(10, 20, VectorType::Column);
+        let vec = Vector2::new_column(10, 20);
         println!("{}", vec);
     }
+
+    #[test]
+    fn dot() {
+        assert_eq!(Vector2::new(3, 4).dot(Vector2::new(3, 4)), 25);
+    }
 }
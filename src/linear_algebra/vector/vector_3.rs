@@ -1,44 +1,98 @@
-use super::VectorType;
-use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+use super::vector_4::Vector4;
+use super::{layout_impl, norm_impl, swizzle_impl, Column, Orientation, Row, VectorType};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
+use crate::num::Conjugate;
 
-use std::fmt::{Display, Error, Formatter};
+use core::fmt::{Display, Error, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub};
 
+/// A three element vector.
+///
+/// The layout is guaranteed to be identical to `[T; 3]`: the orientation is a zero-sized
+/// type parameter, so slices of vectors can be reinterpreted as flat slices of elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct Vector3<T> {
+pub struct Vector3<T, O = Row> {
     pub storage: [T; 3],
-    pub vector_type: VectorType,
+    orientation: PhantomData<O>,
 }
 
-impl<T: Copy> Vector3<T> {
-    pub const fn new(x: T, y: T, z: T, vector_type: VectorType) -> Self {
-        Self {
-            storage: [x, y, z],
-            vector_type,
-        }
+impl<T> Vector3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self::from_array([x, y, z])
     }
-    pub fn transpose(&mut self) {
-        self.vector_type = match self.vector_type {
-            VectorType::Row => VectorType::Column,
-            VectorType::Column => VectorType::Row,
-        }
+}
+
+impl<T> Vector3<T, Column> {
+    pub const fn new_column(x: T, y: T, z: T) -> Self {
+        Self::from_array([x, y, z])
+    }
+}
+
+layout_impl!(Vector3, 3);
+
+#[cfg(feature = "alloc")]
+render_impl!(vector Vector3);
+
+componentwise_impl!(vector Vector3);
+
+overflow_impl!(vector Vector3);
+
+norm_impl!(Vector3);
+
+swizzle_impl!(Vector3, (x 0) (y 1) (z 2));
+
+impl<T, O: Orientation> Vector3<T, O> {
+    /// Appends a component, like `vec4(v, w)` in GLSL, so `v.zyx().extend(1.0)` matches
+    /// `vec4(v.zyx, 1.0)`.
+    pub fn extend(self, w: T) -> Vector4<T, O> {
+        let [x, y, z] = self.storage;
+        Vector4::from_array([x, y, z, w])
     }
 }
 
-impl<T: Display> Display for Vector3<T> {
+impl<T: Copy + Add<Output = T> + Mul<Output = T>, O: Orientation> Vector3<T, O> {
+    pub fn dot(self, rhs: Self) -> T {
+        self.storage[0] * rhs.storage[0]
+            + self.storage[1] * rhs.storage[1]
+            + self.storage[2] * rhs.storage[2]
+    }
+    /// The Hermitian inner product, conjugating the elements of `self`. For real elements it
+    /// equals `dot`.
+    pub fn hdot(self, rhs: Self) -> T
+    where
+        T: Conjugate,
+    {
+        self.map(|x| x.conjugate()).dot(rhs)
+    }
+    pub fn cross(self, rhs: Self) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        let [x, y, z] = self.storage;
+        let [a, b, c] = rhs.storage;
+        Self::from_array([y * c - z * b, z * a - x * c, x * b - y * a])
+    }
+}
+
+impl<T: Display, O: Orientation> Display for Vector3<T, O> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
-        fmt.write_str(
-            (match self.vector_type {
-                VectorType::Row => format!(
-                    "[{} {} {}]",
-                    self.storage[0], self.storage[1], self.storage[2]
-                ),
-                VectorType::Column => format!(
-                    "┌{}┐\n│{}│\n└{}┘",
-                    self.storage[0], self.storage[1], self.storage[2]
-                ),
-            })
-            .as_str(),
-        )
+        match O::VECTOR_TYPE {
+            VectorType::Row => write!(
+                fmt,
+                "[{} {} {}]",
+                self.storage[0], self.storage[1], self.storage[2]
+            ),
+            VectorType::Column => write!(
+                fmt,
+                "┌{}┐\n│{}│\n└{}┘",
+                self.storage[0], self.storage[1], self.storage[2]
+            ),
+        }
     }
 }
 
@@ -48,17 +102,19 @@ cfg_if::cfg_if! {
         macro_rules! vec3 {
             ($i1:tt $i2:tt $i3:tt) => {
                 #[allow(unused_parens)]
-                Vector3::new($i1, $i2, $i3, VectorType::Row)
+                Vector3::new($i1, $i2, $i3)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec3;
     } else {
         #[macro_export]
         macro_rules! vec3 {
             ($i1:tt $i2:tt $i3:tt) => {
-                Vector3::new($i1, $i2, $i3, VectorType::Row)
+                Vector3::new($i1, $i2, $i3)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec3;
     }
 }
@@ -67,18 +123,19 @@ macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Vector3<T> {
-                    type Output = Vector3<T>;
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<T> for Vector3<T, O> {
+                    type Output = Vector3<T, O>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
-                        Vector3::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.storage[2] $operator rhs, self.vector_type)
+                        Vector3::from_array([self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.storage[2] $operator rhs])
                     }
                 }
-                impl<T: Copy + ::std::ops::$op<Vector3<T>, Output = Vector3<T>>> ::std::ops::$op<Vector3<T>> for Vector3<T> {
-                    type Output = Matrix3x3<T>;
+                /// Applies the operator component by component.
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<Vector3<T, O>> for Vector3<T, O> {
+                    type Output = Vector3<T, O>;
 
-                    fn [<$op:lower>](self, rhs: Vector3<T>) -> Self::Output {
-                        Matrix3x3::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.storage[2] $operator rhs)
+                    fn [<$op:lower>](self, rhs: Vector3<T, O>) -> Self::Output {
+                        Vector3::from_array([self.storage[0] $operator rhs.storage[0], self.storage[1] $operator rhs.storage[1], self.storage[2] $operator rhs.storage[2]])
                     }
                 }
             )*
@@ -91,21 +148,186 @@ op_impl!(Add +, Sub -, Mul *, Div /);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linear_algebra::vector::vector_2::Vector2;
 
     #[test]
     fn operators() {
-        let vec = Vector3::new(10, 20, 30, VectorType::Row);
-        assert_eq!(vec + 10, Vector3::new(20, 30, 40, VectorType::Row));
-        assert_eq!(vec - 10, Vector3::new(0, 10, 20, VectorType::Row));
-        assert_eq!(vec * 10, Vector3::new(100, 200, 300, VectorType::Row));
-        assert_eq!(vec / 10, Vector3::new(1, 2, 3, VectorType::Row));
+        let vec = Vector3::new(10, 20, 30);
+        assert_eq!(vec + 10, Vector3::new(20, 30, 40));
+        assert_eq!(vec - 10, Vector3::new(0, 10, 20));
+        assert_eq!(vec * 10, Vector3::new(100, 200, 300));
+        assert_eq!(vec / 10, Vector3::new(1, 2, 3));
+        assert_eq!(vec + Vector3::new(1, 2, 3), Vector3::new(11, 22, 33));
+        assert_eq!(vec - Vector3::new(1, 2, 3), Vector3::new(9, 18, 27));
+        assert_eq!(vec * Vector3::new(1, 2, 3), Vector3::new(10, 40, 90));
     }
 
     #[test]
     fn display() {
-        let vec = Vector3::new(10, 20, 30, VectorType::Row);
+        let vec = Vector3::new(10, 20, 30);
         println!("{}", vec);
-        let vec = Vector3::new(10, 20, 30, VectorType::Column);
+        let vec = Vector3::new_column(10, 20, 30);
         println!("{}", vec);
     }
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Vector3<f32>>(), 3 * 4);
+        assert_eq!(core::mem::size_of::<Vector3<f32, Column>>(), 3 * 4);
+
+        let mut flat = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let vectors = Vector3::<f32>::cast_slice(&flat);
+        assert_eq!(
+            vectors,
+            &[Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]
+        );
+        assert_eq!(Vector3::flatten_slice(vectors), &flat);
+
+        Vector3::<f32>::cast_slice_mut(&mut flat)[1].as_mut_slice()[0] = 7.0;
+        assert_eq!(flat[3], 7.0);
+        assert_eq!(Vector3::from_slice(&flat[3..]), Vector3::new(7.0, 5.0, 6.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cast_uneven_slice() {
+        Vector3::<f32>::cast_slice(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn fallible() {
+        assert!(matches!(
+            Vector3::<f32>::try_cast_slice(&[1.0; 4]),
+            Err(crate::Error::ShapeMismatch {
+                expected: 6,
+                found: 4
+            })
+        ));
+        assert_eq!(
+            Vector3::try_cast_slice(&[1, 2, 3]).unwrap(),
+            [Vector3::new(1, 2, 3)]
+        );
+        assert!(Vector3::<i32>::try_from_slice(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn transpose() {
+        let vec = Vector3::new(1, 2, 3).transpose();
+        assert_eq!(vec, Vector3::new_column(1, 2, 3));
+        assert_eq!(vec.vector_type(), VectorType::Column);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn render() {
+        let row = Vector3::new(1, 2, 3);
+        assert_eq!(
+            row.to_latex(),
+            "\\left[ \\begin{array}{ccc} 1 & 2 & 3 \\end{array} \\right]"
+        );
+        assert_eq!(
+            row.transpose().to_latex(),
+            "\\left[ \\begin{array}{c} 1 \\\\ 2 \\\\ 3 \\end{array} \\right]"
+        );
+        assert_eq!(row.transpose().to_typst(), "mat(delim: \"[\", 1; 2; 3)");
+        assert_eq!(
+            row.to_mathml(),
+            "<math><mrow><mo>[</mo><mtable><mtr><mtd><mn>1</mn></mtd><mtd><mn>2</mn></mtd><mtd><mn>3</mn></mtd></mtr></mtable><mo>]</mo></mrow></math>"
+        );
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(Vector3::new(1, 2, 3).dot(Vector3::new(4, -5, 6)), 12);
+    }
+
+    #[test]
+    fn overflow() {
+        let vec = Vector3::new(i32::MAX - 1, 0, i32::MIN);
+        assert_eq!(vec.checked_add(2), None);
+        assert_eq!(
+            vec.checked_sub(-1),
+            Some(Vector3::new(i32::MAX, 1, i32::MIN + 1))
+        );
+        assert_eq!(vec.checked_div(0), None);
+        assert_eq!(vec.checked_div_elementwise(Vector3::new(1, 1, -1)), None);
+        assert_eq!(vec.saturating_mul(2), Vector3::new(i32::MAX, 0, i32::MIN));
+        assert_eq!(
+            vec.wrapping_add_elementwise(Vector3::new(2, 1, -1)),
+            Vector3::new(i32::MIN, 1, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn cross() {
+        let x = Vector3::new(1, 0, 0);
+        let y = Vector3::new(0, 1, 0);
+        assert_eq!(x.cross(y), Vector3::new(0, 0, 1));
+        assert_eq!(y.cross(x), Vector3::new(0, 0, -1));
+        assert_eq!(Vector3::new(3.0, 0.0, 4.0).length(), 5.0);
+        assert_eq!(
+            Vector3::new(0.0, 0.0, 2.0).normalize(),
+            Vector3::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn swizzle() {
+        let mut vector = Vector3::new(1, 2, 3);
+        assert_eq!((vector.x(), vector.y(), vector.z()), (1, 2, 3));
+        assert_eq!(vector.zyx(), Vector3::new(3, 2, 1));
+        assert_eq!(vector.xz(), Vector2::new(1, 3));
+        assert_eq!(vector.xxyy(), Vector4::new(1, 1, 2, 2));
+        assert_eq!(vector.extend(1), Vector4::new(1, 2, 3, 1));
+        assert_eq!(vector.transpose().zzx(), Vector3::new_column(3, 3, 1));
+        vector.set_y(5);
+        assert_eq!(vector, Vector3::new(1, 5, 3));
+    }
+
+    #[test]
+    fn componentwise() {
+        let vector = Vector3::new(-1.5, 0.25, 4.0);
+        assert_eq!(vector.abs(), Vector3::new(1.5, 0.25, 4.0));
+        assert_eq!(vector.signum(), Vector3::new(-1.0, 1.0, 1.0));
+        assert_eq!(vector.floor(), Vector3::new(-2.0, 0.0, 4.0));
+        assert_eq!(vector.ceil(), Vector3::new(-1.0, 1.0, 4.0));
+        assert_eq!(vector.round(), Vector3::new(-2.0, 0.0, 4.0));
+        assert_eq!(
+            Vector3::new(4.0, 0.25, 9.0).sqrt(),
+            Vector3::new(2.0, 0.5, 3.0)
+        );
+        assert_eq!(vector.powf(2.0), Vector3::new(2.25, 0.0625, 16.0));
+        let other = Vector3::new(0.0, 1.0, 2.0);
+        assert_eq!(vector.min(other), Vector3::new(-1.5, 0.25, 2.0));
+        assert_eq!(vector.max(other), Vector3::new(0.0, 1.0, 4.0));
+        assert_eq!(vector.clamp(-1.0, 1.0), Vector3::new(-1.0, 0.25, 1.0));
+        assert_eq!(vector.lerp(other, 0.5), Vector3::new(-0.75, 0.625, 3.0));
+        assert_eq!(
+            Vector3::new(0.0, 0.5, 2.0).smoothstep(0.0, 1.0),
+            Vector3::new(0.0, 0.5, 1.0)
+        );
+        let integers = Vector3::new(-3, 0, 7);
+        assert_eq!(integers.abs(), Vector3::new(3, 0, 7));
+        assert_eq!(integers.signum(), Vector3::new(-1, 0, 1));
+        assert_eq!(integers.min(Vector3::new(0, 0, 0)), Vector3::new(-3, 0, 0));
+        assert_eq!(integers.clamp(-1, 5), Vector3::new(-1, 0, 5));
+        assert_eq!(
+            Vector3::new(1u8, 9, 4).max(Vector3::new(3, 3, 3)),
+            Vector3::new(3, 9, 4)
+        );
+        assert_eq!(
+            Vector3::new(1, 2, 3).zip_map(Vector3::new(4, 5, 6), |a, b| a * b),
+            Vector3::new(4, 10, 18)
+        );
+        assert_eq!(
+            Vector3::new(1, 2, 3).map(|x| x > 1),
+            Vector3::new(false, true, true)
+        );
+
+        let vector = Vector3::new(3, -1, 7);
+        assert_eq!((vector.sum(), vector.product()), (9, -21));
+        assert_eq!((vector.min_element(), vector.max_element()), (-1, 7));
+        assert_eq!((vector.argmin(), vector.argmax()), (1, 2));
+        assert_eq!(Vector3::new(2, 1, 1).argmin(), 1);
+    }
 }
@@ -1,43 +1,86 @@
-use super::VectorType;
+use super::{layout_impl, norm_impl, swizzle_impl, Column, Orientation, Row, VectorType};
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::render::render_impl;
+use crate::linear_algebra::Element;
+use crate::num::Conjugate;
 
-use std::fmt::{Display, Error, Formatter};
+use core::fmt::{Display, Error, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
 
+/// A four element vector.
+///
+/// The layout is guaranteed to be identical to `[T; 4]`: the orientation is a zero-sized
+/// type parameter, so slices of vectors can be reinterpreted as flat slices of elements.
+#[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct Vector4<T> {
+pub struct Vector4<T, O = Row> {
     pub storage: [T; 4],
-    pub vector_type: VectorType,
+    orientation: PhantomData<O>,
 }
 
-impl<T: Copy> Vector4<T> {
-    pub const fn new(x: T, y: T, z: T, w: T, vector_type: VectorType) -> Self {
-        Self {
-            storage: [x, y, z, w],
-            vector_type,
-        }
+impl<T> Vector4<T> {
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self::from_array([x, y, z, w])
+    }
+}
+
+impl<T> Vector4<T, Column> {
+    pub const fn new_column(x: T, y: T, z: T, w: T) -> Self {
+        Self::from_array([x, y, z, w])
     }
-    pub fn transpose(&mut self) {
-        self.vector_type = match self.vector_type {
-            VectorType::Row => VectorType::Column,
-            VectorType::Column => VectorType::Row,
+}
+
+layout_impl!(Vector4, 4);
+
+#[cfg(feature = "alloc")]
+render_impl!(vector Vector4);
+
+componentwise_impl!(vector Vector4);
+
+overflow_impl!(vector Vector4);
+
+norm_impl!(Vector4);
+
+swizzle_impl!(Vector4, (x 0) (y 1) (z 2) (w 3));
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T> + Element, O: Orientation> Vector4<T, O> {
+    pub fn dot(self, rhs: Self) -> T {
+        #[cfg(feature = "nightly")]
+        if let Some(dot) = crate::nightly::vector4_dot(&self.storage, &rhs.storage) {
+            return dot;
         }
+        self.storage[0] * rhs.storage[0]
+            + self.storage[1] * rhs.storage[1]
+            + self.storage[2] * rhs.storage[2]
+            + self.storage[3] * rhs.storage[3]
+    }
+    /// The Hermitian inner product, conjugating the elements of `self`. For real elements it
+    /// equals `dot`.
+    pub fn hdot(self, rhs: Self) -> T
+    where
+        T: Conjugate,
+    {
+        self.map(|x| x.conjugate()).dot(rhs)
     }
 }
 
-impl<T: Display> Display for Vector4<T> {
+impl<T: Display, O: Orientation> Display for Vector4<T, O> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
-        fmt.write_str(
-            (match self.vector_type {
-                VectorType::Row => format!(
-                    "[{} {} {} {}]",
-                    self.storage[0], self.storage[1], self.storage[2], self.storage[3]
-                ),
-                VectorType::Column => format!(
-                    "┌{}┐\n│{}│\n│{}│\n└{}┘",
-                    self.storage[0], self.storage[1], self.storage[2], self.storage[3]
-                ),
-            })
-            .as_str(),
-        )
+        match O::VECTOR_TYPE {
+            VectorType::Row => write!(
+                fmt,
+                "[{} {} {} {}]",
+                self.storage[0], self.storage[1], self.storage[2], self.storage[3]
+            ),
+            VectorType::Column => write!(
+                fmt,
+                "┌{}┐\n│{}│\n│{}│\n└{}┘",
+                self.storage[0], self.storage[1], self.storage[2], self.storage[3]
+            ),
+        }
     }
 }
 
@@ -45,20 +88,34 @@ macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for Vector4<T> {
-                    type Output = Vector4<T>;
+                impl<T: Copy + ::core::ops::$op<T, Output = T> + Element, O: Orientation> ::core::ops::$op<T> for Vector4<T, O> {
+                    type Output = Vector4<T, O>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
-                        Vector4::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.storage[2] $operator rhs, self.storage[3] $operator rhs, self.vector_type)
+                        #[cfg(feature = "nightly")]
+                        if let Some(storage) = crate::nightly::[<vector4_ $op:lower>](&self.storage, &rhs) {
+                            return Vector4::from_array(storage);
+                        }
+                        Vector4::from_array([self.storage[0] $operator rhs, self.storage[1] $operator rhs, self.storage[2] $operator rhs, self.storage[3] $operator rhs])
                     }
                 }
-                // impl<T: Copy + ::std::ops::$op<Vector4<T>, Output = Vector4<T>>> ::std::ops::$op<Vector4<T>> for Vector4<T> {
-                //     type Output = Matrix4x4<T>;
+                /// Applies the operator component by component.
+                impl<T: Copy + ::core::ops::$op<T, Output = T> + Element, O: Orientation> ::core::ops::$op<Vector4<T, O>> for Vector4<T, O> {
+                    type Output = Vector4<T, O>;
 
-                //     fn [<$op:lower>](self, rhs: Vector4<T>) -> Self::Output {
-                //         Matrix4x4::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs)
-                //     }
-                // }
+                    fn [<$op:lower>](self, rhs: Vector4<T, O>) -> Self::Output {
+                        #[cfg(feature = "nightly")]
+                        if let Some(storage) = crate::nightly::[<vector4_ $op:lower _elementwise>](&self.storage, &rhs.storage) {
+                            return Vector4::from_array(storage);
+                        }
+                        Vector4::from_array([
+                            self.storage[0] $operator rhs.storage[0],
+                            self.storage[1] $operator rhs.storage[1],
+                            self.storage[2] $operator rhs.storage[2],
+                            self.storage[3] $operator rhs.storage[3],
+                        ])
+                    }
+                }
             )*
         }
     }
@@ -71,17 +128,19 @@ cfg_if::cfg_if! {
         macro_rules! vec4 {
             ($i1:tt $i2:tt $i3:tt $i4:tt) => {
                 #[allow(unused_parens)]
-                Vector4::new($i1, $i2, $i3, $i4, VectorType::Row)
+                Vector4::new($i1, $i2, $i3, $i4)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec4;
     } else {
         #[macro_export]
         macro_rules! vec4 {
             ($i1:tt $i2:tt $i3:tt $i4:tt) => {
-                Vector4::new($i1, $i2, $i3, $i4, VectorType::Row)
+                Vector4::new($i1, $i2, $i3, $i4)
             }
         }
+        #[allow(unused_imports)]
         pub(crate) use vec4;
     }
 }
@@ -89,21 +148,54 @@ cfg_if::cfg_if! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::linear_algebra::vector::vector_2::Vector2;
+    use crate::linear_algebra::vector::vector_3::Vector3;
 
     #[test]
     fn operators() {
-        let vec = Vector4::new(10, 20, 30, 40, VectorType::Row);
-        assert_eq!(vec + 10, Vector4::new(20, 30, 40, 50, VectorType::Row));
-        assert_eq!(vec - 10, Vector4::new(0, 10, 20, 30, VectorType::Row));
-        assert_eq!(vec * 10, Vector4::new(100, 200, 300, 400, VectorType::Row));
-        assert_eq!(vec / 10, Vector4::new(1, 2, 3, 4, VectorType::Row));
+        let vec = Vector4::new(10, 20, 30, 40);
+        assert_eq!(vec + 10, Vector4::new(20, 30, 40, 50));
+        assert_eq!(vec - 10, Vector4::new(0, 10, 20, 30));
+        assert_eq!(vec * 10, Vector4::new(100, 200, 300, 400));
+        assert_eq!(vec / 10, Vector4::new(1, 2, 3, 4));
+        assert_eq!(vec + Vector4::new(1, 2, 3, 4), Vector4::new(11, 22, 33, 44));
+        assert_eq!(vec - Vector4::new(1, 2, 3, 4), Vector4::new(9, 18, 27, 36));
+        assert_eq!(
+            vec * Vector4::new(1, 2, 3, 4),
+            Vector4::new(10, 40, 90, 160)
+        );
+        assert_eq!(vec / Vector4::new(2, 5, 3, 8), Vector4::new(5, 4, 10, 5));
     }
 
     #[test]
     fn display() {
-        let vec = Vector4::new(10, 20, 30, 40, VectorType::Row);
+        let vec = Vector4::new(10, 20, 30, 40);
         println!("{}", vec);
-        let vec = Vector4::new(10, 20, 30, 40, VectorType::Column);
+        let vec = Vector4::new_column(10, 20, 30, 40);
         println!("{}", vec);
     }
+
+    #[test]
+    fn dot() {
+        assert_eq!(vec4![1 2 3 4].dot(vec4![5 6 7 8]), 70);
+        assert_eq!(
+            Vector4::new(0.5, 1.0, 1.5, 2.0).dot(Vector4::new(2.0, 2.0, 2.0, 2.0)),
+            10.0
+        );
+    }
+
+    #[test]
+    fn swizzle() {
+        let mut vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(vector.w(), 4.0);
+        assert_eq!(vector.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(vector.xyz(), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(vector.ww(), Vector2::new(4.0, 4.0));
+        assert_eq!(
+            vector.xy().extend(0.0).extend(1.0),
+            Vector4::new(1.0, 2.0, 0.0, 1.0)
+        );
+        vector.set_w(0.0);
+        assert_eq!(vector, Vector4::new(1.0, 2.0, 3.0, 0.0));
+    }
 }
@@ -1,36 +1,172 @@
-use super::VectorType;
+use super::{Column, Orientation, Row, VectorType};
+use crate::error::check_shape;
+use crate::linear_algebra::componentwise::componentwise_impl;
+use crate::linear_algebra::overflow::overflow_impl;
+use crate::linear_algebra::render::render_impl;
+use crate::num::Conjugate;
 
-use std::fmt::{Display, Error, Formatter};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Display, Error, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
 
+/// A heap allocated vector of five or more elements.
+///
+/// The elements are stored contiguously in a single allocation.
 #[derive(Clone, Eq, PartialEq, Debug)]
-pub struct VectorN<T> {
+pub struct VectorN<T, O = Row> {
     storage: Vec<T>,
-    vector_type: VectorType,
+    orientation: PhantomData<O>,
 }
 
 impl<T> VectorN<T> {
-    pub fn new(vec: Vec<T>, vector_type: VectorType) -> Self {
+    pub fn new(vec: Vec<T>) -> Self {
+        Self::from_vec(vec)
+    }
+    pub fn try_new(vec: Vec<T>) -> Result<Self, crate::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+
+impl<T> VectorN<T, Column> {
+    pub fn new_column(vec: Vec<T>) -> Self {
+        Self::from_vec(vec)
+    }
+    pub fn try_new_column(vec: Vec<T>) -> Result<Self, crate::Error> {
+        Self::try_from_vec(vec)
+    }
+}
+
+impl<T, O: Orientation> VectorN<T, O> {
+    pub fn from_vec(vec: Vec<T>) -> Self {
         if vec.len() < 5 {
             panic!("Do not use `VectorN` for Vectors below 5 items");
         }
 
         Self {
             storage: vec,
-            vector_type,
+            orientation: PhantomData,
         }
     }
-    pub fn transpose(&mut self) {
-        self.vector_type = match self.vector_type {
-            VectorType::Row => VectorType::Column,
-            VectorType::Column => VectorType::Row,
-        };
+    /// Fails with [`Error::DimensionTooSmall`](crate::Error::DimensionTooSmall) below 5 items.
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, crate::Error> {
+        if vec.len() < 5 {
+            return Err(crate::Error::DimensionTooSmall {
+                minimum: 5,
+                found: vec.len(),
+            });
+        }
+        Ok(Self::from_vec(vec))
+    }
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_vec(slice.to_vec())
+    }
+    pub fn try_from_slice(slice: &[T]) -> Result<Self, crate::Error>
+    where
+        T: Copy,
+    {
+        Self::try_from_vec(slice.to_vec())
+    }
+    pub fn into_vec(self) -> Vec<T> {
+        self.storage
+    }
+    /// Applies `f` to every element.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> VectorN<U, O> {
+        VectorN::from_vec(self.storage.into_iter().map(f).collect())
+    }
+    /// Combines corresponding elements of two vectors with `f`.
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn zip_map<U, V>(
+        self,
+        other: VectorN<U, O>,
+        mut f: impl FnMut(T, U) -> V,
+    ) -> VectorN<V, O> {
+        assert_eq!(
+            self.storage.len(),
+            other.storage.len(),
+            "Cannot combine vectors of different lengths"
+        );
+        VectorN::from_vec(
+            self.storage
+                .into_iter()
+                .zip(other.storage)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        )
+    }
+    pub fn try_zip_map<U, V>(
+        self,
+        other: VectorN<U, O>,
+        f: impl FnMut(T, U) -> V,
+    ) -> Result<VectorN<V, O>, crate::Error> {
+        check_shape(self.storage.len(), other.storage.len())?;
+        Ok(self.zip_map(other, f))
+    }
+    pub const fn vector_type(&self) -> VectorType {
+        O::VECTOR_TYPE
+    }
+    pub fn transpose(self) -> VectorN<T, O::Transposed> {
+        VectorN::from_vec(self.storage)
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.storage
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.storage
+    }
+    pub fn as_ptr(&self) -> *const T {
+        self.storage.as_ptr()
+    }
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.storage.as_mut_ptr()
     }
 }
 
-impl<T: Display> Display for VectorN<T> {
+render_impl!(vector VectorN);
+
+componentwise_impl!(vector VectorN);
+
+overflow_impl!(vector VectorN);
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>, O: Orientation> VectorN<T, O> {
+    pub fn dot(&self, rhs: &Self) -> T {
+        assert_eq!(
+            self.storage.len(),
+            rhs.storage.len(),
+            "Cannot take the dot product of vectors of different lengths"
+        );
+        self.storage
+            .iter()
+            .zip(&rhs.storage)
+            .skip(1)
+            .fold(self.storage[0] * rhs.storage[0], |sum, (a, b)| {
+                sum + *a * *b
+            })
+    }
+    /// The Hermitian inner product, conjugating the elements of `self`. For real elements it
+    /// equals `dot`.
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn hdot(&self, rhs: &Self) -> T
+    where
+        T: Conjugate,
+    {
+        self.clone().map(|x| x.conjugate()).dot(rhs)
+    }
+    pub fn try_dot(&self, rhs: &Self) -> Result<T, crate::Error> {
+        check_shape(self.storage.len(), rhs.storage.len())?;
+        Ok(self.dot(rhs))
+    }
+}
+
+impl<T: Display, O: Orientation> Display for VectorN<T, O> {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
         fmt.write_str(
-            (match self.vector_type {
+            (match O::VECTOR_TYPE {
                 VectorType::Row => format!(
                     "[{}{}]",
                     self.storage[0..self.storage.len() - 1]
@@ -58,20 +194,21 @@ macro_rules! op_impl {
     ($($op:ident $operator:tt),*) => {
         ::paste::paste! {
             $(
-                impl<T: Copy + ::std::ops::$op<T, Output = T>> ::std::ops::$op<T> for VectorN<T> {
-                    type Output = VectorN<T>;
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<T> for VectorN<T, O> {
+                    type Output = VectorN<T, O>;
 
                     fn [<$op:lower>](self, rhs: T) -> Self::Output {
-                        VectorN::new(self.storage.iter().map(|x| *x $operator rhs).collect::<Vec<_>>(), self.vector_type)
+                        VectorN::from_vec(self.storage.iter().map(|x| *x $operator rhs).collect::<Vec<_>>())
                     }
                 }
-                // impl<T: Copy + ::std::ops::$op<VectorN<T>, Output = VectorN<T>>> ::std::ops::$op<VectorN<T>> for VectorN<T> {
-                //     type Output = MatrixNxN<T>;
+                /// Applies the operator component by component. Panics if the lengths differ.
+                impl<T: Copy + ::core::ops::$op<T, Output = T>, O: Orientation> ::core::ops::$op<VectorN<T, O>> for VectorN<T, O> {
+                    type Output = VectorN<T, O>;
 
-                //     fn [<$op:lower>](self, rhs: VectorN<T>) -> Self::Output {
-                //         MatrixNxN::new(self.storage[0] $operator rhs, self.storage[1] $operator rhs)
-                //     }
-                // }
+                    fn [<$op:lower>](self, rhs: VectorN<T, O>) -> Self::Output {
+                        self.zip_map(rhs, |a, b| a $operator b)
+                    }
+                }
             )*
         }
     }
@@ -85,33 +222,94 @@ mod tests {
 
     #[test]
     fn operators() {
-        let vec = VectorN::new(vec![10, 20, 30, 40, 50], VectorType::Row);
+        let vec = VectorN::new(vec![10, 20, 30, 40, 50]);
+        assert_eq!(vec.clone() + 10, VectorN::new(vec![20, 30, 40, 50, 60]));
+        assert_eq!(vec.clone() - 10, VectorN::new(vec![0, 10, 20, 30, 40]));
         assert_eq!(
-            vec.clone() + 10,
-            VectorN::new(vec![20, 30, 40, 50, 60], VectorType::Row)
+            vec.clone() * 10,
+            VectorN::new(vec![100, 200, 300, 400, 500])
         );
+        assert_eq!(vec.clone() / 10, VectorN::new(vec![1, 2, 3, 4, 5]));
+        let other = VectorN::new(vec![1, 2, 3, 4, 5]);
         assert_eq!(
-            vec.clone() - 10,
-            VectorN::new(vec![0, 10, 20, 30, 40], VectorType::Row)
+            vec.clone() + other.clone(),
+            VectorN::new(vec![11, 22, 33, 44, 55])
         );
         assert_eq!(
-            vec.clone() * 10,
-            VectorN::new(vec![100, 200, 300, 400, 500], VectorType::Row)
+            vec.clone() - other.clone(),
+            VectorN::new(vec![9, 18, 27, 36, 45])
+        );
+        assert_eq!(
+            vec.clone() * other.clone(),
+            VectorN::new(vec![10, 40, 90, 160, 250])
         );
-        assert_eq!(vec / 10, VectorN::new(vec![1, 2, 3, 4, 5], VectorType::Row));
+        assert_eq!(vec / other, VectorN::new(vec![10; 5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths() {
+        let _ = VectorN::new(vec![0; 5]) + VectorN::new(vec![0; 6]);
     }
 
     #[test]
     #[should_panic]
     fn less_than_5() {
-        VectorN::new(vec![0], VectorType::Row);
+        VectorN::new(vec![0]);
+    }
+
+    #[test]
+    fn fallible() {
+        assert!(matches!(
+            VectorN::try_new(vec![0; 3]),
+            Err(crate::Error::DimensionTooSmall {
+                minimum: 5,
+                found: 3
+            })
+        ));
+        let vector = VectorN::try_new_column(vec![1; 5]).unwrap();
+        assert!(vector.try_dot(&VectorN::new_column(vec![1; 6])).is_err());
+        assert!(matches!(
+            vector.try_zip_map(VectorN::new_column(vec![1; 6]), |a, b| a + b),
+            Err(crate::Error::ShapeMismatch {
+                expected: 5,
+                found: 6
+            })
+        ));
     }
 
     #[test]
     fn display() {
-        let vec = VectorN::new(vec![10, 20, 30, 40, 50], VectorType::Row);
+        let vec = VectorN::new(vec![10, 20, 30, 40, 50]);
         println!("{vec}");
-        let vec = VectorN::new(vec![10, 20, 30, 40, 50], VectorType::Column);
+        let vec = VectorN::new_column(vec![10, 20, 30, 40, 50]);
         println!("{vec}");
     }
+
+    #[test]
+    fn dot() {
+        let vec = VectorN::new(vec![1, 2, 3, 4, 5]);
+        assert_eq!(vec.dot(&vec), 55);
+    }
+
+    #[test]
+    fn componentwise() {
+        let vector = VectorN::new(vec![1.0, -4.0, 9.0, 0.0, 2.5]);
+        assert_eq!(
+            vector.clone().abs().sqrt(),
+            VectorN::new(vec![1.0, 2.0, 3.0, 0.0, 2.5f64.sqrt()])
+        );
+        assert_eq!(
+            vector.clone().lerp(VectorN::new(vec![3.0; 5]), 0.5),
+            VectorN::new(vec![2.0, -0.5, 6.0, 1.5, 2.75])
+        );
+        assert_eq!(vector.sum(), 8.5);
+        assert_eq!((vector.argmin(), vector.argmax()), (1, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_map_lengths() {
+        VectorN::new(vec![1; 5]).zip_map(VectorN::new(vec![1; 6]), |a, b| a + b);
+    }
 }
@@ -0,0 +1,299 @@
+//! `core::simd` kernels for `f32` and `f64` vectors and matrices.
+//!
+//! Every kernel performs exactly the same floating point operations, in the same order, as the
+//! scalar implementation it replaces, so both paths produce bit-identical results. The generic
+//! entry points below return `None` for element types without a kernel, in which case callers
+//! fall back to the scalar path.
+
+use crate::linear_algebra::matrix::matrix_4x4;
+
+use core::any::Any;
+use core::simd::{simd_swizzle, Simd};
+
+fn cast_ref<A: 'static, B: 'static>(value: &A) -> Option<&B> {
+    (value as &dyn Any).downcast_ref()
+}
+
+fn cast<A: 'static, B: 'static>(value: A) -> Option<B> {
+    (&mut Some(value) as &mut dyn Any)
+        .downcast_mut::<Option<B>>()
+        .and_then(Option::take)
+}
+
+macro_rules! kernels {
+    ($($module:ident $t:ident),*) => {
+        $(
+            mod $module {
+                use super::*;
+
+                fn load(row: [$t; 4]) -> Simd<$t, 4> {
+                    Simd::from_array(row)
+                }
+
+                pub(super) fn add(lhs: &[$t; 4], rhs: &$t) -> [$t; 4] {
+                    (load(*lhs) + Simd::splat(*rhs)).to_array()
+                }
+
+                pub(super) fn sub(lhs: &[$t; 4], rhs: &$t) -> [$t; 4] {
+                    (load(*lhs) - Simd::splat(*rhs)).to_array()
+                }
+
+                pub(super) fn mul(lhs: &[$t; 4], rhs: &$t) -> [$t; 4] {
+                    (load(*lhs) * Simd::splat(*rhs)).to_array()
+                }
+
+                pub(super) fn div(lhs: &[$t; 4], rhs: &$t) -> [$t; 4] {
+                    (load(*lhs) / Simd::splat(*rhs)).to_array()
+                }
+
+                pub(super) fn add_elementwise(lhs: &[$t; 4], rhs: &[$t; 4]) -> [$t; 4] {
+                    (load(*lhs) + load(*rhs)).to_array()
+                }
+
+                pub(super) fn sub_elementwise(lhs: &[$t; 4], rhs: &[$t; 4]) -> [$t; 4] {
+                    (load(*lhs) - load(*rhs)).to_array()
+                }
+
+                pub(super) fn mul_elementwise(lhs: &[$t; 4], rhs: &[$t; 4]) -> [$t; 4] {
+                    (load(*lhs) * load(*rhs)).to_array()
+                }
+
+                pub(super) fn div_elementwise(lhs: &[$t; 4], rhs: &[$t; 4]) -> [$t; 4] {
+                    (load(*lhs) / load(*rhs)).to_array()
+                }
+
+                pub(super) fn dot(lhs: &[$t; 4], rhs: &[$t; 4]) -> $t {
+                    let products = (load(*lhs) * load(*rhs)).to_array();
+                    products[0] + products[1] + products[2] + products[3]
+                }
+
+                pub(super) fn matrix_mul(lhs: &[[$t; 4]; 4], rhs: &[[$t; 4]; 4]) -> [[$t; 4]; 4] {
+                    let rhs = rhs.map(load);
+                    lhs.map(|row| {
+                        (Simd::splat(row[0]) * rhs[0]
+                            + Simd::splat(row[1]) * rhs[1]
+                            + Simd::splat(row[2]) * rhs[2]
+                            + Simd::splat(row[3]) * rhs[3])
+                            .to_array()
+                    })
+                }
+
+                pub(super) fn transpose(matrix: &[[$t; 4]; 4]) -> [[$t; 4]; 4] {
+                    let [r0, r1, r2, r3] = matrix.map(load);
+                    let low01 = simd_swizzle!(r0, r1, [0, 4, 1, 5]);
+                    let low23 = simd_swizzle!(r2, r3, [0, 4, 1, 5]);
+                    let high01 = simd_swizzle!(r0, r1, [2, 6, 3, 7]);
+                    let high23 = simd_swizzle!(r2, r3, [2, 6, 3, 7]);
+                    [
+                        simd_swizzle!(low01, low23, [0, 1, 4, 5]).to_array(),
+                        simd_swizzle!(low01, low23, [2, 3, 6, 7]).to_array(),
+                        simd_swizzle!(high01, high23, [0, 1, 4, 5]).to_array(),
+                        simd_swizzle!(high01, high23, [2, 3, 6, 7]).to_array(),
+                    ]
+                }
+
+                pub(super) fn inverse(matrix: &[[$t; 4]; 4]) -> Option<[[$t; 4]; 4]> {
+                    use matrix_4x4::inverse_tables::*;
+
+                    let (s, c) = matrix_4x4::minors(matrix);
+                    let determinant = matrix_4x4::determinant_from_minors(&s, &c);
+                    if determinant == 0.0 {
+                        return None;
+                    }
+                    let inverse_determinant = Simd::splat(1.0 / determinant);
+                    let gather = |column: usize| {
+                        Simd::from_array(LANE_ROWS.map(|row| matrix[row][column]))
+                    };
+                    let minor = |index: usize| Simd::from_array([c[index], c[index], s[index], s[index]]);
+                    Some(::core::array::from_fn(|row| {
+                        let sign = Simd::from_array(::core::array::from_fn(|lane| {
+                            if (row + lane) % 2 == 1 { -1.0 } else { 1.0 }
+                        }));
+                        let cofactors = gather(X_COLUMNS[row]) * minor(P_MINORS[row])
+                            - gather(Y_COLUMNS[row]) * minor(Q_MINORS[row])
+                            + gather(Z_COLUMNS[row]) * minor(R_MINORS[row]);
+                        (cofactors * sign * inverse_determinant).to_array()
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+kernels!(kernels_f32 f32, kernels_f64 f64);
+
+/// Runs `$kernel` if every argument is an `f32` or every argument is an `f64` type, with the
+/// concrete argument types inferred from the kernel signature.
+macro_rules! dispatch {
+    ($kernel:ident($($argument:ident),*)) => {{
+        if let ($(Some($argument),)*) = ($(cast_ref($argument),)*) {
+            return cast(kernels_f32::$kernel($($argument),*));
+        }
+        if let ($(Some($argument),)*) = ($(cast_ref($argument),)*) {
+            return cast(kernels_f64::$kernel($($argument),*));
+        }
+        None
+    }};
+}
+
+pub(crate) fn vector4_add<T: 'static>(lhs: &[T; 4], rhs: &T) -> Option<[T; 4]> {
+    dispatch!(add(lhs, rhs))
+}
+
+pub(crate) fn vector4_sub<T: 'static>(lhs: &[T; 4], rhs: &T) -> Option<[T; 4]> {
+    dispatch!(sub(lhs, rhs))
+}
+
+pub(crate) fn vector4_mul<T: 'static>(lhs: &[T; 4], rhs: &T) -> Option<[T; 4]> {
+    dispatch!(mul(lhs, rhs))
+}
+
+pub(crate) fn vector4_div<T: 'static>(lhs: &[T; 4], rhs: &T) -> Option<[T; 4]> {
+    dispatch!(div(lhs, rhs))
+}
+
+pub(crate) fn vector4_add_elementwise<T: 'static>(lhs: &[T; 4], rhs: &[T; 4]) -> Option<[T; 4]> {
+    dispatch!(add_elementwise(lhs, rhs))
+}
+
+pub(crate) fn vector4_sub_elementwise<T: 'static>(lhs: &[T; 4], rhs: &[T; 4]) -> Option<[T; 4]> {
+    dispatch!(sub_elementwise(lhs, rhs))
+}
+
+pub(crate) fn vector4_mul_elementwise<T: 'static>(lhs: &[T; 4], rhs: &[T; 4]) -> Option<[T; 4]> {
+    dispatch!(mul_elementwise(lhs, rhs))
+}
+
+pub(crate) fn vector4_div_elementwise<T: 'static>(lhs: &[T; 4], rhs: &[T; 4]) -> Option<[T; 4]> {
+    dispatch!(div_elementwise(lhs, rhs))
+}
+
+pub(crate) fn vector4_dot<T: 'static>(lhs: &[T; 4], rhs: &[T; 4]) -> Option<T> {
+    dispatch!(dot(lhs, rhs))
+}
+
+pub(crate) fn matrix_mul<T: 'static, const N: usize>(
+    lhs: &[[T; N]; N],
+    rhs: &[[T; N]; N],
+) -> Option<[[T; N]; N]> {
+    dispatch!(matrix_mul(lhs, rhs))
+}
+
+pub(crate) fn matrix4x4_transpose<T: 'static>(matrix: &[[T; 4]; 4]) -> Option<[[T; 4]; 4]> {
+    dispatch!(transpose(matrix))
+}
+
+pub(crate) fn matrix4x4_inverse<T: 'static>(matrix: &[[T; 4]; 4]) -> Option<Option<[[T; 4]; 4]>> {
+    dispatch!(inverse(matrix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+
+    /// Deterministic pseudo-random values spread over several orders of magnitude.
+    fn values(seed: u64) -> impl Iterator<Item = f64> {
+        let mut state = seed;
+        core::iter::repeat_with(move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let mantissa = (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5;
+            mantissa * 10f64.powi((state % 7) as i32 - 3)
+        })
+    }
+
+    fn matrices<T: Copy>(convert: fn(f64) -> T) -> impl Iterator<Item = [[T; 4]; 4]> {
+        (0..64).map(move |seed| {
+            let mut values = values(seed).map(convert);
+            core::array::from_fn(|_| core::array::from_fn(|_| values.next().unwrap()))
+        })
+    }
+
+    macro_rules! identical_tests {
+        ($($name:ident $t:ident),*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let bits = |matrix: [[$t; 4]; 4]| matrix.map(|row| row.map($t::to_bits));
+                    let convert = |value: f64| value as $t;
+                    for (lhs, rhs) in matrices(convert).zip(matrices(convert).skip(1)) {
+                        let (lhs, rhs) = (Matrix4x4::from_rows(lhs), Matrix4x4::from_rows(rhs));
+                        let scalar = rhs.as_rows()[0][0];
+
+                        for (row, other) in lhs.as_rows().iter().zip(rhs.as_rows()) {
+                            assert_eq!(
+                                vector4_add(row, &scalar).unwrap().map($t::to_bits),
+                                row.map(|x| x + scalar).map($t::to_bits)
+                            );
+                            assert_eq!(
+                                vector4_sub(row, &scalar).unwrap().map($t::to_bits),
+                                row.map(|x| x - scalar).map($t::to_bits)
+                            );
+                            assert_eq!(
+                                vector4_mul(row, &scalar).unwrap().map($t::to_bits),
+                                row.map(|x| x * scalar).map($t::to_bits)
+                            );
+                            assert_eq!(
+                                vector4_div(row, &scalar).unwrap().map($t::to_bits),
+                                row.map(|x| x / scalar).map($t::to_bits)
+                            );
+                            for (kernel, op) in [
+                                (vector4_add_elementwise as fn(&[$t; 4], &[$t; 4]) -> Option<[$t; 4]>, (|a, b| a + b) as fn($t, $t) -> $t),
+                                (vector4_sub_elementwise, |a, b| a - b),
+                                (vector4_mul_elementwise, |a, b| a * b),
+                                (vector4_div_elementwise, |a, b| a / b),
+                            ] {
+                                assert_eq!(
+                                    kernel(row, other).unwrap().map($t::to_bits),
+                                    ::core::array::from_fn::<_, 4, _>(|i| op(row[i], other[i]).to_bits())
+                                );
+                            }
+                            assert_eq!(
+                                vector4_dot(row, other).unwrap().to_bits(),
+                                (row[0] * other[0] + row[1] * other[1] + row[2] * other[2] + row[3] * other[3]).to_bits()
+                            );
+                        }
+
+                        assert_eq!(
+                            bits(matrix_mul(lhs.as_rows(), rhs.as_rows()).unwrap()),
+                            bits(lhs.mul_scalar(&rhs).into_rows())
+                        );
+                        assert_eq!(
+                            bits(matrix4x4_transpose(lhs.as_rows()).unwrap()),
+                            bits(lhs.transpose_scalar().into_rows())
+                        );
+                        assert_eq!(
+                            matrix4x4_inverse(lhs.as_rows()).unwrap().map(bits),
+                            lhs.inverse_scalar().map(|inverse| bits(inverse.into_rows()))
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    identical_tests!(identical_f32 f32, identical_f64 f64);
+
+    #[test]
+    fn fallback() {
+        let matrix = [[1i32; 4]; 4];
+        assert_eq!(matrix_mul(&matrix, &matrix), None);
+        assert_eq!(matrix4x4_inverse(&matrix), None);
+        assert_eq!(vector4_dot(&[1.0f32; 4], &[1.0f32; 4]), Some(4.0));
+        assert_eq!(matrix_mul(&[[1.0f32; 3]; 3], &[[1.0f32; 3]; 3]), None);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Matrix4x4::from_rows([
+            [2.0f32, 0.0, 0.0, 1.0],
+            [0.0, 4.0, 0.0, 0.0],
+            [0.0, 0.0, 8.0, 0.0],
+            [0.0, 0.0, 1.0, 1.0],
+        ]);
+        assert_eq!(matrix * matrix.inverse().unwrap(), Matrix4x4::identity());
+        assert_eq!(Matrix4x4::<f64>::from_rows([[0.0; 4]; 4]).inverse(), None);
+    }
+}
@@ -0,0 +1,1102 @@
+//! Arbitrary-precision integers.
+//!
+//! Magnitudes are stored as little-endian base 2^32 digits without leading zeros, so zero has no
+//! digits and equal values have equal representations.
+
+use super::{Checked, Conjugate, Integer, Num, One, Pivot, Signed, Zero};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
+/// Operands with at least this many digits are multiplied with Karatsuba's algorithm.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParseBigIntError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum ParseErrorKind {
+    Empty,
+    InvalidDigit,
+    InvalidRadix,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse integer from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::InvalidRadix => "radix must be between 2 and 36",
+        })
+    }
+}
+
+impl core::error::Error for ParseBigIntError {}
+
+fn trim(digits: &[u32]) -> &[u32] {
+    let length = digits.len() - digits.iter().rev().take_while(|&&d| d == 0).count();
+    &digits[..length]
+}
+
+fn normalized(mut digits: Vec<u32>) -> Vec<u32> {
+    digits.truncate(trim(&digits).len());
+    digits
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    let (a, b) = (trim(a), trim(b));
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Adds `b` into `a` starting at digit `offset`, growing `a` as needed.
+fn add_at(a: &mut Vec<u32>, b: &[u32], offset: usize) {
+    if a.len() < offset + b.len() {
+        a.resize(offset + b.len(), 0);
+    }
+    let mut carry = 0u64;
+    for (i, &digit) in b.iter().enumerate() {
+        let sum = a[offset + i] as u64 + digit as u64 + carry;
+        a[offset + i] = sum as u32;
+        carry = sum >> 32;
+    }
+    let mut i = offset + b.len();
+    while carry != 0 {
+        if i == a.len() {
+            a.push(0);
+        }
+        let sum = a[i] as u64 + carry;
+        a[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = a.to_vec();
+    add_at(&mut sum, b, 0);
+    normalized(sum)
+}
+
+/// `a - b`, which must not be negative.
+fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let b = trim(b);
+    let mut difference = a.to_vec();
+    let mut borrow = 0i64;
+    for (i, digit) in difference.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+        let value = *digit as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        *digit = value as u32;
+        borrow = (value < 0) as i64;
+    }
+    assert!(
+        borrow == 0 && difference.len() >= b.len(),
+        "Subtraction underflowed"
+    );
+    normalized(difference)
+}
+
+fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let value = product[i + j] as u64 + x as u64 * y as u64 + carry;
+            product[i + j] = value as u32;
+            carry = value >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    normalized(product)
+}
+
+/// Splits `digits` into the low `at` digits and the rest.
+fn split(digits: &[u32], at: usize) -> (&[u32], &[u32]) {
+    let at = at.min(digits.len());
+    (trim(&digits[..at]), &digits[at..])
+}
+
+fn karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let half = a.len().max(b.len()) / 2;
+    let (a_low, a_high) = split(a, half);
+    let (b_low, b_high) = split(b, half);
+    let low = mul(a_low, b_low);
+    let high = mul(a_high, b_high);
+    let middle = sub(
+        &sub(&mul(&add(a_low, a_high), &add(b_low, b_high)), &low),
+        &high,
+    );
+    let mut product = low;
+    add_at(&mut product, &middle, half);
+    add_at(&mut product, &high, 2 * half);
+    normalized(product)
+}
+
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = (trim(a), trim(b));
+    if a.is_empty() || b.is_empty() {
+        Vec::new()
+    } else if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        schoolbook(a, b)
+    } else {
+        karatsuba(a, b)
+    }
+}
+
+/// Multiplies by `factor` and adds `addend` in place.
+fn mul_small_add(digits: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for digit in digits.iter_mut() {
+        let value = *digit as u64 * factor as u64 + carry;
+        *digit = value as u32;
+        carry = value >> 32;
+    }
+    if carry != 0 {
+        digits.push(carry as u32);
+    }
+}
+
+/// Divides by `divisor` in place, returning the remainder.
+fn div_small(digits: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for digit in digits.iter_mut().rev() {
+        let value = (remainder << 32) | *digit as u64;
+        *digit = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+    remainder as u32
+}
+
+fn shift_left(digits: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return digits.to_vec();
+    }
+    let mut shifted = Vec::with_capacity(digits.len() + 1);
+    let mut carry = 0;
+    for &digit in digits {
+        shifted.push((digit << shift) | carry);
+        carry = digit >> (32 - shift);
+    }
+    shifted.push(carry);
+    shifted
+}
+
+fn shift_right(digits: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return normalized(digits.to_vec());
+    }
+    let shifted = (0..digits.len())
+        .map(|i| {
+            let high = digits.get(i + 1).map_or(0, |&next| next << (32 - shift));
+            (digits[i] >> shift) | high
+        })
+        .collect();
+    normalized(shifted)
+}
+
+/// The quotient and remainder, by Knuth's algorithm D for multi-digit divisors.
+fn div_rem(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let (a, b) = (trim(a), trim(b));
+    assert!(!b.is_empty(), "Division by zero");
+    if compare(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let mut quotient = a.to_vec();
+        let remainder = div_small(&mut quotient, b[0]);
+        return (normalized(quotient), normalized(vec![remainder]));
+    }
+
+    // Normalize so the top digit of the divisor has its high bit set, which keeps each estimated
+    // quotient digit at most two too large.
+    let shift = b[b.len() - 1].leading_zeros();
+    let divisor = shift_left(b, shift);
+    let divisor = trim(&divisor);
+    let mut remainder = shift_left(a, shift);
+    if remainder.len() == a.len() {
+        remainder.push(0);
+    }
+    let n = divisor.len();
+    let (top, second) = (divisor[n - 1] as u64, divisor[n - 2] as u64);
+    let mut quotient = vec![0u32; remainder.len() - n];
+    for j in (0..quotient.len()).rev() {
+        let numerator = ((remainder[j + n] as u64) << 32) | remainder[j + n - 1] as u64;
+        let mut estimate = numerator / top;
+        let mut rest = numerator % top;
+        while estimate >> 32 != 0
+            || estimate * second > ((rest << 32) | remainder[j + n - 2] as u64)
+        {
+            estimate -= 1;
+            rest += top;
+            if rest >> 32 != 0 {
+                break;
+            }
+        }
+
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = estimate * divisor[i] as u64 + carry;
+            carry = product >> 32;
+            let value = remainder[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            remainder[i + j] = value as u32;
+            borrow = (value < 0) as i64;
+        }
+        let value = remainder[j + n] as i64 - borrow - carry as i64;
+        remainder[j + n] = value as u32;
+        if value < 0 {
+            // The estimate was one too large, so add the divisor back.
+            estimate -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = remainder[i + j] as u64 + divisor[i] as u64 + carry;
+                remainder[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+    (normalized(quotient), shift_right(&remainder[..n], shift))
+}
+
+/// The largest power of `radix` that fits in a digit, and its exponent.
+fn radix_chunk(radix: u32) -> (u32, usize) {
+    let mut power = radix;
+    let mut count = 1;
+    while let Some(next) = power.checked_mul(radix) {
+        power = next;
+        count += 1;
+    }
+    (power, count)
+}
+
+fn parse_digits(text: &str, radix: u32) -> Result<Vec<u32>, ParseBigIntError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseBigIntError {
+            kind: ParseErrorKind::InvalidRadix,
+        });
+    }
+    if text.is_empty() {
+        return Err(ParseBigIntError {
+            kind: ParseErrorKind::Empty,
+        });
+    }
+    let (_, count) = radix_chunk(radix);
+    let mut digits = Vec::new();
+    let characters: Vec<char> = text.chars().collect();
+    for chunk in characters.chunks(count) {
+        let mut factor = 1u32;
+        let mut value = 0u32;
+        for character in chunk {
+            let digit = character.to_digit(radix).ok_or(ParseBigIntError {
+                kind: ParseErrorKind::InvalidDigit,
+            })?;
+            factor *= radix;
+            value = value * radix + digit;
+        }
+        mul_small_add(&mut digits, factor, value);
+    }
+    Ok(normalized(digits))
+}
+
+fn format_digits(digits: &[u32], radix: u32, uppercase: bool) -> String {
+    debug_assert!((2..=36).contains(&radix));
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    let (power, count) = radix_chunk(radix);
+    let mut rest = digits.to_vec();
+    let mut chunks = Vec::new();
+    while !rest.is_empty() {
+        chunks.push(div_small(&mut rest, power));
+        rest = normalized(rest);
+    }
+    let to_char = |digit: u32| {
+        let character = core::char::from_digit(digit, radix).unwrap();
+        if uppercase {
+            character.to_ascii_uppercase()
+        } else {
+            character
+        }
+    };
+    let mut text = String::new();
+    for (i, &chunk) in chunks.iter().rev().enumerate() {
+        let mut chunk_digits = Vec::with_capacity(count);
+        let mut value = chunk;
+        while value != 0 {
+            chunk_digits.push(to_char(value % radix));
+            value /= radix;
+        }
+        if i > 0 {
+            chunk_digits.resize(count, '0');
+        }
+        text.extend(chunk_digits.iter().rev());
+    }
+    text
+}
+
+/// A non-negative integer of any size.
+#[derive(Clone, Default, Eq, PartialEq, Hash)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    /// Builds a number from little-endian base 2^32 digits.
+    pub fn from_digits(digits: Vec<u32>) -> Self {
+        Self {
+            digits: normalized(digits),
+        }
+    }
+    /// The little-endian base 2^32 digits, without leading zeros.
+    pub fn digits(&self) -> &[u32] {
+        &self.digits
+    }
+    /// The number of bits needed to represent the number.
+    pub fn bits(&self) -> u64 {
+        self.digits.last().map_or(0, |top| {
+            self.digits.len() as u64 * 32 - top.leading_zeros() as u64
+        })
+    }
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = div_rem(&self.digits, &divisor.digits);
+        (Self::from_digits(quotient), Self::from_digits(remainder))
+    }
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+    /// `self` to the power `exponent`, modulo `modulus`.
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        self.try_modpow(exponent, modulus)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_modpow(&self, exponent: &Self, modulus: &Self) -> Result<Self, crate::Error> {
+        if modulus.is_zero() {
+            return Err(crate::Error::DivisionByZero);
+        }
+        let mut result = Self::one() % modulus;
+        let mut base = self % modulus;
+        for (i, &digit) in exponent.digits.iter().enumerate() {
+            let last = i + 1 == exponent.digits.len();
+            let bits = if last { 32 - digit.leading_zeros() } else { 32 };
+            for bit in 0..bits {
+                if digit >> bit & 1 == 1 {
+                    result = &(&result * &base) % modulus;
+                }
+                if !(last && bit + 1 == bits) {
+                    base = &(&base * &base) % modulus;
+                }
+            }
+        }
+        Ok(result)
+    }
+    /// Parses digits in `radix` with an optional leading `+`. Radixes outside 2 to 36 are an
+    /// error.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let text = text.strip_prefix('+').unwrap_or(text);
+        parse_digits(text, radix).map(Self::from_digits)
+    }
+    /// Formats the number in `radix`, which must be between 2 and 36, with lowercase letters.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.try_to_str_radix(radix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_to_str_radix(&self, radix: u32) -> Result<String, crate::Error> {
+        if !(2..=36).contains(&radix) {
+            return Err(crate::Error::InvalidArgument(
+                "Radix must be between 2 and 36",
+            ));
+        }
+        Ok(format_digits(&self.digits, radix, false))
+    }
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.digits[..] {
+            [] => Some(0),
+            [low] => Some(low as u64),
+            [low, high] => Some((high as u64) << 32 | low as u64),
+            _ => None,
+        }
+    }
+    /// The nearest `f64`, rounding ties to even, or infinity if the number is too large.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        if bits <= 64 {
+            return self.to_u64().unwrap() as f64;
+        }
+        // Keep the top 64 bits and fold every bit below them into the lowest, which is still
+        // well below the 53 that survive, so converting rounds as the full number would.
+        let shift = bits - 64;
+        let (limb, offset) = ((shift / 32) as usize, shift % 32);
+        let limb_at = |i: usize| self.digits.get(i).map_or(0, |&digit| digit as u128);
+        let window = limb_at(limb) | limb_at(limb + 1) << 32 | limb_at(limb + 2) << 64;
+        let sticky =
+            window & ((1 << offset) - 1) != 0 || self.digits[..limb].iter().any(|&d| d != 0);
+        let top = (window >> offset) as u64 | sticky as u64;
+        if shift > 1023 {
+            return f64::INFINITY;
+        }
+        top as f64 * f64::from_bits((1023 + shift) << 52)
+    }
+}
+
+/// A signed integer of any size.
+#[derive(Clone, Default, Eq, PartialEq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+    pub fn into_magnitude(self) -> BigUint {
+        self.magnitude
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    /// The quotient rounded toward zero and the remainder with the sign of `self`, like the
+    /// primitive integers.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude);
+        (
+            Self::from_biguint(self.negative != divisor.negative, quotient),
+            Self::from_biguint(self.negative, remainder),
+        )
+    }
+    pub fn pow(&self, exponent: u32) -> Self {
+        Self::from_biguint(
+            self.negative && exponent % 2 == 1,
+            self.magnitude.pow(exponent),
+        )
+    }
+    /// `self` to the power `exponent`, modulo `modulus`, in `[0, modulus)`.
+    ///
+    /// Panics if `exponent` is negative or `modulus` is not positive.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        self.try_modpow(exponent, modulus)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_modpow(&self, exponent: &Self, modulus: &Self) -> Result<Self, crate::Error> {
+        if exponent.negative {
+            return Err(crate::Error::InvalidArgument("Exponent is negative"));
+        }
+        if modulus.negative {
+            return Err(crate::Error::InvalidArgument("Modulus is negative"));
+        }
+        let result = Self::from_biguint(
+            self.negative
+                && exponent
+                    .magnitude
+                    .digits
+                    .first()
+                    .is_some_and(|d| d & 1 == 1),
+            self.magnitude
+                .try_modpow(&exponent.magnitude, &modulus.magnitude)?,
+        );
+        Ok(if result.negative {
+            result + modulus.clone()
+        } else {
+            result
+        })
+    }
+    /// Parses digits in `radix` with an optional leading sign. Radixes outside 2 to 36 are an
+    /// error.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(text) => (true, text),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        parse_digits(text, radix)
+            .map(|digits| Self::from_biguint(negative, BigUint::from_digits(digits)))
+    }
+    /// Formats the number in `radix`, which must be between 2 and 36, with lowercase letters.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.try_to_str_radix(radix)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_to_str_radix(&self, radix: u32) -> Result<String, crate::Error> {
+        let digits = self.magnitude.try_to_str_radix(radix)?;
+        Ok(if self.negative {
+            format!("-{digits}")
+        } else {
+            digits
+        })
+    }
+    pub fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.magnitude.to_u64()?;
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+    /// The nearest `f64`, or infinity if the number is too large.
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.magnitude.to_f64();
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+macro_rules! from_unsigned_impl {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(value: $t) -> Self {
+                    let mut value = value as u128;
+                    let mut digits = Vec::new();
+                    while value != 0 {
+                        digits.push(value as u32);
+                        value >>= 32;
+                    }
+                    Self { digits }
+                }
+            }
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    Self::from_biguint(false, BigUint::from(value))
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! from_signed_impl {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    Self::from_biguint(value < 0, BigUint::from(value.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+from_signed_impl!(i8, i16, i32, i64, i128, isize);
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self::from_biguint(false, magnitude)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(text, 10)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(text, 10)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.digits, &other.digits)
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Add<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> Self::Output {
+        BigUint::from_digits(add(&self.digits, &rhs.digits))
+    }
+}
+
+impl<'a> Sub<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    /// Panics if the result would be negative.
+    fn sub(self, rhs: &BigUint) -> Self::Output {
+        BigUint::from_digits(sub(&self.digits, &rhs.digits))
+    }
+}
+
+impl<'a> Mul<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> Self::Output {
+        BigUint::from_digits(mul(&self.digits, &rhs.digits))
+    }
+}
+
+impl<'a> Div<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<'a> Rem<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<'a> Add<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_biguint(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => BigInt::from_biguint(rhs.negative, &rhs.magnitude - &self.magnitude),
+            _ => BigInt::from_biguint(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl<'a> Sub<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &-rhs.clone()
+    }
+}
+
+impl<'a> Mul<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::from_biguint(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl<'a> Div<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<'a> Rem<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        Self::from_biguint(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+/// Forwards the operators on owned values to the ones on references.
+macro_rules! op_impl {
+    ($($t:ident: $($op:ident),*);*) => {
+        ::paste::paste! {
+            $($(
+                impl $op for $t {
+                    type Output = $t;
+
+                    fn [<$op:lower>](self, rhs: $t) -> Self::Output {
+                        (&self).[<$op:lower>](&rhs)
+                    }
+                }
+                impl $op<&$t> for $t {
+                    type Output = $t;
+
+                    fn [<$op:lower>](self, rhs: &$t) -> Self::Output {
+                        (&self).[<$op:lower>](rhs)
+                    }
+                }
+                impl $op<$t> for &$t {
+                    type Output = $t;
+
+                    fn [<$op:lower>](self, rhs: $t) -> Self::Output {
+                        self.[<$op:lower>](&rhs)
+                    }
+                }
+            )*)*
+        }
+    }
+}
+
+op_impl!(BigUint: Add, Sub, Mul, Div, Rem; BigInt: Add, Sub, Mul, Div, Rem);
+
+macro_rules! num_impl {
+    ($($t:ident),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    Self::default()
+                }
+                fn is_zero(&self) -> bool {
+                    *self == Self::default()
+                }
+            }
+            impl One for $t {
+                fn one() -> Self {
+                    Self::from(1u8)
+                }
+            }
+            impl Num for $t {}
+            impl Integer for $t {}
+            impl Pivot for $t {}
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+num_impl!(BigUint, BigInt);
+
+impl Checked for BigUint {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        (self >= rhs).then(|| self - rhs)
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self / rhs)
+    }
+}
+
+impl Checked for BigInt {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self / rhs)
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> Self {
+        Self::from_biguint(false, self.magnitude.clone())
+    }
+    fn signum(&self) -> Self {
+        Self::from_biguint(self.negative, BigUint::from(!self.is_zero() as u8))
+    }
+}
+
+macro_rules! format_impl {
+    ($($format:ident $radix:literal $prefix:literal $uppercase:literal),*) => {
+        $(
+            impl $format for BigUint {
+                fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+                    fmt.pad_integral(true, $prefix, &format_digits(&self.digits, $radix, $uppercase))
+                }
+            }
+            impl $format for BigInt {
+                fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+                    fmt.pad_integral(
+                        !self.negative,
+                        $prefix,
+                        &format_digits(&self.magnitude.digits, $radix, $uppercase),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+format_impl!(Display 10 "" false, Binary 2 "0b" false, Octal 8 "0o" false, LowerHex 16 "0x" false, UpperHex 16 "0x" true);
+
+impl Debug for BigUint {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_nxn::MatrixNxN;
+    use crate::num::rational::Rational;
+
+    /// A deterministic pseudo-random sequence of digits.
+    fn random_digits(seed: u64, count: usize) -> Vec<u32> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 32) as u32
+            })
+            .collect()
+    }
+
+    fn big(text: &str) -> BigInt {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn matches_primitives() {
+        let values = [
+            0i128,
+            1,
+            -1,
+            7,
+            -13,
+            1 << 40,
+            -(1 << 62),
+            i64::MAX as i128,
+            123456789012345678,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!(&x + &y, BigInt::from(a + b));
+                assert_eq!(&x - &y, BigInt::from(a - b));
+                assert_eq!(&x * &y, BigInt::from(a * b));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if b != 0 {
+                    assert_eq!(&x / &y, BigInt::from(a / b));
+                    assert_eq!(&x % &y, BigInt::from(a % b));
+                }
+            }
+        }
+        assert_eq!(BigInt::from(-5).to_i64(), Some(-5));
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(BigInt::from(u64::MAX).to_i64(), None);
+        assert_eq!(BigUint::from(u64::MAX).to_u64(), Some(u64::MAX));
+    }
+
+    #[test]
+    fn multiplication() {
+        let a = BigUint::from_digits(random_digits(1, 150));
+        let b = BigUint::from_digits(random_digits(2, 97));
+        let product = &a * &b;
+        assert_eq!(product.digits(), schoolbook(a.digits(), b.digits()));
+        assert_eq!(&product / &b, a);
+        assert!((&product % &b).is_zero());
+        assert_eq!(&(&a * &a) - &(&b * &b), &(&a + &b) * &(&a - &b));
+    }
+
+    #[test]
+    fn division() {
+        for (seed, (n, m)) in [(150, 40), (40, 39), (8, 2), (5, 1), (33, 33)]
+            .into_iter()
+            .enumerate()
+        {
+            let a = BigUint::from_digits(random_digits(seed as u64 * 2 + 3, n));
+            let b = BigUint::from_digits(random_digits(seed as u64 * 2 + 4, m));
+            let (quotient, remainder) = a.div_rem(&b);
+            assert!(remainder < b);
+            assert_eq!(&(&quotient * &b) + &remainder, a);
+        }
+        // Exercises the rare add-back step of long division.
+        let a = BigUint::from_digits(vec![0, 0, 0x8000_0000, 0x7fff_ffff]);
+        let b = BigUint::from_digits(vec![1, 0, 0x8000_0000]);
+        let (quotient, remainder) = a.div_rem(&b);
+        assert_eq!(&(&quotient * &b) + &remainder, a);
+        assert!(remainder < b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = BigUint::from(1u8) / BigUint::zero();
+    }
+
+    #[test]
+    fn number_theory() {
+        assert_eq!(
+            BigUint::from(2u8).pow(100).to_string(),
+            "1267650600228229401496703205376"
+        );
+        assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+        assert_eq!(
+            BigUint::from(4u8).modpow(&BigUint::from(13u8), &BigUint::from(497u16)),
+            BigUint::from(445u16)
+        );
+        // Fermat's little theorem for the Mersenne prime 2^127 - 1.
+        let prime = &BigUint::from(2u8).pow(127) - &BigUint::one();
+        let exponent = &prime - &BigUint::one();
+        assert_eq!(BigUint::from(3u8).modpow(&exponent, &prime), BigUint::one());
+        assert_eq!(
+            BigInt::from(-2).modpow(&BigInt::from(3), &BigInt::from(5)),
+            BigInt::from(2)
+        );
+
+        let a = big("123456789012345678901234567890") * big("987654321");
+        let b = big("123456789012345678901234567890") * big("-123456789");
+        assert_eq!(a.gcd(&b), big("123456789012345678901234567890") * big("9"));
+    }
+
+    #[test]
+    fn formatting() {
+        let value = big("-1234567890123456789012345678901234567890");
+        assert_eq!(
+            value.to_string(),
+            "-1234567890123456789012345678901234567890"
+        );
+        for radix in [2, 3, 7, 10, 16, 36] {
+            let text = value.to_str_radix(radix);
+            assert_eq!(BigInt::from_str_radix(&text, radix).unwrap(), value);
+        }
+        assert_eq!(BigUint::from(255u8).to_str_radix(16), "ff");
+        assert!(BigUint::from(255u8).try_to_str_radix(37).is_err());
+        assert!(BigInt::from(-255).try_to_str_radix(1).is_err());
+        assert_eq!(BigInt::from(-255).try_to_str_radix(16).unwrap(), "-ff");
+        assert_eq!(format!("{:#x}", BigInt::from(-255)), "-0xff");
+        assert_eq!(format!("{:X}", BigUint::from(0xabcu16)), "ABC");
+        assert_eq!(format!("{:b}", BigUint::from(5u8)), "101");
+        assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+        assert_eq!(format!("{:+}", BigInt::from(42)), "+42");
+        assert_eq!(BigInt::zero().to_string(), "0");
+        assert_eq!(
+            format!("{:?}", BigUint::from(1000000000000u64)),
+            "1000000000000"
+        );
+
+        assert_eq!(
+            "".parse::<BigInt>().unwrap_err().to_string(),
+            "cannot parse integer from empty string"
+        );
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("-5".parse::<BigUint>().is_err());
+        assert_eq!("+00042".parse::<BigUint>().unwrap(), BigUint::from(42u8));
+        assert_eq!(
+            BigInt::from_str_radix("10", 37).unwrap_err().to_string(),
+            "radix must be between 2 and 36"
+        );
+        assert!(BigUint::from_str_radix("0", 1).is_err());
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(BigUint::zero().to_f64(), 0.0);
+        assert_eq!(BigInt::from(-12345).to_f64(), -12345.0);
+        assert_eq!(BigUint::from(u64::MAX).to_f64(), 18446744073709551615.0);
+        // 2^117 + 2^64 + 1 is just above halfway to the next `f64`, which rounding after each
+        // limb would miss.
+        let above_half = BigUint::from_str_radix("200000000000010000000000000001", 16).unwrap();
+        assert_eq!(above_half.to_f64(), 2f64.powi(117) + 2f64.powi(65));
+        // 2^117 + 2^64 is exactly halfway and ties to the even neighbour.
+        let tie = BigUint::from_str_radix("200000000000010000000000000000", 16).unwrap();
+        assert_eq!(tie.to_f64(), 2f64.powi(117));
+        let tie = BigUint::from_str_radix("200000000000030000000000000000", 16).unwrap();
+        assert_eq!(tie.to_f64(), 2f64.powi(117) + 2f64.powi(66));
+        assert_eq!(BigUint::from(2u8).pow(1023).to_f64(), 2f64.powi(1023));
+        assert_eq!(BigUint::from(2u8).pow(1024).to_f64(), f64::INFINITY);
+        assert_eq!((-BigInt::from(2).pow(2000)).to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn exact_linear_algebra() {
+        // A 6x6 Hilbert matrix scaled to integers, whose determinant overflows i64 on the way.
+        let size = 6;
+        let scale = BigInt::from(27720);
+        let elements = (0..size * size)
+            .map(|k| &scale / &BigInt::from(k / size + k % size + 1))
+            .collect();
+        let matrix = MatrixNxN::from_vec(size, elements);
+        let determinant = matrix.determinant();
+        let expected = scale.pow(6) / big("186313420339200000");
+        assert_eq!(determinant, expected);
+
+        let third = Rational::new(BigInt::from(1), BigInt::from(3));
+        let huge = Rational::new(BigInt::from(2).pow(200), BigInt::from(3).pow(50));
+        assert_eq!((huge.clone() * third.clone()) / third, huge);
+    }
+}
@@ -0,0 +1,471 @@
+use super::{Conjugate, Num, One, Pivot, Real, Zero};
+
+use core::fmt::{self, Display, Error, Formatter, Write};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number `re + im i`.
+///
+/// Complex numbers with `Real` components implement `Num`, so they can be the elements of
+/// vectors and matrices. Over other types only addition, subtraction and multiplication are
+/// available, and conjugation needs signed components.
+#[repr(C)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Neg<Output = T>> Complex<T> {
+    pub fn conjugate(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl<T: Num + Copy> Complex<T> {
+    /// The imaginary unit.
+    pub fn i() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+    pub fn norm_squared(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Real> Complex<T> {
+    /// Raises to an integer power by repeated squaring.
+    pub fn powi(self, n: i32) -> Self {
+        let mut result = Self::one();
+        let mut base = self;
+        let mut exponent = n.unsigned_abs();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        if n < 0 {
+            Self::one() / result
+        } else {
+            result
+        }
+    }
+    pub fn from_polar(modulus: T, argument: T) -> Self {
+        let (sin, cos) = argument.sin_cos();
+        Self::new(modulus * cos, modulus * sin)
+    }
+    /// The modulus and argument.
+    pub fn to_polar(self) -> (T, T) {
+        (self.modulus(), self.argument())
+    }
+    /// The absolute value, computed without overflow for large components.
+    pub fn modulus(self) -> T {
+        let (re, im) = (self.re.abs(), self.im.abs());
+        let (large, small) = if re > im { (re, im) } else { (im, re) };
+        if large.is_zero() {
+            return large;
+        }
+        let ratio = small / large;
+        large * (T::one() + ratio * ratio).sqrt()
+    }
+    /// The angle from the positive real axis, in `(-π, π]`.
+    pub fn argument(self) -> T {
+        self.im.atan2(self.re)
+    }
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+    /// The principal natural logarithm, with imaginary part in `(-π, π]`.
+    pub fn ln(self) -> Self {
+        Self::new(self.modulus().ln(), self.argument())
+    }
+    /// The principal square root, with non-negative real part.
+    pub fn sqrt(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        let two = T::one() + T::one();
+        let t = ((self.modulus() + self.re.abs()) / two).sqrt();
+        if self.re >= T::zero() {
+            Self::new(t, self.im / (two * t))
+        } else if self.im < T::zero() {
+            Self::new(self.im.abs() / (two * t), -t)
+        } else {
+            Self::new(self.im.abs() / (two * t), t)
+        }
+    }
+    /// Raises to a real power on the principal branch.
+    pub fn powf(self, n: T) -> Self {
+        if self.is_zero() {
+            return if n.is_zero() { Self::one() } else { self };
+        }
+        let (modulus, argument) = self.to_polar();
+        Self::from_polar(modulus.powf(n), argument * n)
+    }
+    /// Raises to a complex power on the principal branch.
+    pub fn powc(self, n: Self) -> Self {
+        if self.is_zero() {
+            return if n.is_zero() { Self::one() } else { self };
+        }
+        (n * self.ln()).exp()
+    }
+    pub fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        Self::new(sin * self.im.cosh(), cos * self.im.sinh())
+    }
+    pub fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        Self::new(cos * self.im.cosh(), -(sin * self.im.sinh()))
+    }
+    pub fn tan(self) -> Self {
+        self.sin() / self.cos()
+    }
+    pub fn sinh(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        Self::new(self.re.sinh() * cos, self.re.cosh() * sin)
+    }
+    pub fn cosh(self) -> Self {
+        let (sin, cos) = self.im.sin_cos();
+        Self::new(self.re.cosh() * cos, self.re.sinh() * sin)
+    }
+    pub fn tanh(self) -> Self {
+        self.sinh() / self.cosh()
+    }
+}
+
+impl<T: Zero> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+}
+
+impl<T: Num + Copy> Zero for Complex<T> {
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Num + Copy> One for Complex<T> {
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T: Real> Num for Complex<T> {}
+
+/// Pivots on the largest modulus, as for real elements.
+impl<T: Real> Pivot for Complex<T> {
+    fn tolerance(elements: &[Self], columns: usize) -> Self {
+        let largest = elements
+            .iter()
+            .fold(T::zero(), |largest, x| largest.max(x.modulus()));
+        let dimension = columns.max(elements.len() / columns.max(1));
+        Self::from(largest * T::epsilon() * T::from_f64(dimension as f64))
+    }
+    fn is_pivot(&self, tolerance: &Self) -> bool {
+        self.modulus() > tolerance.re
+    }
+    fn is_better_pivot(&self, other: &Self) -> bool {
+        self.norm_squared() > other.norm_squared()
+    }
+    const EXACT: bool = false;
+}
+
+impl<T: Neg<Output = T> + Copy> Conjugate for Complex<T> {
+    fn conjugate(&self) -> Self {
+        Complex::conjugate(*self)
+    }
+}
+
+impl<T: Num + Copy> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Num + Copy> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Num + Copy> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Real> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Smith's algorithm, which divides by the larger component of `rhs` first so that the
+    /// intermediate products neither overflow nor underflow as readily as `|rhs|²`.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.im.abs() <= rhs.re.abs() {
+            let ratio = rhs.im / rhs.re;
+            let denominator = rhs.re + rhs.im * ratio;
+            Self::new(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator,
+            )
+        } else {
+            let ratio = rhs.re / rhs.im;
+            let denominator = rhs.re * ratio + rhs.im;
+            Self::new(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator,
+            )
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Num + Copy> $op<T> for Complex<T> {
+                    type Output = Complex<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        self $operator Complex::from(rhs)
+                    }
+                }
+            )*
+        }
+    }
+}
+
+op_impl!(Add +, Sub -, Mul *);
+
+impl<T: Num + Copy> Div<T> for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+/// Writes through to a formatter, replacing a leading minus sign of the first piece with
+/// `negative` and prefixing any other first piece with `positive`.
+struct SignedWriter<'a, 'b> {
+    fmt: &'a mut Formatter<'b>,
+    negative: &'static str,
+    positive: &'static str,
+    started: bool,
+}
+
+impl fmt::Write for SignedWriter<'_, '_> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        if self.started || text.is_empty() {
+            return self.fmt.write_str(text);
+        }
+        self.started = true;
+        match text.strip_prefix('-') {
+            Some(rest) => {
+                self.fmt.write_str(self.negative)?;
+                self.fmt.write_str(rest)
+            }
+            None => {
+                self.fmt.write_str(self.positive)?;
+                self.fmt.write_str(text)
+            }
+        }
+    }
+}
+
+impl<T: Display> Display for Complex<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let precision = fmt.precision();
+        let positive = if fmt.sign_plus() { "+" } else { "" };
+        for (part, negative, positive) in [(&self.re, "-", positive), (&self.im, " - ", " + ")] {
+            let mut writer = SignedWriter {
+                fmt: &mut *fmt,
+                negative,
+                positive,
+                started: false,
+            };
+            match precision {
+                Some(precision) => write!(writer, "{:.*}", precision, part)?,
+                None => write!(writer, "{}", part)?,
+            }
+        }
+        fmt.write_str("i")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+    #[cfg(feature = "alloc")]
+    use crate::linear_algebra::matrix::matrix_nxn::MatrixNxN;
+    use crate::linear_algebra::vector::vector_3::Vector3;
+    use crate::test_util::assert_close;
+
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Complex::new(1, 2), Complex::new(3, -1));
+        assert_eq!(a + b, Complex::new(4, 1));
+        assert_eq!(a - b, Complex::new(-2, 3));
+        assert_eq!(a * b, Complex::new(5, 5));
+        assert_eq!(-a, Complex::new(-1, -2));
+        assert_eq!(a * 2, Complex::new(2, 4));
+        assert_eq!(Complex::<i32>::i() * Complex::i(), Complex::new(-1, 0));
+        assert_eq!(a.conjugate(), Complex::new(1, -2));
+        assert_eq!(a.norm_squared(), 5);
+
+        let (a, b) = (Complex::new(1.0, 2.0), Complex::new(3.0, -1.0));
+        assert_close(a / b * b, a);
+        assert_close(a / b, Complex::new(0.1, 0.7));
+        assert_close(a / Complex::new(0.5, 2.0), Complex::new(18.0, -4.0) / 17.0);
+        assert_eq!(a / 2.0, Complex::new(0.5, 1.0));
+        assert_close(a.powi(3), a * a * a);
+        assert_close(a.powi(-2) * a * a, Complex::one());
+        assert_eq!(Complex::new(3.0, 4.0).modulus(), 5.0);
+        assert_eq!(Complex::new(3e300, 4e300).modulus(), 5e300);
+        // `|rhs|²` overflows, but Smith's algorithm does not.
+        let huge = Complex::new(3e300, 4e300);
+        assert_close(huge / huge, Complex::one());
+        assert_close(
+            Complex::new(1e-300, 1e-300) / Complex::new(0.0, 1e-300),
+            Complex::new(1.0, -1.0),
+        );
+    }
+
+    #[test]
+    fn polar() {
+        let z = Complex::from_polar(2.0, FRAC_PI_2);
+        assert_close(z, Complex::new(0.0, 2.0));
+        let (modulus, argument) = z.to_polar();
+        assert!((modulus - 2.0).abs() < 1e-12 && (argument - FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(Complex::new(-1.0, 0.0).argument(), PI);
+    }
+
+    #[test]
+    fn functions() {
+        let i = Complex::<f64>::i();
+        assert_close((i * PI).exp(), Complex::new(-1.0, 0.0));
+        assert_close(Complex::new(-1.0, 0.0).ln(), i * PI);
+        assert_close(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_close(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_close(Complex::new(0.0, -2.0).sqrt(), Complex::new(1.0, -1.0));
+        assert_eq!(Complex::new(0.0, 0.0).sqrt(), Complex::zero());
+
+        let z = Complex::new(0.7, -1.3);
+        assert_close(z.sqrt() * z.sqrt(), z);
+        assert_close(z.ln().exp(), z);
+        assert_close(z.powf(3.0), z * z * z);
+        assert_close(z.powc(Complex::new(2.0, 0.0)), z * z);
+        assert_close(i.powc(i), Complex::new((-FRAC_PI_2).exp(), 0.0));
+        assert_close(z.sin() * z.sin() + z.cos() * z.cos(), Complex::one());
+        assert_close(z.cosh() * z.cosh() - z.sinh() * z.sinh(), Complex::one());
+        assert_close(z.tan(), z.sin() / z.cos());
+        assert_close((i * z).sin(), i * z.sinh());
+        assert_close(z.tanh(), z.sinh() / z.cosh());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1, 2).to_string(), "1 + 2i");
+        assert_eq!(Complex::new(1, -2).to_string(), "1 - 2i");
+        assert_eq!(format!("{:.2}", Complex::new(0.5, -1.0)), "0.50 - 1.00i");
+        assert_eq!(format!("{:+}", Complex::new(1, 2)), "+1 + 2i");
+    }
+
+    #[test]
+    fn linear_algebra() {
+        let i = Complex::<f64>::i();
+        let one = Complex::one();
+        let v = Vector3::new(one, i, one + i);
+        assert_eq!(v * i, Vector3::new(i, -one, i - one));
+        // `dot` is bilinear and `hdot` is the Hermitian inner product.
+        assert_eq!(v.dot(v), Complex::new(0.0, 2.0));
+        assert_eq!(v.hdot(v), Complex::new(4.0, 0.0));
+        assert_eq!(v.hdot(v * i), v.hdot(v) * i);
+        assert_eq!((v * i).hdot(v), v.hdot(v) * -i);
+
+        let m = Matrix2x2::from_rows([[one, i], [i * 2.0, one + i]]);
+        assert_eq!(
+            m.adjoint(),
+            Matrix2x2::from_rows([[one, i * -2.0], [-i, one - i]])
+        );
+        assert_eq!(
+            m * Matrix2x2::identity(),
+            Matrix2x2::from_rows([[one, i], [i * 2.0, one + i]])
+        );
+
+        #[cfg(feature = "alloc")]
+        {
+            let size = 5;
+            let storage: Vec<_> = (0..size * size)
+                .map(|k| Complex::new(k as f64, (k % 3) as f64))
+                .collect();
+            let m = MatrixNxN::from_vec(size, storage);
+            let adjoint = m.clone().adjoint();
+            for row in 0..size {
+                for column in 0..size {
+                    assert_eq!(adjoint.row(row)[column], m.row(column)[row].conjugate());
+                }
+            }
+            assert_eq!(adjoint.adjoint(), m);
+
+            // Tridiagonal with `2` on the diagonal and `±i` beside it, so the determinants of
+            // the leading blocks go 2, 3, 4, 5, 6.
+            let hermitian = MatrixNxN::from_vec(
+                size,
+                (0..size * size)
+                    .map(|k| match (k % size) as isize - (k / size) as isize {
+                        0 => one * 2.0,
+                        1 => i,
+                        -1 => -i,
+                        _ => Complex::zero(),
+                    })
+                    .collect(),
+            );
+            assert_close(hermitian.determinant(), Complex::new(6.0, 0.0));
+            assert_eq!(hermitian.rank(), size);
+            // Rows are multiples of the first by powers of `i`.
+            let first: Vec<_> = (0..size).map(|k| Complex::new(k as f64, 1.0)).collect();
+            let singular = MatrixNxN::from_vec(
+                size,
+                (0..size * size)
+                    .map(|k| first[k % size] * i.powi((k / size) as i32))
+                    .collect(),
+            );
+            assert!(singular.determinant().modulus() < 1e-12);
+            assert_eq!(singular.rank(), 1);
+            assert_eq!(
+                MatrixNxN::from_vec(size, (0..25).collect()).adjoint(),
+                MatrixNxN::from_vec(size, (0..25).collect()).transpose()
+            );
+        }
+    }
+}
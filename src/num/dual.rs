@@ -0,0 +1,483 @@
+//! Forward-mode automatic differentiation with dual numbers.
+
+use super::{Conjugate, Num, One, Real, Signed, Zero};
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::matrix::matrix_nxn::MatrixNxN;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::vector::{vector_n::VectorN, Orientation};
+
+#[cfg(feature = "alloc")]
+use crate::error::check_shape;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A dual number `value + derivative ε` with `ε² = 0`.
+///
+/// Evaluating a function on `Dual::variable(x)` carries its exact derivative at `x` along with
+/// the value. Dual numbers implement `Real`, so they work in any function written against
+/// starfruit's generic vectors and matrices.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Hash, Debug)]
+pub struct Dual<T> {
+    pub value: T,
+    pub derivative: T,
+}
+
+impl<T> Dual<T> {
+    pub const fn new(value: T, derivative: T) -> Self {
+        Self { value, derivative }
+    }
+}
+
+impl<T: Num + Copy> Dual<T> {
+    /// A constant, whose derivative is zero.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+    /// The variable being differentiated with respect to, whose derivative is one.
+    pub fn variable(value: T) -> Self {
+        Self::new(value, T::one())
+    }
+    /// Applies a function with value `f` and derivative `df` at `self.value`.
+    fn chain(self, f: T, df: T) -> Self {
+        Self::new(f, df * self.derivative)
+    }
+}
+
+/// The derivative of `f` at `x`.
+pub fn derivative<T: Num + Copy>(f: impl FnOnce(Dual<T>) -> Dual<T>, x: T) -> T {
+    f(Dual::variable(x)).derivative
+}
+
+/// `x` as constants, except component `index` which is the variable.
+#[cfg(feature = "alloc")]
+fn seed<T: Num + Copy, O: Orientation>(x: &VectorN<T, O>, index: usize) -> VectorN<Dual<T>, O> {
+    VectorN::from_vec(
+        x.as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| match i == index {
+                true => Dual::variable(value),
+                false => Dual::constant(value),
+            })
+            .collect(),
+    )
+}
+
+/// The gradient of a scalar function at `x`, from one evaluation per component.
+#[cfg(feature = "alloc")]
+pub fn gradient<T: Num + Copy, O: Orientation>(
+    f: impl Fn(VectorN<Dual<T>, O>) -> Dual<T>,
+    x: &VectorN<T, O>,
+) -> VectorN<T, O> {
+    VectorN::from_vec(
+        (0..x.as_slice().len())
+            .map(|i| f(seed(x, i)).derivative)
+            .collect(),
+    )
+}
+
+/// The Jacobian at `x` with one row per output, for any number of outputs.
+#[cfg(feature = "alloc")]
+pub fn jacobian_rows<T: Num + Copy, O: Orientation, P: Orientation>(
+    f: impl Fn(VectorN<Dual<T>, O>) -> VectorN<Dual<T>, P>,
+    x: &VectorN<T, O>,
+) -> Vec<VectorN<T, O>> {
+    let (_, elements) = jacobian_elements(f, x);
+    elements
+        .chunks(x.as_slice().len())
+        .map(|row| VectorN::from_vec(row.to_vec()))
+        .collect()
+}
+
+/// The Jacobian at `x` of a function whose output has as many components as its input, with one
+/// row per output. Panics if the lengths differ; see [`jacobian_rows`] for other functions.
+#[cfg(feature = "alloc")]
+pub fn jacobian<T: Num + Copy, O: Orientation, P: Orientation>(
+    f: impl Fn(VectorN<Dual<T>, O>) -> VectorN<Dual<T>, P>,
+    x: &VectorN<T, O>,
+) -> MatrixNxN<T> {
+    try_jacobian(f, x).unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[cfg(feature = "alloc")]
+pub fn try_jacobian<T: Num + Copy, O: Orientation, P: Orientation>(
+    f: impl Fn(VectorN<Dual<T>, O>) -> VectorN<Dual<T>, P>,
+    x: &VectorN<T, O>,
+) -> Result<MatrixNxN<T>, crate::Error> {
+    let size = x.as_slice().len();
+    let (outputs, elements) = jacobian_elements(f, x);
+    check_shape(size, outputs)?;
+    Ok(MatrixNxN::from_vec(size, elements))
+}
+
+/// The number of outputs and the row-major Jacobian, from one evaluation per input.
+#[cfg(feature = "alloc")]
+fn jacobian_elements<T: Num + Copy, O: Orientation, P: Orientation>(
+    f: impl Fn(VectorN<Dual<T>, O>) -> VectorN<Dual<T>, P>,
+    x: &VectorN<T, O>,
+) -> (usize, Vec<T>) {
+    let inputs = x.as_slice().len();
+    let columns: Vec<Vec<T>> = (0..inputs)
+        .map(|i| {
+            f(seed(x, i))
+                .into_vec()
+                .into_iter()
+                .map(|output| output.derivative)
+                .collect()
+        })
+        .collect();
+    let outputs = columns[0].len();
+    let elements = (0..outputs * inputs)
+        .map(|k| columns[k % inputs][k / inputs])
+        .collect();
+    (outputs, elements)
+}
+
+/// `x` as constants, except component `index` which is the variable.
+fn seed_array<T: Num + Copy, const N: usize>(x: [T; N], index: usize) -> [Dual<T>; N] {
+    core::array::from_fn(|i| match i == index {
+        true => Dual::variable(x[i]),
+        false => Dual::constant(x[i]),
+    })
+}
+
+/// The gradient at `x` of a scalar function of a fixed number of variables, such as the
+/// components of a `Vector2` or `Vector3`.
+pub fn gradient_array<T: Num + Copy, const N: usize>(
+    f: impl Fn([Dual<T>; N]) -> Dual<T>,
+    x: [T; N],
+) -> [T; N] {
+    core::array::from_fn(|i| f(seed_array(x, i)).derivative)
+}
+
+/// The Jacobian at `x` of a function between fixed-size arrays, with one row per output.
+pub fn jacobian_array<T: Num + Copy, const N: usize, const M: usize>(
+    f: impl Fn([Dual<T>; N]) -> [Dual<T>; M],
+    x: [T; N],
+) -> [[T; N]; M] {
+    let columns: [[Dual<T>; M]; N] = core::array::from_fn(|i| f(seed_array(x, i)));
+    core::array::from_fn(|row| core::array::from_fn(|column| columns[column][row].derivative))
+}
+
+impl<T: Zero> From<T> for Dual<T> {
+    fn from(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+}
+
+impl<T: Num + Copy> Zero for Dual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.derivative.is_zero()
+    }
+}
+
+impl<T: Num + Copy> One for Dual<T> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Num + Copy> Num for Dual<T> {}
+
+impl<T: Num + Copy> Conjugate for Dual<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Real> Signed for Dual<T> {
+    fn abs(&self) -> Self {
+        if self.value < T::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+    fn signum(&self) -> Self {
+        Self::constant(self.value.signum())
+    }
+}
+
+impl<T: Real> Real for Dual<T> {
+    fn pi() -> Self {
+        Self::constant(T::pi())
+    }
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+    fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, T::one() / (sqrt + sqrt))
+    }
+    fn sin(self) -> Self {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(sin, cos)
+    }
+    fn cos(self) -> Self {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(cos, -sin)
+    }
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        self.chain(tan, T::one() + tan * tan)
+    }
+    fn asin(self) -> Self {
+        let root = (T::one() - self.value * self.value).sqrt();
+        self.chain(self.value.asin(), T::one() / root)
+    }
+    fn acos(self) -> Self {
+        let root = (T::one() - self.value * self.value).sqrt();
+        self.chain(self.value.acos(), -T::one() / root)
+    }
+    fn atan(self) -> Self {
+        self.chain(
+            self.value.atan(),
+            T::one() / (T::one() + self.value * self.value),
+        )
+    }
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        Self::new(
+            y.value.atan2(x.value),
+            (x.value * y.derivative - y.value * x.derivative)
+                / (x.value * x.value + y.value * y.value),
+        )
+    }
+    fn sinh(self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+    fn cosh(self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+    fn tanh(self) -> Self {
+        let tanh = self.value.tanh();
+        self.chain(tanh, T::one() - tanh * tanh)
+    }
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), T::one() / self.value)
+    }
+    fn powf(self, n: Self) -> Self {
+        let power = self.value.powf(n.value);
+        let mut derivative = n.value * self.value.powf(n.value - T::one()) * self.derivative;
+        // Skipping the term for a constant exponent keeps negative bases finite.
+        if !n.derivative.is_zero() {
+            derivative = derivative + power * self.value.ln() * n.derivative;
+        }
+        Self::new(power, derivative)
+    }
+    fn floor(self) -> Self {
+        Self::constant(self.value.floor())
+    }
+    fn ceil(self) -> Self {
+        Self::constant(self.value.ceil())
+    }
+    fn round(self) -> Self {
+        Self::constant(self.value.round())
+    }
+}
+
+impl<T: Num + Copy> Add for Dual<T> {
+    type Output = Dual<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T: Num + Copy> Sub for Dual<T> {
+    type Output = Dual<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T: Num + Copy> Mul for Dual<T> {
+    type Output = Dual<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.derivative + self.derivative * rhs.value,
+        )
+    }
+}
+
+impl<T: Num + Copy> Div for Dual<T> {
+    type Output = Dual<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Dual<T> {
+    type Output = Dual<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Num + Copy> $op<T> for Dual<T> {
+                    type Output = Dual<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        self $operator Dual::from(rhs)
+                    }
+                }
+            )*
+        }
+    }
+}
+
+op_impl!(Add +, Sub -, Mul *, Div /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+    use crate::linear_algebra::vector::vector_3::Vector3;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn derivatives() {
+        assert_eq!(derivative(|x| x * x * x - x * 2.0, 3.0), 25.0);
+        assert_close(
+            derivative(|x| x.sin() * x.exp(), 0.5),
+            0.5f64.exp() * (0.5f64.sin() + 0.5f64.cos()),
+        );
+        assert_close(derivative(|x| (x * x + 1.0).sqrt().ln(), 2.0), 2.0 / 5.0);
+        assert_close(derivative(|x| x.atan2(Dual::constant(2.0)), 2.0), 0.25);
+        assert_close(
+            derivative(|x| Dual::constant(2.0).powf(x), 3.0),
+            8.0 * 2.0f64.ln(),
+        );
+        assert_close(derivative(|x| x.powf(Dual::constant(3.0)), -2.0), 12.0);
+        assert_close(derivative(|x| x.tanh().asin(), 0.3), {
+            let t = 0.3f64.tanh();
+            (1.0 - t * t) / (1.0 - t * t).sqrt()
+        });
+        assert_eq!(derivative(|x: Dual<f64>| x.abs(), -4.0), -1.0);
+        assert_eq!(derivative(|x: Dual<f64>| x.floor(), 1.5), 0.0);
+    }
+
+    #[test]
+    fn through_vectors_and_matrices() {
+        // The derivative of the length of a rotating vector scaled by t.
+        let length = derivative(
+            |t: Dual<f64>| Vector3::new(t.cos(), t.sin(), Dual::constant(1.0)).length() * t,
+            2.0,
+        );
+        assert_close(length, 2.0f64.sqrt());
+        let determinant = derivative(
+            |t| Matrix2x2::from_rows([[t, t * t], [Dual::constant(1.0), t * t * t]]).determinant(),
+            3.0,
+        );
+        assert_close(determinant, 4.0 * 27.0 - 2.0 * 3.0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn gradients() {
+        // The Rosenbrock function in five dimensions.
+        let rosenbrock = |x: VectorN<Dual<f64>>| {
+            let x = x.into_vec();
+            x.windows(2).fold(Dual::zero(), |sum, pair| {
+                let (a, b) = (pair[0], pair[1]);
+                sum + (b - a * a) * (b - a * a) * 100.0
+                    + (Dual::<f64>::one() - a) * (Dual::<f64>::one() - a)
+            })
+        };
+        let x = VectorN::new(vec![1.0, 2.0, 0.5, -1.0, 3.0]);
+        let gradient = gradient(rosenbrock, &x);
+        let x = x.into_vec();
+        let expected: Vec<f64> = (0..5)
+            .map(|i| {
+                let mut g = 0.0;
+                if i + 1 < 5 {
+                    g += -400.0 * x[i] * (x[i + 1] - x[i] * x[i]) - 2.0 * (1.0 - x[i]);
+                }
+                if i > 0 {
+                    g += 200.0 * (x[i] - x[i - 1] * x[i - 1]);
+                }
+                g
+            })
+            .collect();
+        assert_eq!(gradient.into_vec(), expected);
+
+        let jacobian = jacobian(
+            |x: VectorN<Dual<f64>>| {
+                let sum = x.as_slice().iter().fold(Dual::zero(), |sum, &v| sum + v);
+                x.map(|v| v * sum)
+            },
+            &VectorN::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]),
+        );
+        assert_eq!(jacobian.size(), 5);
+        assert_eq!(jacobian.row(0), &[16.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(jacobian.row(2), &[3.0, 3.0, 18.0, 3.0, 3.0]);
+
+        // Five inputs and six outputs, the last being their sum.
+        let extended = |x: VectorN<Dual<f64>>| {
+            let mut outputs = x.into_vec();
+            outputs.push(outputs.iter().fold(Dual::zero(), |sum, &v| sum + v));
+            VectorN::new(outputs)
+        };
+        let x = VectorN::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let rows = jacobian_rows(extended, &x);
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[1].as_slice(), &[0.0, 1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(rows[5].as_slice(), &[1.0; 5]);
+        assert!(matches!(
+            try_jacobian(extended, &x),
+            Err(crate::Error::ShapeMismatch {
+                expected: 5,
+                found: 6
+            })
+        ));
+    }
+
+    #[test]
+    fn arrays() {
+        let gradient = gradient_array(|[x, y]: [Dual<f64>; 2]| x * x * y + y, [3.0, 2.0]);
+        assert_eq!(gradient, [12.0, 10.0]);
+
+        // From two inputs to three outputs, and from three to two.
+        let jacobian = jacobian_array(|[x, y]: [Dual<f64>; 2]| [x * y, x + y, x * x], [2.0, 3.0]);
+        assert_eq!(jacobian, [[3.0, 2.0], [1.0, 1.0], [4.0, 0.0]]);
+        let jacobian = jacobian_array(
+            |[x, y, z]: [Dual<f64>; 3]| [x * y * z, (x - z).sin()],
+            [1.0, 2.0, 1.0],
+        );
+        assert_eq!(jacobian, [[2.0, 1.0, 2.0], [1.0, 0.0, -1.0]]);
+        // The gradient of a vector's length is its direction.
+        let position = Vector3::new(1.0, 2.0, 2.0);
+        let gradient = gradient_array(
+            |x: [Dual<f64>; 3]| Vector3::<Dual<f64>>::from_array(x).length(),
+            position.storage,
+        );
+        assert_close(Vector3::from_array(gradient), position.normalize());
+    }
+}
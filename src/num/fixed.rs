@@ -0,0 +1,712 @@
+//! Fixed-point numbers.
+//!
+//! Every operation, including the elementary functions, is computed with integer arithmetic only,
+//! so results are bit-identical on every platform. The elementary functions work internally in a
+//! Q65.62 `i128` format and round once into the target format.
+
+use super::{Checked, Conjugate, Num, One, Real, Saturating, Signed, WideSum, Wrapping, Zero};
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::Hash;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The number of fractional bits of the internal format.
+const WORKING_FRAC: u32 = 62;
+const ONE: i128 = 1 << WORKING_FRAC;
+const PI: i128 = 14488038916154245685;
+const HALF_PI: i128 = 7244019458077122842;
+const LN_2: i128 = 3196577161300663915;
+/// The reciprocal of the CORDIC gain, the product of `cos(atan(2^-i))`.
+const CORDIC_GAIN: i128 = 2800459870029452954;
+/// `atan(2^-i)` for each CORDIC iteration.
+const ATAN: [i128; WORKING_FRAC as usize] = [
+    3622009729038561421,
+    2138197195906305897,
+    1129764675555192497,
+    573486189672913778,
+    287855953345232185,
+    144068303048368715,
+    72051730834756822,
+    36028064038054493,
+    18014306884351854,
+    9007187801521084,
+    4503598195715550,
+    2251799634728303,
+    1125899884473003,
+    562949950625109,
+    281474976361131,
+    140737488311637,
+    70368744172203,
+    35184372088149,
+    17592186044331,
+    8796093022197,
+    4398046511103,
+    2199023255552,
+    1099511627776,
+    549755813888,
+    274877906944,
+    137438953472,
+    68719476736,
+    34359738368,
+    17179869184,
+    8589934592,
+    4294967296,
+    2147483648,
+    1073741824,
+    536870912,
+    268435456,
+    134217728,
+    67108864,
+    33554432,
+    16777216,
+    8388608,
+    4194304,
+    2097152,
+    1048576,
+    524288,
+    262144,
+    131072,
+    65536,
+    32768,
+    16384,
+    8192,
+    4096,
+    2048,
+    1024,
+    512,
+    256,
+    128,
+    64,
+    32,
+    16,
+    8,
+    4,
+    2,
+];
+/// A value beyond the range of every format, returned by `exp` on overflow.
+const HUGE: i128 = 1 << 125;
+
+/// The integer types that can store a `Fixed`.
+pub trait FixedInt: Copy + Default + Ord + Hash + Debug + 'static {
+    const BITS: u32;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn to_i128(self) -> i128;
+    /// Keeps the low bits, like `as`.
+    fn wrapping_from_i128(value: i128) -> Self;
+    fn saturating_from_i128(value: i128) -> Self;
+    fn checked_from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! fixed_int_impl {
+    ($($t:ty),*) => {
+        $(
+            impl FixedInt for $t {
+                const BITS: u32 = <$t>::BITS;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                fn wrapping_from_i128(value: i128) -> Self {
+                    value as $t
+                }
+                fn saturating_from_i128(value: i128) -> Self {
+                    value.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+                fn checked_from_i128(value: i128) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+fixed_int_impl!(i8, i16, i32, i64);
+
+/// A signed fixed-point number stored in `I` with `FRAC` fractional bits.
+///
+/// The operators wrap on overflow in every build profile, so results never depend on whether
+/// debug assertions are enabled. Division by zero panics.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Fixed<I, const FRAC: u32> {
+    bits: I,
+}
+
+/// 16 integer and 16 fractional bits.
+pub type Q16_16 = Fixed<i32, 16>;
+/// 32 integer and 32 fractional bits.
+pub type Q32_32 = Fixed<i64, 32>;
+
+impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+    const VALID: () = assert!(
+        FRAC < I::BITS && FRAC <= WORKING_FRAC,
+        "Too many fractional bits for the storage type"
+    );
+    pub const MIN: Self = Self { bits: I::MIN };
+    pub const MAX: Self = Self { bits: I::MAX };
+
+    pub fn from_bits(bits: I) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self { bits }
+    }
+    pub fn to_bits(self) -> I {
+        self.bits
+    }
+    /// The nearest fixed-point number, saturating outside the range. NaN becomes zero.
+    pub fn from_f64(value: f64) -> Self {
+        let scaled = Real::round(value * (1u128 << FRAC) as f64);
+        Self::from_bits(if scaled >= I::MAX.to_i128() as f64 {
+            I::MAX
+        } else if scaled <= I::MIN.to_i128() as f64 {
+            I::MIN
+        } else {
+            I::saturating_from_i128(scaled as i128)
+        })
+    }
+    pub fn to_f64(self) -> f64 {
+        self.raw() as f64 / (1u128 << FRAC) as f64
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturate(self.raw() + rhs.raw())
+    }
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturate(self.raw() - rhs.raw())
+    }
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturate((self.raw() * rhs.raw()) >> FRAC)
+    }
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        Self::saturate((self.raw() << FRAC) / rhs.raw())
+    }
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::wrap(self.raw() + rhs.raw())
+    }
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::wrap(self.raw() - rhs.raw())
+    }
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::wrap((self.raw() * rhs.raw()) >> FRAC)
+    }
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        Self::wrap((self.raw() << FRAC) / rhs.raw())
+    }
+
+    fn raw(self) -> i128 {
+        self.bits.to_i128()
+    }
+    fn wrap(raw: i128) -> Self {
+        Self::from_bits(I::wrapping_from_i128(raw))
+    }
+    fn saturate(raw: i128) -> Self {
+        Self::from_bits(I::saturating_from_i128(raw))
+    }
+    fn to_working(self) -> i128 {
+        self.raw() << (WORKING_FRAC - FRAC)
+    }
+    /// Rounds a working value to the nearest representable number, saturating.
+    fn from_working(value: i128) -> Self {
+        let shift = WORKING_FRAC - FRAC;
+        if shift == 0 {
+            Self::saturate(value)
+        } else {
+            Self::saturate((value + (1 << (shift - 1))) >> shift)
+        }
+    }
+    /// Rounds to an integral value with `offset` added to the raw bits first.
+    fn floor_offset(self, offset: i128) -> Self {
+        Self::saturate(((self.raw() + offset) >> FRAC) << FRAC)
+    }
+}
+
+/// The cosine and sine of an angle in `[-π/2, π/2]`.
+fn cordic_rotate(mut angle: i128) -> (i128, i128) {
+    let (mut x, mut y) = (CORDIC_GAIN, 0);
+    for (i, &step) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if angle >= 0 {
+            x -= dx;
+            y += dy;
+            angle -= step;
+        } else {
+            x += dx;
+            y -= dy;
+            angle += step;
+        }
+    }
+    (x, y)
+}
+
+/// The sine and cosine of any angle.
+fn sin_cos(angle: i128) -> (i128, i128) {
+    let quadrant = (angle + HALF_PI / 2).div_euclid(HALF_PI);
+    let (cos, sin) = cordic_rotate(angle - quadrant * HALF_PI);
+    match quadrant.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// The angle of `(x, y)`, which may be in any fixed-point scale.
+fn atan2(y: i128, x: i128) -> i128 {
+    let largest = x.unsigned_abs().max(y.unsigned_abs());
+    if largest == 0 {
+        return 0;
+    }
+    // Scale so the vector is large for precision but cannot overflow as CORDIC grows it.
+    let shift = 61 - (128 - largest.leading_zeros() as i32);
+    let scale = |value: i128| {
+        if shift >= 0 {
+            value << shift
+        } else {
+            value >> -shift
+        }
+    };
+    let (x, y) = (scale(x), scale(y));
+    let (mut x, mut y, mut angle) = match (x < 0, y >= 0) {
+        (false, _) => (x, y, 0),
+        (true, true) => (y, -x, HALF_PI),
+        (true, false) => (-y, x, -HALF_PI),
+    };
+    for (i, &step) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            angle += step;
+        } else {
+            x -= dx;
+            y += dy;
+            angle -= step;
+        }
+    }
+    angle
+}
+
+/// `e^x`, or `HUGE` if the result exceeds every format.
+fn exp(x: i128) -> i128 {
+    let k = (x + LN_2 / 2).div_euclid(LN_2);
+    if k >= 63 {
+        return HUGE;
+    }
+    if k < -(WORKING_FRAC as i128) - 1 {
+        return 0;
+    }
+    let r = x - k * LN_2;
+    let (mut sum, mut term, mut n) = (ONE, ONE, 1);
+    loop {
+        term = ((term * r) >> WORKING_FRAC) / n;
+        if term == 0 {
+            break;
+        }
+        sum += term;
+        n += 1;
+    }
+    if k >= 0 {
+        sum << k
+    } else {
+        sum >> -k
+    }
+}
+
+/// The natural logarithm of a positive number with `frac` fractional bits.
+fn ln(raw: i128, frac: u32) -> i128 {
+    let top = 127 - raw.leading_zeros() as i128;
+    // Normalize the mantissa into [1, 2) with the working scale.
+    let mantissa = if top <= WORKING_FRAC as i128 {
+        raw << (WORKING_FRAC as i128 - top)
+    } else {
+        raw >> (top - WORKING_FRAC as i128)
+    };
+    // ln(m) = 2 atanh((m - 1) / (m + 1)), a fast series for m in [1, 2).
+    let s = ((mantissa - ONE) << WORKING_FRAC) / (mantissa + ONE);
+    let s_squared = (s * s) >> WORKING_FRAC;
+    let (mut sum, mut power, mut k) = (0, s, 1);
+    while power != 0 {
+        sum += power / k;
+        power = (power * s_squared) >> WORKING_FRAC;
+        k += 2;
+    }
+    2 * sum + (top - frac as i128) * LN_2
+}
+
+/// `sqrt(1 - x^2)` for `x` in the working format, clamped to zero outside `[-1, 1]`.
+fn complement(x: i128) -> i128 {
+    let rest = ONE - ((x * x) >> WORKING_FRAC);
+    if rest <= 0 {
+        0
+    } else {
+        (rest << WORKING_FRAC).isqrt()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> From<I> for Fixed<I, FRAC> {
+    /// Converts an integer, saturating if it is out of range.
+    fn from(value: I) -> Self {
+        Self::saturate(value.to_i128() << FRAC)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Zero for Fixed<I, FRAC> {
+    fn zero() -> Self {
+        Self::default()
+    }
+    fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> One for Fixed<I, FRAC> {
+    /// One, or the largest value if one is not representable.
+    fn one() -> Self {
+        Self::saturate(1 << FRAC)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Num for Fixed<I, FRAC> {}
+
+impl<I: FixedInt, const FRAC: u32> Conjugate for Fixed<I, FRAC> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Signed for Fixed<I, FRAC> {
+    /// The absolute value, saturating for `MIN`.
+    fn abs(&self) -> Self {
+        Self::saturate(self.raw().abs())
+    }
+    fn signum(&self) -> Self {
+        match self.raw().signum() {
+            0 => Self::zero(),
+            1 => Self::one(),
+            _ => -Self::one(),
+        }
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Checked for Fixed<I, FRAC> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        I::checked_from_i128(self.raw() + rhs.raw()).map(Self::from_bits)
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        I::checked_from_i128(self.raw() - rhs.raw()).map(Self::from_bits)
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        I::checked_from_i128((self.raw() * rhs.raw()) >> FRAC).map(Self::from_bits)
+    }
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        I::checked_from_i128((self.raw() << FRAC) / rhs.raw()).map(Self::from_bits)
+    }
+}
+
+macro_rules! overflow_impl {
+    ($($trait:ident $prefix:ident { $($extra:tt)* }),*) => {
+        ::paste::paste! {
+            $(
+                impl<I: FixedInt, const FRAC: u32> $trait for Fixed<I, FRAC> {
+                    fn [<$prefix _add>](&self, rhs: &Self) -> Self {
+                        Self::[<$prefix _add>](*self, *rhs)
+                    }
+                    fn [<$prefix _sub>](&self, rhs: &Self) -> Self {
+                        Self::[<$prefix _sub>](*self, *rhs)
+                    }
+                    fn [<$prefix _mul>](&self, rhs: &Self) -> Self {
+                        Self::[<$prefix _mul>](*self, *rhs)
+                    }
+                    fn [<$prefix _div>](&self, rhs: &Self) -> Self {
+                        Self::[<$prefix _div>](*self, *rhs)
+                    }
+                    $($extra)*
+                }
+            )*
+        }
+    };
+}
+
+overflow_impl!(
+    Saturating saturating {
+        fn saturating_dot(pairs: impl IntoIterator<Item = (Self, Self)>) -> Self {
+            let mut sum = WideSum::default();
+            for (a, b) in pairs {
+                sum.add_product(a.raw(), b.raw());
+            }
+            Self::saturate(sum.clamp(FRAC, i128::MIN, i128::MAX))
+        }
+    },
+    Wrapping wrapping {}
+);
+
+/// The elementary functions are deterministic approximations accurate to about one unit in the
+/// last place. Where the floating-point result would be infinite or NaN they saturate instead:
+/// `sqrt` of a negative number and `powf` of a non-positive base are zero, `ln` of a non-positive
+/// number is `MIN`, and `tan` at its poles is `MAX` or `MIN`.
+impl<I: FixedInt, const FRAC: u32> Real for Fixed<I, FRAC> {
+    fn pi() -> Self {
+        Self::from_working(PI)
+    }
+    fn epsilon() -> Self {
+        Self::from_bits(I::wrapping_from_i128(1))
+    }
+    fn from_f64(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+    fn to_f64(self) -> f64 {
+        self.to_f64()
+    }
+    fn sqrt(self) -> Self {
+        if self.raw() <= 0 {
+            return Self::zero();
+        }
+        Self::saturate((self.raw() << FRAC).isqrt())
+    }
+    fn sin(self) -> Self {
+        Self::from_working(sin_cos(self.to_working()).0)
+    }
+    fn cos(self) -> Self {
+        Self::from_working(sin_cos(self.to_working()).1)
+    }
+    fn tan(self) -> Self {
+        let (sin, cos) = sin_cos(self.to_working());
+        if cos == 0 {
+            return Self::from_working(sin.signum() * HUGE);
+        }
+        Self::from_working((sin << WORKING_FRAC) / cos)
+    }
+    fn asin(self) -> Self {
+        let x = self.to_working();
+        Self::from_working(atan2(x.clamp(-ONE, ONE), complement(x)))
+    }
+    fn acos(self) -> Self {
+        let x = self.to_working();
+        Self::from_working(atan2(complement(x), x.clamp(-ONE, ONE)))
+    }
+    fn atan(self) -> Self {
+        Self::from_working(atan2(self.raw(), 1 << FRAC))
+    }
+    fn atan2(self, other: Self) -> Self {
+        Self::from_working(atan2(self.raw(), other.raw()))
+    }
+    fn sinh(self) -> Self {
+        let x = self.to_working();
+        Self::from_working((exp(x) - exp(-x)) / 2)
+    }
+    fn cosh(self) -> Self {
+        let x = self.to_working();
+        Self::from_working((exp(x) + exp(-x)) / 2)
+    }
+    fn tanh(self) -> Self {
+        // tanh(x) = 1 - 2 / (e^2x + 1), which cannot overflow for positive x.
+        let x = self.to_working().abs();
+        let tanh = ONE - ((2 * ONE) << WORKING_FRAC) / (exp(2 * x) + ONE);
+        Self::from_working(if self.raw() < 0 { -tanh } else { tanh })
+    }
+    fn exp(self) -> Self {
+        Self::from_working(exp(self.to_working()))
+    }
+    fn ln(self) -> Self {
+        if self.raw() <= 0 {
+            return Self::MIN;
+        }
+        Self::from_working(ln(self.raw(), FRAC))
+    }
+    fn powf(self, n: Self) -> Self {
+        if self.raw() <= 0 {
+            return Self::zero();
+        }
+        let exponent = n.raw().checked_mul(ln(self.raw(), FRAC)).map_or(
+            n.raw().signum() * ln(self.raw(), FRAC).signum() * HUGE,
+            |product| product >> FRAC,
+        );
+        Self::from_working(exp(exponent))
+    }
+    fn floor(self) -> Self {
+        self.floor_offset(0)
+    }
+    fn ceil(self) -> Self {
+        self.floor_offset((1 << FRAC) - 1)
+    }
+    /// Rounds half away from zero, like `f64::round`.
+    fn round(self) -> Self {
+        if self.raw() < 0 {
+            -(-self).floor_offset(1 << FRAC >> 1)
+        } else {
+            self.floor_offset(1 << FRAC >> 1)
+        }
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Add for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Sub for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Mul for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Div for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.wrapping_div(rhs)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Neg for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::wrap(-self.raw())
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Display for Fixed<I, FRAC> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_f64(), fmt)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Debug for Fixed<I, FRAC> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_f64(), fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+    use crate::linear_algebra::vector::vector_2::Vector2;
+
+    fn q16(value: f64) -> Q16_16 {
+        Q16_16::from_f64(value)
+    }
+
+    fn q32(value: f64) -> Q32_32 {
+        Q32_32::from_f64(value)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(q16(1.5) + q16(2.25), q16(3.75));
+        assert_eq!(q16(1.5) - q16(2.25), q16(-0.75));
+        assert_eq!(q16(1.5) * q16(-2.25), q16(-3.375));
+        assert_eq!(q16(3.375) / q16(1.5), q16(2.25));
+        assert_eq!(Q16_16::from(7), q16(7.0));
+        assert_eq!(Q16_16::one().to_bits(), 1 << 16);
+        assert_eq!(Q16_16::from(40000), Q16_16::MAX);
+
+        let big = q16(30000.0);
+        assert_eq!(big.saturating_add(big), Q16_16::MAX);
+        assert_eq!((-big).saturating_mul(big), Q16_16::MIN);
+        assert_eq!(big + big, q16(60000.0 - 65536.0));
+        assert_eq!(
+            Q16_16::saturating_dot([(big, big), (-big, big), (q16(1.5), q16(-2.0))]),
+            q16(-3.0)
+        );
+        assert_eq!(big.checked_add(&big), None);
+        assert_eq!(big.checked_div(&Q16_16::zero()), None);
+        assert_eq!(q16(1.0).checked_div(&q16(4.0)), Some(q16(0.25)));
+        assert_eq!(Q16_16::MIN.abs(), Q16_16::MAX);
+
+        assert_eq!(q16(-2.5).floor(), q16(-3.0));
+        assert_eq!(q16(-2.5).ceil(), q16(-2.0));
+        assert_eq!(q16(-2.5).round(), q16(-3.0));
+        assert_eq!(q16(2.4).round(), q16(2.0));
+        assert_eq!(format!("{}", q16(-2.5)), "-2.5");
+        assert_eq!(Q16_16::from_f64(f64::INFINITY), Q16_16::MAX);
+    }
+
+    #[test]
+    fn deterministic_bits() {
+        assert_eq!(q16(2.0).sqrt().to_bits(), 92681);
+        assert_eq!(Q16_16::pi().to_bits(), 205887);
+        assert_eq!(q32(2.0).sqrt().to_bits(), 6074000999);
+        assert_eq!(q16(-4.0).sqrt(), Q16_16::zero());
+    }
+
+    #[test]
+    fn functions() {
+        let tolerance = 4.0 / 65536.0;
+        for i in -40..=40 {
+            let x = i as f64 * 0.37;
+            let close =
+                |actual: Q16_16, expected: f64| (actual.to_f64() - expected).abs() <= tolerance;
+            assert!(close(q16(x).sin(), q16(x).to_f64().sin()), "sin {x}");
+            assert!(close(q16(x).cos(), q16(x).to_f64().cos()), "cos {x}");
+            assert!(close(q16(x).atan(), q16(x).to_f64().atan()), "atan {x}");
+            assert!(close(q16(x).tanh(), q16(x).to_f64().tanh()), "tanh {x}");
+            assert!(
+                close(q16(x / 8.0).exp(), q16(x / 8.0).to_f64().exp()),
+                "exp {x}"
+            );
+            if x > 0.0 {
+                assert!(close(q16(x).ln(), q16(x).to_f64().ln()), "ln {x}");
+                assert!(close(q16(x).sqrt(), q16(x).to_f64().sqrt()), "sqrt {x}");
+            }
+        }
+        for (y, x) in [
+            (1.0, 1.0),
+            (1.0, -1.0),
+            (-1.0, -1.0),
+            (-1.0, 1.0),
+            (0.0, -1.0),
+            (2.0, 0.0),
+        ] {
+            let angle = q32(y).atan2(q32(x)).to_f64();
+            assert!((angle - f64::atan2(y, x)).abs() < 1e-9);
+        }
+        assert!((q32(0.5).asin().to_f64() - 0.5f64.asin()).abs() < 1e-9);
+        assert!((q32(0.5).acos().to_f64() - 0.5f64.acos()).abs() < 1e-9);
+        assert!((q32(1.0).tan().to_f64() - 1.0f64.tan()).abs() < 1e-9);
+        assert!((q32(2.0).powf(q32(0.5)).to_f64() - 2.0f64.sqrt()).abs() < 1e-8);
+        assert!((q32(1000.0).sin().to_f64() - 1000.0f64.sin()).abs() < 1e-8);
+        assert!((q32(3.0).sinh().to_f64() - 3.0f64.sinh()).abs() < 1e-8);
+        assert_eq!(q16(20.0).exp(), Q16_16::MAX);
+        assert_eq!(q16(-20.0).exp(), Q16_16::zero());
+        assert_eq!(Q16_16::zero().ln(), Q16_16::MIN);
+    }
+
+    #[test]
+    fn linear_algebra() {
+        let v = Vector2::new(q16(3.0), q16(4.0));
+        assert_eq!(v.length(), q16(5.0));
+        let [x, y] = v.normalize().storage;
+        assert!((x.to_f64() - 0.6).abs() < 1e-4 && (y.to_f64() - 0.8).abs() < 1e-4);
+        let matrix = Matrix2x2::from_rows([[q16(2.0), q16(1.0)], [q16(1.0), q16(1.0)]]);
+        assert_eq!(matrix.determinant(), q16(1.0));
+        assert_eq!(
+            matrix.inverse(),
+            Some(Matrix2x2::from_rows([
+                [q16(1.0), q16(-1.0)],
+                [q16(-1.0), q16(2.0)]
+            ]))
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Half-precision storage types.
+//!
+//! `F16` is IEEE 754 binary16 and `BF16` is bfloat16, the upper half of an `f32`. Conversions
+//! round to nearest with ties to even, and arithmetic is performed in `f32` and rounded back.
+//! `f32` has more than twice the precision of either format, so the double rounding of `+`, `-`,
+//! `*`, `/` and `sqrt` still gives the correctly rounded result.
+
+use super::{Conjugate, Float, Num, One, Real, Signed, Zero};
+use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::vector::{vector_n::VectorN, Orientation};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Error, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// `2^exponent`, which must be in the normal range of `f64`.
+fn power_of_two(exponent: i32) -> f64 {
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+/// Shifts right, rounding to nearest with ties to even.
+fn round_shift(value: u64, shift: u32) -> u64 {
+    if shift == 0 {
+        return value;
+    }
+    if shift >= 64 {
+        return 0;
+    }
+    let quotient = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || remainder == half && quotient & 1 == 1 {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Rounds to a 16-bit format with `exponent_bits` and `mantissa_bits`.
+fn encode(value: f64, exponent_bits: u32, mantissa_bits: u32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 63) as u16) << (exponent_bits + mantissa_bits);
+    let exponent_mask = (1u16 << exponent_bits) - 1;
+    if value.is_nan() {
+        return sign | exponent_mask << mantissa_bits | 1 << (mantissa_bits - 1);
+    }
+    if value.is_infinite() {
+        return sign | exponent_mask << mantissa_bits;
+    }
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    if exponent == 0 {
+        // Zero, or an f64 subnormal far below the smallest half subnormal.
+        return sign;
+    }
+    let significand = bits & ((1 << 52) - 1) | 1 << 52;
+    let biased = exponent - 1023 + (1 << (exponent_bits - 1)) - 1;
+    if biased >= 1 {
+        let mut rounded = round_shift(significand, 52 - mantissa_bits);
+        let mut biased = biased as u64;
+        // Rounding up may carry into the next binade.
+        if rounded == 1 << (mantissa_bits + 1) {
+            rounded >>= 1;
+            biased += 1;
+        }
+        if biased >= exponent_mask as u64 {
+            return sign | exponent_mask << mantissa_bits;
+        }
+        sign | (biased << mantissa_bits | rounded & ((1 << mantissa_bits) - 1)) as u16
+    } else {
+        // Subnormal, where rounding up to the smallest normal encodes correctly by itself.
+        let shift = 52 - mantissa_bits + (1 - biased) as u32;
+        sign | round_shift(significand, shift) as u16
+    }
+}
+
+/// The exact value of a 16-bit format with `exponent_bits` and `mantissa_bits`.
+fn decode(bits: u16, exponent_bits: u32, mantissa_bits: u32) -> f32 {
+    let sign = if bits >> (exponent_bits + mantissa_bits) == 1 {
+        -1.0
+    } else {
+        1.0
+    };
+    let exponent_mask = (1u16 << exponent_bits) - 1;
+    let exponent = (bits >> mantissa_bits) & exponent_mask;
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+    let bias = (1 << (exponent_bits - 1)) - 1;
+    let magnitude = if exponent == exponent_mask {
+        if mantissa == 0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    } else if exponent == 0 {
+        mantissa as f64 * power_of_two(1 - bias - mantissa_bits as i32)
+    } else {
+        (mantissa | 1 << mantissa_bits) as f64
+            * power_of_two(exponent as i32 - bias - mantissa_bits as i32)
+    };
+    (sign * magnitude) as f32
+}
+
+macro_rules! half_impl {
+    ($($half:ident $exponent_bits:literal $mantissa_bits:literal $name:literal),*) => {
+        $(
+            #[doc = concat!("A ", $name, " floating-point number.")]
+            ///
+            /// Comparisons follow IEEE 754, so NaN is unequal to itself and the zeros are equal.
+            #[repr(transparent)]
+            #[derive(Copy, Clone, Default)]
+            pub struct $half(u16);
+
+            impl $half {
+                const SIGN: u16 = 1 << ($exponent_bits + $mantissa_bits);
+                const EXPONENT: u16 = ((1 << $exponent_bits) - 1) << $mantissa_bits;
+
+                pub const ZERO: Self = Self(0);
+                pub const INFINITY: Self = Self(Self::EXPONENT);
+                pub const NEG_INFINITY: Self = Self(Self::SIGN | Self::EXPONENT);
+                pub const NAN: Self = Self(Self::EXPONENT | 1 << ($mantissa_bits - 1));
+                /// The largest finite value.
+                pub const MAX: Self = Self(Self::EXPONENT - 1);
+                /// The smallest positive normal value.
+                pub const MIN_POSITIVE: Self = Self(1 << $mantissa_bits);
+
+                pub const fn from_bits(bits: u16) -> Self {
+                    Self(bits)
+                }
+                pub const fn to_bits(self) -> u16 {
+                    self.0
+                }
+                pub fn from_f32(value: f32) -> Self {
+                    Self(encode(value as f64, $exponent_bits, $mantissa_bits))
+                }
+                /// Rounds directly from `f64`, avoiding the double rounding of going through `f32`.
+                pub fn from_f64(value: f64) -> Self {
+                    Self(encode(value, $exponent_bits, $mantissa_bits))
+                }
+                /// The exact value, which every `f32` can represent.
+                pub fn to_f32(self) -> f32 {
+                    decode(self.0, $exponent_bits, $mantissa_bits)
+                }
+                pub fn to_f64(self) -> f64 {
+                    self.to_f32() as f64
+                }
+                pub fn is_nan(self) -> bool {
+                    self.0 & !Self::SIGN > Self::EXPONENT
+                }
+                /// Rounds every element of a buffer.
+                #[cfg(feature = "alloc")]
+                pub fn from_f32_slice(values: &[f32]) -> Vec<Self> {
+                    values.iter().map(|&value| Self::from_f32(value)).collect()
+                }
+                /// Widens every element of a buffer.
+                #[cfg(feature = "alloc")]
+                pub fn to_f32_vec(values: &[Self]) -> Vec<f32> {
+                    values.iter().map(|value| value.to_f32()).collect()
+                }
+
+                fn apply(self, f: impl FnOnce(f32) -> f32) -> Self {
+                    Self::from_f32(f(self.to_f32()))
+                }
+            }
+
+            impl From<$half> for f32 {
+                fn from(value: $half) -> Self {
+                    value.to_f32()
+                }
+            }
+
+            impl PartialEq for $half {
+                fn eq(&self, other: &Self) -> bool {
+                    self.to_f32() == other.to_f32()
+                }
+            }
+
+            impl PartialOrd for $half {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    self.to_f32().partial_cmp(&other.to_f32())
+                }
+            }
+
+            impl Zero for $half {
+                fn zero() -> Self {
+                    Self::ZERO
+                }
+                fn is_zero(&self) -> bool {
+                    self.0 & !Self::SIGN == 0
+                }
+            }
+
+            impl One for $half {
+                fn one() -> Self {
+                    Self::from_f32(1.0)
+                }
+            }
+
+            impl Num for $half {}
+
+            impl Conjugate for $half {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl Signed for $half {
+                fn abs(&self) -> Self {
+                    Self(self.0 & !Self::SIGN)
+                }
+                fn signum(&self) -> Self {
+                    self.apply(f32::signum)
+                }
+            }
+
+            impl Real for $half {
+                fn pi() -> Self {
+                    Self::from_f64(::core::f64::consts::PI)
+                }
+                fn epsilon() -> Self {
+                    Self::from_f64(power_of_two(-$mantissa_bits))
+                }
+                fn from_f64(value: f64) -> Self {
+                    Self::from_f64(value)
+                }
+                fn to_f64(self) -> f64 {
+                    self.to_f64()
+                }
+                fn sqrt(self) -> Self {
+                    self.apply(<f32 as Real>::sqrt)
+                }
+                fn sin(self) -> Self {
+                    self.apply(<f32 as Real>::sin)
+                }
+                fn cos(self) -> Self {
+                    self.apply(<f32 as Real>::cos)
+                }
+                fn tan(self) -> Self {
+                    self.apply(<f32 as Real>::tan)
+                }
+                fn asin(self) -> Self {
+                    self.apply(<f32 as Real>::asin)
+                }
+                fn acos(self) -> Self {
+                    self.apply(<f32 as Real>::acos)
+                }
+                fn atan(self) -> Self {
+                    self.apply(<f32 as Real>::atan)
+                }
+                fn atan2(self, other: Self) -> Self {
+                    self.apply(|y| y.atan2(other.to_f32()))
+                }
+                fn sinh(self) -> Self {
+                    self.apply(<f32 as Real>::sinh)
+                }
+                fn cosh(self) -> Self {
+                    self.apply(<f32 as Real>::cosh)
+                }
+                fn tanh(self) -> Self {
+                    self.apply(<f32 as Real>::tanh)
+                }
+                fn exp(self) -> Self {
+                    self.apply(<f32 as Real>::exp)
+                }
+                fn ln(self) -> Self {
+                    self.apply(<f32 as Real>::ln)
+                }
+                fn powf(self, n: Self) -> Self {
+                    self.apply(|x| x.powf(n.to_f32()))
+                }
+                fn floor(self) -> Self {
+                    self.apply(<f32 as Real>::floor)
+                }
+                fn ceil(self) -> Self {
+                    self.apply(<f32 as Real>::ceil)
+                }
+                fn round(self) -> Self {
+                    self.apply(<f32 as Real>::round)
+                }
+            }
+
+            impl Float for $half {
+                fn infinity() -> Self {
+                    Self::INFINITY
+                }
+                fn is_nan(self) -> bool {
+                    self.is_nan()
+                }
+                fn next_up(self) -> Self {
+                    if self.is_nan() || self.0 == Self::INFINITY.0 {
+                        self
+                    } else if self.is_zero() {
+                        Self(1)
+                    } else if self.0 & Self::SIGN == 0 {
+                        Self(self.0 + 1)
+                    } else {
+                        Self(self.0 - 1)
+                    }
+                }
+                fn next_down(self) -> Self {
+                    -(-self).next_up()
+                }
+            }
+
+            impl Neg for $half {
+                type Output = $half;
+
+                fn neg(self) -> Self::Output {
+                    Self(self.0 ^ Self::SIGN)
+                }
+            }
+
+            impl Display for $half {
+                fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+                    Display::fmt(&self.to_f32(), fmt)
+                }
+            }
+
+            impl Debug for $half {
+                fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+                    Debug::fmt(&self.to_f32(), fmt)
+                }
+            }
+
+            ::paste::paste! {
+                impl Matrix4x4<f32> {
+                    #[doc = concat!("Rounds every element to ", $name, ".")]
+                    pub fn [<to_ $half:lower>](self) -> Matrix4x4<$half> {
+                        self.map($half::from_f32)
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                impl<O: Orientation> VectorN<f32, O> {
+                    #[doc = concat!("Rounds every element to ", $name, ".")]
+                    pub fn [<to_ $half:lower>](&self) -> VectorN<$half, O> {
+                        VectorN::from_vec($half::from_f32_slice(self.as_slice()))
+                    }
+                }
+            }
+
+            impl Matrix4x4<$half> {
+                #[doc = concat!("Widens every ", $name, " element to f32 exactly.")]
+                pub fn to_f32(self) -> Matrix4x4<f32> {
+                    self.map($half::to_f32)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<O: Orientation> VectorN<$half, O> {
+                #[doc = concat!("Widens every ", $name, " element to f32 exactly.")]
+                pub fn to_f32(&self) -> VectorN<f32, O> {
+                    VectorN::from_vec($half::to_f32_vec(self.as_slice()))
+                }
+            }
+        )*
+    };
+}
+
+half_impl!(F16 5 10 "IEEE 754 half-precision", BF16 8 7 "bfloat16");
+
+macro_rules! op_impl {
+    ($($half:ident: $($op:ident $operator:tt),*);*) => {
+        ::paste::paste! {
+            $($(
+                impl $op for $half {
+                    type Output = $half;
+
+                    fn [<$op:lower>](self, rhs: Self) -> Self::Output {
+                        $half::from_f32(self.to_f32() $operator rhs.to_f32())
+                    }
+                }
+            )*)*
+        }
+    }
+}
+
+op_impl!(F16: Add +, Sub -, Mul *, Div /; BF16: Add +, Sub -, Mul *, Div /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::vector::vector_3::Vector3;
+
+    #[test]
+    fn conversion() {
+        assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(-2.0).to_bits(), 0xc000);
+        assert_eq!(F16::from_f32(65504.0).to_bits(), 0x7bff);
+        assert_eq!(F16::from_f32(65520.0), F16::INFINITY);
+        assert_eq!(F16::from_f32(65519.0).to_bits(), 0x7bff);
+        assert_eq!(F16::from_f32(2.0f32.powi(-24)).to_bits(), 0x0001);
+        assert_eq!(F16::from_f32(2.0f32.powi(-25)).to_bits(), 0x0000);
+        assert_eq!(F16::from_f32(1.5 * 2.0f32.powi(-25)).to_bits(), 0x0001);
+        assert_eq!(F16::from_f32(6.097555e-5).to_bits(), 0x03ff);
+        assert_eq!(F16::from_f32(0.1).to_bits(), 0x2e66);
+        assert!(F16::from_f32(f32::NAN).is_nan());
+        assert_eq!(F16::from_f32(-0.0).to_bits(), 0x8000);
+        // Ties round to even: 1 + 2^-11 lies halfway between 1 and 1 + 2^-10.
+        assert_eq!(F16::from_f32(1.0 + 2.0f32.powi(-11)).to_bits(), 0x3c00);
+        assert_eq!(
+            F16::from_f32(1.0 + 3.0 * 2.0f32.powi(-11)).to_bits(),
+            0x3c02
+        );
+        // Rounding f64 through f32 first would round 1 + 2^-11 + 2^-40 down to the tie.
+        assert_eq!(
+            F16::from_f64(1.0 + 2.0f64.powi(-11) + 2.0f64.powi(-40)).to_bits(),
+            0x3c01
+        );
+
+        assert_eq!(BF16::from_f32(1.0).to_bits(), 0x3f80);
+        assert_eq!(BF16::from_f32(3.140625).to_bits(), 0x4049);
+        assert_eq!(
+            BF16::from_f32(f32::from_bits(0x3f80_8000)).to_bits(),
+            0x3f80
+        );
+        assert_eq!(
+            BF16::from_f32(f32::from_bits(0x3f81_8000)).to_bits(),
+            0x3f82
+        );
+        assert_eq!(BF16::from_f32(f32::MAX), BF16::INFINITY);
+        assert_eq!(BF16::from_f32(f32::from_bits(1)).to_bits(), 0);
+
+        // Every finite value survives a round trip through f32.
+        for bits in 0..=u16::MAX {
+            let half = F16::from_bits(bits);
+            if !half.is_nan() {
+                assert_eq!(F16::from_f32(half.to_f32()).to_bits(), bits);
+            }
+            let brain = BF16::from_bits(bits);
+            if !brain.is_nan() {
+                assert_eq!(BF16::from_f32(brain.to_f32()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (F16::from_f32(1.5), F16::from_f32(0.25));
+        assert_eq!(a + b, F16::from_f32(1.75));
+        assert_eq!(a * b, F16::from_f32(0.375));
+        assert_eq!(a / b, F16::from_f32(6.0));
+        assert_eq!(-a, F16::from_f32(-1.5));
+        assert_eq!(F16::MAX + F16::MAX, F16::INFINITY);
+        assert!(F16::NAN != F16::NAN);
+        assert_eq!(F16::ZERO, -F16::ZERO);
+        assert!(F16::from_f32(-1.0) < F16::ZERO);
+        assert_eq!(F16::epsilon().to_f32(), 2.0f32.powi(-10));
+        assert_eq!(BF16::epsilon().to_f32(), 2.0f32.powi(-7));
+        assert_eq!(F16::from_f32(2.0).sqrt().to_bits(), 0x3da8);
+        assert_eq!(F16::ZERO.next_up().to_bits(), 1);
+        assert_eq!(F16::ZERO.next_down().to_bits(), 0x8001);
+        assert_eq!(F16::MAX.next_up(), F16::INFINITY);
+        assert_eq!(format!("{}", BF16::from_f32(-2.5)), "-2.5");
+    }
+
+    #[test]
+    fn buffers() {
+        let vector = Vector3::new(F16::from_f32(3.0), F16::from_f32(0.0), F16::from_f32(4.0));
+        assert_eq!(vector.length(), F16::from_f32(5.0));
+
+        let matrix = Matrix4x4::from_rows([
+            [1.0, 0.1, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 70000.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let half = matrix.to_f16();
+        assert_eq!(half.as_slice()[1].to_bits(), 0x2e66);
+        assert_eq!(half.as_slice()[11], F16::INFINITY);
+        assert_eq!(matrix.to_bf16().to_f32().as_slice()[11], 70144.0);
+        assert_eq!(half.to_f32().as_slice()[0], 1.0);
+
+        #[cfg(feature = "alloc")]
+        {
+            let weights = VectorN::new(vec![0.5, -0.25, 1.0 / 3.0, 2.0, 1e-8]);
+            let stored = weights.to_bf16();
+            assert_eq!(core::mem::size_of_val(stored.as_slice()), 10);
+            let restored = stored.to_f32();
+            for (original, restored) in weights.as_slice().iter().zip(restored.as_slice()) {
+                assert!((original - restored).abs() <= original.abs() * 2.0f32.powi(-8));
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! Exact second derivatives with hyper-dual numbers.
+
+use super::{Conjugate, Num, One, Real, Signed, Zero};
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::matrix::matrix_nxn::MatrixNxN;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::vector::{vector_n::VectorN, Orientation};
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A hyper-dual number `value + e1 ε₁ + e2 ε₂ + e12 ε₁ε₂` with `ε₁² = ε₂² = 0`.
+///
+/// Seeding `e1` and `e2` with directions `u` and `v` makes `e12` the second derivative along `u`
+/// and `v`, exact up to rounding since no differences are taken.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Hash, Debug)]
+pub struct HyperDual<T> {
+    pub value: T,
+    pub e1: T,
+    pub e2: T,
+    pub e12: T,
+}
+
+impl<T> HyperDual<T> {
+    pub const fn new(value: T, e1: T, e2: T, e12: T) -> Self {
+        Self { value, e1, e2, e12 }
+    }
+}
+
+impl<T: Num + Copy> HyperDual<T> {
+    /// A constant, whose derivatives are zero.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero(), T::zero(), T::zero())
+    }
+    /// The variable being differentiated with respect to, in both directions.
+    pub fn variable(value: T) -> Self {
+        Self::new(value, T::one(), T::one(), T::zero())
+    }
+    /// Applies a function with value `f` and first and second derivatives `df` and `ddf` at
+    /// `self.value`.
+    fn chain(self, f: T, df: T, ddf: T) -> Self {
+        Self::new(
+            f,
+            df * self.e1,
+            df * self.e2,
+            df * self.e12 + ddf * self.e1 * self.e2,
+        )
+    }
+    fn recip(self) -> Self {
+        let recip = T::one() / self.value;
+        let square = recip * recip;
+        self.chain(recip, T::zero() - square, (square + square) * recip)
+    }
+    fn is_constant(&self) -> bool {
+        self.e1.is_zero() && self.e2.is_zero() && self.e12.is_zero()
+    }
+}
+
+/// The second derivative of `f` at `x`.
+pub fn second_derivative<T: Num + Copy>(f: impl FnOnce(HyperDual<T>) -> HyperDual<T>, x: T) -> T {
+    f(HyperDual::variable(x)).e12
+}
+
+/// The Hessian of a scalar function at `x`, from one evaluation per pair of components.
+#[cfg(feature = "alloc")]
+pub fn hessian<T: Num + Copy, O: Orientation>(
+    f: impl Fn(VectorN<HyperDual<T>, O>) -> HyperDual<T>,
+    x: &VectorN<T, O>,
+) -> MatrixNxN<T> {
+    let size = x.as_slice().len();
+    let mut elements = vec![T::zero(); size * size];
+    for i in 0..size {
+        for j in i..size {
+            let seeded = x
+                .as_slice()
+                .iter()
+                .enumerate()
+                .map(|(k, &value)| {
+                    let one = |index| if k == index { T::one() } else { T::zero() };
+                    HyperDual::new(value, one(i), one(j), T::zero())
+                })
+                .collect();
+            let second = f(VectorN::from_vec(seeded)).e12;
+            elements[i * size + j] = second;
+            elements[j * size + i] = second;
+        }
+    }
+    MatrixNxN::from_vec(size, elements)
+}
+
+impl<T: Zero> From<T> for HyperDual<T> {
+    fn from(value: T) -> Self {
+        Self::new(value, T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Num + Copy> Zero for HyperDual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.is_constant()
+    }
+}
+
+impl<T: Num + Copy> One for HyperDual<T> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Num + Copy> Num for HyperDual<T> {}
+
+impl<T: Num + Copy> Conjugate for HyperDual<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Real> Signed for HyperDual<T> {
+    fn abs(&self) -> Self {
+        if self.value < T::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+    fn signum(&self) -> Self {
+        Self::constant(self.value.signum())
+    }
+}
+
+impl<T: Real> Real for HyperDual<T> {
+    fn pi() -> Self {
+        Self::constant(T::pi())
+    }
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+    fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        let derivative = T::one() / (sqrt + sqrt);
+        self.chain(sqrt, derivative, -derivative / (self.value + self.value))
+    }
+    fn sin(self) -> Self {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(sin, cos, -sin)
+    }
+    fn cos(self) -> Self {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(cos, -sin, -cos)
+    }
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        let derivative = T::one() + tan * tan;
+        self.chain(tan, derivative, (tan + tan) * derivative)
+    }
+    fn asin(self) -> Self {
+        let square = T::one() - self.value * self.value;
+        let derivative = T::one() / square.sqrt();
+        self.chain(
+            self.value.asin(),
+            derivative,
+            self.value * derivative / square,
+        )
+    }
+    fn acos(self) -> Self {
+        let square = T::one() - self.value * self.value;
+        let derivative = -T::one() / square.sqrt();
+        self.chain(
+            self.value.acos(),
+            derivative,
+            self.value * derivative / square,
+        )
+    }
+    fn atan(self) -> Self {
+        let derivative = T::one() / (T::one() + self.value * self.value);
+        let value = self.value;
+        self.chain(
+            value.atan(),
+            derivative,
+            -(value + value) * derivative * derivative,
+        )
+    }
+    fn atan2(self, other: Self) -> Self {
+        // Away from the axes atan2 differs from atan(y / x) or -atan(x / y) by a constant, so
+        // take the derivatives from whichever quotient is bounded.
+        let (y, x) = (self, other);
+        let derivatives = if x.value.abs() >= y.value.abs() {
+            (y / x).atan()
+        } else {
+            -(x / y).atan()
+        };
+        Self {
+            value: y.value.atan2(x.value),
+            ..derivatives
+        }
+    }
+    fn sinh(self) -> Self {
+        let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+        self.chain(sinh, cosh, sinh)
+    }
+    fn cosh(self) -> Self {
+        let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+        self.chain(cosh, sinh, cosh)
+    }
+    fn tanh(self) -> Self {
+        let tanh = self.value.tanh();
+        let derivative = T::one() - tanh * tanh;
+        self.chain(tanh, derivative, -(tanh + tanh) * derivative)
+    }
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp, exp)
+    }
+    fn ln(self) -> Self {
+        let recip = T::one() / self.value;
+        self.chain(self.value.ln(), recip, -recip * recip)
+    }
+    fn powf(self, n: Self) -> Self {
+        if !n.is_constant() {
+            return (self.ln() * n).exp();
+        }
+        // A constant exponent keeps negative bases finite.
+        let n = n.value;
+        let one = T::one();
+        self.chain(
+            self.value.powf(n),
+            n * self.value.powf(n - one),
+            n * (n - one) * self.value.powf(n - one - one),
+        )
+    }
+    fn floor(self) -> Self {
+        Self::constant(self.value.floor())
+    }
+    fn ceil(self) -> Self {
+        Self::constant(self.value.ceil())
+    }
+    fn round(self) -> Self {
+        Self::constant(self.value.round())
+    }
+}
+
+impl<T: Num + Copy> Add for HyperDual<T> {
+    type Output = HyperDual<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value + rhs.value,
+            self.e1 + rhs.e1,
+            self.e2 + rhs.e2,
+            self.e12 + rhs.e12,
+        )
+    }
+}
+
+impl<T: Num + Copy> Sub for HyperDual<T> {
+    type Output = HyperDual<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value - rhs.value,
+            self.e1 - rhs.e1,
+            self.e2 - rhs.e2,
+            self.e12 - rhs.e12,
+        )
+    }
+}
+
+impl<T: Num + Copy> Mul for HyperDual<T> {
+    type Output = HyperDual<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.e1 + self.e1 * rhs.value,
+            self.value * rhs.e2 + self.e2 * rhs.value,
+            self.value * rhs.e12 + self.e1 * rhs.e2 + self.e2 * rhs.e1 + self.e12 * rhs.value,
+        )
+    }
+}
+
+impl<T: Num + Copy> Div for HyperDual<T> {
+    type Output = HyperDual<T>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for HyperDual<T> {
+    type Output = HyperDual<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, -self.e1, -self.e2, -self.e12)
+    }
+}
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Num + Copy> $op<T> for HyperDual<T> {
+                    type Output = HyperDual<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        self $operator HyperDual::from(rhs)
+                    }
+                }
+            )*
+        }
+    }
+}
+
+op_impl!(Add +, Sub -, Mul *, Div /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn second_derivatives() {
+        assert_eq!(second_derivative(|x| x * x * x, 2.0), 12.0);
+        let x = 0.7f64;
+        assert_close(
+            second_derivative(|x| x.sin() * x.exp(), x),
+            2.0 * x.cos() * x.exp(),
+        );
+        assert_close(second_derivative(Real::sqrt, 4.0), -1.0 / 32.0);
+        assert_close(second_derivative(Real::ln, 2.0), -0.25);
+        assert_close(second_derivative(|x| x.atan(), 1.0), -0.5);
+        assert_close(
+            second_derivative(|x| x.powf(HyperDual::constant(3.0)), -2.0),
+            -12.0,
+        );
+        assert_close(second_derivative(|x| x.powf(x), 1.0), 2.0);
+        assert_close(second_derivative(Real::tanh, 0.5), {
+            let t = 0.5f64.tanh();
+            -2.0 * t * (1.0 - t * t)
+        });
+        assert_close(second_derivative(|x| x / (x + 1.0), 1.0), -0.25);
+        // atan2(x, 1) and atan2(1, 1 / x) are atan(x) for positive x.
+        assert_close(
+            second_derivative(|x| x.atan2(HyperDual::constant(1.0)), 2.0),
+            -4.0 / 25.0,
+        );
+        assert_close(
+            second_derivative(|x| HyperDual::constant(1.0).atan2(x.recip()), 2.0),
+            -4.0 / 25.0,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hessians() {
+        // f = x0² x1 + sin(x2) x3 + x4³, whose Hessian is sparse.
+        let x = VectorN::new(vec![1.0, 2.0, 0.5, 3.0, -1.0]);
+        let hessian = hessian(
+            |x: VectorN<HyperDual<f64>>| {
+                let x = x.into_vec();
+                x[0] * x[0] * x[1] + x[2].sin() * x[3] + x[4] * x[4] * x[4]
+            },
+            &x,
+        );
+        let mut expected = [0.0; 25];
+        expected[0] = 4.0;
+        expected[1] = 2.0;
+        expected[5] = 2.0;
+        expected[2 * 5 + 2] = -0.5f64.sin() * 3.0;
+        expected[2 * 5 + 3] = 0.5f64.cos();
+        expected[3 * 5 + 2] = 0.5f64.cos();
+        expected[24] = -6.0;
+        assert_eq!(hessian.as_slice(), &expected);
+    }
+}
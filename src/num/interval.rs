@@ -0,0 +1,749 @@
+//! Interval arithmetic with guaranteed enclosures.
+//!
+//! Every operation rounds its bounds outward, so the result always contains the exact result for
+//! every choice of operands within the inputs. Arithmetic and `sqrt` are correctly rounded and
+//! widen by one unit in the last place. The other elementary functions widen by two or three units,
+//! depending on the function, which bounds the error of both the platform's implementations and
+//! the crate's own when the `std` feature is disabled.
+
+use super::dual::Dual;
+use super::{Conjugate, Float, Num, One, Real, Signed, Zero};
+#[cfg(feature = "alloc")]
+use crate::error::check_shape;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A closed interval `[lower, upper]` of real numbers.
+///
+/// Intervals implement `Real`, so they can be the elements of vectors and matrices. Intervals are
+/// only ordered when they do not overlap.
+#[derive(Copy, Clone, PartialEq, Hash, Debug)]
+pub struct Interval<T> {
+    lower: T,
+    upper: T,
+}
+
+impl<T: Float> Interval<T> {
+    /// Panics if `lower` is greater than `upper` or either is NaN.
+    pub fn new(lower: T, upper: T) -> Self {
+        Self::try_new(lower, upper).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_new(lower: T, upper: T) -> Result<Self, crate::Error> {
+        if lower <= upper {
+            Ok(Self { lower, upper })
+        } else {
+            Err(crate::Error::InvalidArgument(
+                "Lower bound exceeds upper bound",
+            ))
+        }
+    }
+    pub fn point(value: T) -> Self {
+        Self::new(value, value)
+    }
+    /// The whole real line.
+    pub fn entire() -> Self {
+        Self::new(-T::infinity(), T::infinity())
+    }
+    pub fn lower(self) -> T {
+        self.lower
+    }
+    pub fn upper(self) -> T {
+        self.upper
+    }
+    /// The width, rounded up.
+    pub fn width(self) -> T {
+        if self.lower == self.upper {
+            T::zero()
+        } else {
+            (self.upper - self.lower).next_up()
+        }
+    }
+    /// A number in the interval close to its centre. This is zero for the whole real line and the
+    /// finite value nearest the infinite bound for an interval unbounded on one side.
+    pub fn midpoint(self) -> T {
+        let infinity = T::infinity();
+        match (self.lower == -infinity, self.upper == infinity) {
+            (true, true) => T::zero(),
+            (true, false) => (-infinity).next_up().min(self.upper),
+            (false, true) => infinity.next_down().max(self.lower),
+            (false, false) => {
+                let two = T::one() + T::one();
+                (self.lower / two + self.upper / two).clamp(self.lower, self.upper)
+            }
+        }
+    }
+    /// The largest absolute value in the interval.
+    pub fn magnitude(self) -> T {
+        self.lower.abs().max(self.upper.abs())
+    }
+    /// The smallest absolute value in the interval.
+    pub fn mignitude(self) -> T {
+        if self.contains(T::zero()) {
+            T::zero()
+        } else {
+            self.lower.abs().min(self.upper.abs())
+        }
+    }
+    pub fn contains(self, value: T) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+    pub fn contains_interval(self, other: Self) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+    /// Whether `other` lies strictly inside this interval.
+    pub fn interior_contains(self, other: Self) -> bool {
+        self.lower < other.lower && other.upper < self.upper
+    }
+    pub fn overlaps(self, other: Self) -> bool {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        self.overlaps(other)
+            .then(|| Self::new(self.lower.max(other.lower), self.upper.min(other.upper)))
+    }
+    /// The smallest interval containing both.
+    pub fn hull(self, other: Self) -> Self {
+        Self::new(self.lower.min(other.lower), self.upper.max(other.upper))
+    }
+
+    /// The square root of the non-negative part, or an error if there is none.
+    pub fn try_sqrt(self) -> Result<Self, crate::Error> {
+        if self.upper < T::zero() {
+            return Err(crate::Error::InvalidArgument(
+                "Square root of a negative interval",
+            ));
+        }
+        let interval = Self::new(self.lower.max(T::zero()), self.upper).increasing(T::sqrt, 1);
+        Ok(Self::new(interval.lower.max(T::zero()), interval.upper))
+    }
+    /// The logarithm of the positive part, or an error if there is none.
+    pub fn try_ln(self) -> Result<Self, crate::Error> {
+        if self.upper <= T::zero() {
+            return Err(crate::Error::InvalidArgument(
+                "Logarithm of a non-positive interval",
+            ));
+        }
+        if self.lower <= T::zero() {
+            let upper = self.upper.ln();
+            Ok(Self::new(
+                -T::infinity(),
+                Self::widen(upper, upper, 2).upper,
+            ))
+        } else {
+            Ok(self.increasing(T::ln, 2))
+        }
+    }
+    /// The arcsine of the part within `[-1, 1]`, or an error if there is none.
+    pub fn try_asin(self) -> Result<Self, crate::Error> {
+        let one = T::one();
+        let domain =
+            self.intersection(Self::new(-one, one))
+                .ok_or(crate::Error::InvalidArgument(
+                    "Arcsine of an interval outside [-1, 1]",
+                ))?;
+        Ok(domain.increasing(T::asin, 2))
+    }
+    /// The arccosine of the part within `[-1, 1]`, or an error if there is none.
+    pub fn try_acos(self) -> Result<Self, crate::Error> {
+        let one = T::one();
+        let domain =
+            self.intersection(Self::new(-one, one))
+                .ok_or(crate::Error::InvalidArgument(
+                    "Arccosine of an interval outside [-1, 1]",
+                ))?;
+        let interval = domain.decreasing(T::acos, 2);
+        Ok(Self::new(interval.lower.max(T::zero()), interval.upper))
+    }
+
+    /// Rounds correctly rounded bounds outward.
+    fn outward(lower: T, upper: T) -> Self {
+        Self::widen(lower, upper, 1)
+    }
+    /// Rounds bounds computed to within `ulps` units in the last place outward.
+    fn widen(mut lower: T, mut upper: T, ulps: u32) -> Self {
+        for _ in 0..ulps {
+            lower = lower.next_down();
+            upper = upper.next_up();
+        }
+        Self::new(lower, upper)
+    }
+    /// Encloses a non-decreasing function accurate to `ulps` units in the last place.
+    fn increasing(self, f: impl Fn(T) -> T, ulps: u32) -> Self {
+        Self::widen(f(self.lower), f(self.upper), ulps)
+    }
+    /// Encloses a non-increasing function accurate to `ulps` units in the last place.
+    fn decreasing(self, f: impl Fn(T) -> T, ulps: u32) -> Self {
+        Self::widen(f(self.upper), f(self.lower), ulps)
+    }
+    /// Whether the interval may contain `point + k period` for some integer `k`, erring towards
+    /// yes.
+    fn reaches(self, point: T, period: T) -> bool {
+        let (a, b) = ((self.lower - point) / period, (self.upper - point) / period);
+        let slack = a.abs().max(b.abs()).max(T::one()) * T::epsilon() * T::from_f64(8.0);
+        (b + slack).floor() >= (a - slack).ceil()
+    }
+    /// Encloses a function with period 2π and range `[-1, 1]` that peaks at `peak` and bottoms
+    /// out at `trough`, accurate to `ulps` units in the last place.
+    fn periodic(self, f: impl Fn(T) -> T, peak: T, trough: T, ulps: u32) -> Self {
+        let one = T::one();
+        let tau = T::pi() + T::pi();
+        // Also catches infinite bounds.
+        if (self.upper - self.lower).partial_cmp(&tau) != Some(Ordering::Less) {
+            return Self::new(-one, one);
+        }
+        let (a, b) = (f(self.lower), f(self.upper));
+        let bounds = Self::widen(a.min(b), a.max(b), ulps);
+        let mut lower = bounds.lower.max(-one);
+        let mut upper = bounds.upper.min(one);
+        if self.reaches(peak, tau) {
+            upper = one;
+        }
+        if self.reaches(trough, tau) {
+            lower = -one;
+        }
+        Self::new(lower, upper)
+    }
+}
+
+/// The product of bounds, taking zero times infinity as zero.
+fn product<T: Float>(a: T, b: T) -> T {
+    if a.is_zero() || b.is_zero() {
+        T::zero()
+    } else {
+        a * b
+    }
+}
+
+/// The smallest and largest of four bounds.
+fn extremes<T: Float>(values: [T; 4]) -> (T, T) {
+    values[1..]
+        .iter()
+        .fold((values[0], values[0]), |(lower, upper), &value| {
+            (lower.min(value), upper.max(value))
+        })
+}
+
+/// Solves the square system `matrix x = rhs`, with `matrix` given row-major like
+/// `MatrixNxN::as_slice`, by Gaussian elimination with partial pivoting.
+///
+/// The result encloses the solutions of every point system within the intervals. Returns `None`
+/// if a pivot may be zero, in which case the system may be singular. Panics if `matrix` does not
+/// have `rhs.len()` squared elements.
+#[cfg(feature = "alloc")]
+pub fn gaussian_elimination<T: Float>(
+    matrix: &[Interval<T>],
+    rhs: &[Interval<T>],
+) -> Option<Vec<Interval<T>>> {
+    match try_gaussian_elimination(matrix, rhs) {
+        Ok(x) => Some(x),
+        Err(crate::Error::SingularMatrix) => None,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+/// Like [`gaussian_elimination`], but returns `Error::ShapeMismatch` for a wrongly sized `matrix`
+/// and `Error::SingularMatrix` if a pivot may be zero.
+#[cfg(feature = "alloc")]
+pub fn try_gaussian_elimination<T: Float>(
+    matrix: &[Interval<T>],
+    rhs: &[Interval<T>],
+) -> Result<Vec<Interval<T>>, crate::Error> {
+    let n = rhs.len();
+    check_shape(n * n, matrix.len())?;
+    let (mut a, mut b) = (matrix.to_vec(), rhs.to_vec());
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| {
+                let (x, y) = (a[i * n + k].mignitude(), a[j * n + k].mignitude());
+                x.partial_cmp(&y).unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        if a[pivot * n + k].contains(T::zero()) {
+            return Err(crate::Error::SingularMatrix);
+        }
+        for column in 0..n {
+            a.swap(k * n + column, pivot * n + column);
+        }
+        b.swap(k, pivot);
+        for i in k + 1..n {
+            let factor = a[i * n + k] / a[k * n + k];
+            for j in k + 1..n {
+                a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+            }
+            b[i] = b[i] - factor * b[k];
+        }
+    }
+    let mut x = vec![Interval::zero(); n];
+    for i in (0..n).rev() {
+        let sum = (i + 1..n).fold(b[i], |sum, j| sum - a[i * n + j] * x[j]);
+        x[i] = sum / a[i * n + i];
+    }
+    Ok(x)
+}
+
+/// Interval Newton's method for a root of `f` in `x`, with derivatives from dual numbers.
+///
+/// Returns a tight enclosure proven to contain exactly one root, or `None` if `x` contains no
+/// root or a unique root could not be verified, such as when the derivative may vanish.
+pub fn newton<T: Float>(
+    f: impl Fn(Dual<Interval<T>>) -> Dual<Interval<T>>,
+    x: Interval<T>,
+) -> Option<Interval<T>> {
+    let mut x = x;
+    let mut verified = false;
+    for _ in 0..64 {
+        let derivative = f(Dual::variable(x)).derivative;
+        if derivative.contains(T::zero()) {
+            return None;
+        }
+        let midpoint = Interval::point(x.midpoint());
+        let step = midpoint - f(Dual::constant(midpoint)).value / derivative;
+        // A Newton step mapping the interval into its interior proves a root exists.
+        verified |= x.interior_contains(step);
+        let next = step.intersection(x)?;
+        if next == x {
+            break;
+        }
+        x = next;
+    }
+    verified.then_some(x)
+}
+
+impl<T: Float> From<T> for Interval<T> {
+    fn from(value: T) -> Self {
+        Self::point(value)
+    }
+}
+
+impl<T: Float> Zero for Interval<T> {
+    fn zero() -> Self {
+        Self::point(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.lower.is_zero() && self.upper.is_zero()
+    }
+}
+
+impl<T: Float> One for Interval<T> {
+    fn one() -> Self {
+        Self::point(T::one())
+    }
+}
+
+impl<T: Float> Num for Interval<T> {}
+
+impl<T: Float> Conjugate for Interval<T> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Float> PartialOrd for Interval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.upper < other.lower {
+            Some(Ordering::Less)
+        } else if self.lower > other.upper {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Float> Signed for Interval<T> {
+    fn abs(&self) -> Self {
+        if self.lower >= T::zero() {
+            *self
+        } else if self.upper <= T::zero() {
+            -*self
+        } else {
+            Self::new(T::zero(), self.magnitude())
+        }
+    }
+    fn signum(&self) -> Self {
+        let sign = |x: T| {
+            if x.is_zero() {
+                T::zero()
+            } else if x > T::zero() {
+                T::one()
+            } else {
+                -T::one()
+            }
+        };
+        Self::new(sign(self.lower), sign(self.upper))
+    }
+}
+
+/// Functions panic if the interval lies entirely outside their domain, and are otherwise
+/// restricted to the part of the interval inside it.
+impl<T: Float> Real for Interval<T> {
+    fn pi() -> Self {
+        Self::outward(T::pi(), T::pi())
+    }
+    fn epsilon() -> Self {
+        Self::point(T::epsilon())
+    }
+    /// Encloses `value` even if `T` cannot represent it exactly.
+    fn from_f64(value: f64) -> Self {
+        let converted = T::from_f64(value);
+        if converted.to_f64() == value {
+            Self::point(converted)
+        } else {
+            Self::outward(converted, converted)
+        }
+    }
+    /// The midpoint.
+    fn to_f64(self) -> f64 {
+        self.midpoint().to_f64()
+    }
+    fn sqrt(self) -> Self {
+        self.try_sqrt().unwrap_or_else(|error| panic!("{}", error))
+    }
+    fn sin(self) -> Self {
+        let half_pi = T::pi() / (T::one() + T::one());
+        self.periodic(T::sin, half_pi, -half_pi, 2)
+    }
+    fn cos(self) -> Self {
+        self.periodic(T::cos, T::zero(), T::pi(), 2)
+    }
+    fn tan(self) -> Self {
+        let half_pi = T::pi() / (T::one() + T::one());
+        let width = (self.upper - self.lower).partial_cmp(&T::pi());
+        if width != Some(Ordering::Less) || self.reaches(half_pi, T::pi()) {
+            return Self::entire();
+        }
+        self.increasing(T::tan, 3)
+    }
+    fn asin(self) -> Self {
+        self.try_asin().unwrap_or_else(|error| panic!("{}", error))
+    }
+    fn acos(self) -> Self {
+        self.try_acos().unwrap_or_else(|error| panic!("{}", error))
+    }
+    fn atan(self) -> Self {
+        self.increasing(T::atan, 2)
+    }
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        let pi = Self::pi();
+        // The angle jumps across the negative x axis and is undefined at the origin.
+        if x.lower < T::zero() && y.contains(T::zero())
+            || x.contains(T::zero()) && y.contains(T::zero())
+        {
+            return Self::new(-pi.upper, pi.upper);
+        }
+        // Elsewhere each edge sweeps the angle monotonically, so the extremes are at corners.
+        let (lower, upper) = extremes([
+            y.lower.atan2(x.lower),
+            y.lower.atan2(x.upper),
+            y.upper.atan2(x.lower),
+            y.upper.atan2(x.upper),
+        ]);
+        Self::widen(lower, upper, 3)
+    }
+    fn sinh(self) -> Self {
+        self.increasing(T::sinh, 3)
+    }
+    fn cosh(self) -> Self {
+        if self.upper <= T::zero() {
+            self.decreasing(T::cosh, 3)
+        } else if self.lower >= T::zero() {
+            self.increasing(T::cosh, 3)
+        } else {
+            let upper = self.lower.cosh().max(self.upper.cosh());
+            Self::new(T::one(), Self::widen(upper, upper, 3).upper)
+        }
+    }
+    fn tanh(self) -> Self {
+        let interval = self.increasing(T::tanh, 3);
+        Self::new(interval.lower.max(-T::one()), interval.upper.min(T::one()))
+    }
+    fn exp(self) -> Self {
+        let interval = self.increasing(T::exp, 2);
+        Self::new(interval.lower.max(T::zero()), interval.upper)
+    }
+    fn ln(self) -> Self {
+        self.try_ln().unwrap_or_else(|error| panic!("{}", error))
+    }
+    /// Computed as `exp(n ln(self))`, so `self` must contain positive numbers.
+    fn powf(self, n: Self) -> Self {
+        (self.ln() * n).exp()
+    }
+    fn floor(self) -> Self {
+        Self::new(self.lower.floor(), self.upper.floor())
+    }
+    fn ceil(self) -> Self {
+        Self::new(self.lower.ceil(), self.upper.ceil())
+    }
+    fn round(self) -> Self {
+        Self::new(self.lower.round(), self.upper.round())
+    }
+    fn min(self, other: Self) -> Self {
+        Self::new(self.lower.min(other.lower), self.upper.min(other.upper))
+    }
+    fn max(self, other: Self) -> Self {
+        Self::new(self.lower.max(other.lower), self.upper.max(other.upper))
+    }
+}
+
+impl<T: Float> Add for Interval<T> {
+    type Output = Interval<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::outward(self.lower + rhs.lower, self.upper + rhs.upper)
+    }
+}
+
+impl<T: Float> Sub for Interval<T> {
+    type Output = Interval<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::outward(self.lower - rhs.upper, self.upper - rhs.lower)
+    }
+}
+
+impl<T: Float> Mul for Interval<T> {
+    type Output = Interval<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (lower, upper) = extremes([
+            product(self.lower, rhs.lower),
+            product(self.lower, rhs.upper),
+            product(self.upper, rhs.lower),
+            product(self.upper, rhs.upper),
+        ]);
+        Self::outward(lower, upper)
+    }
+}
+
+impl<T: Float> Div for Interval<T> {
+    type Output = Interval<T>;
+
+    /// Division by an interval containing zero gives the whole real line.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(T::zero()) {
+            return Self::entire();
+        }
+        let (lower, upper) = extremes([
+            self.lower / rhs.lower,
+            self.lower / rhs.upper,
+            self.upper / rhs.lower,
+            self.upper / rhs.upper,
+        ]);
+        Self::outward(lower, upper)
+    }
+}
+
+impl<T: Float> Neg for Interval<T> {
+    type Output = Interval<T>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.upper, -self.lower)
+    }
+}
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Float> $op<T> for Interval<T> {
+                    type Output = Interval<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        self $operator Interval::point(rhs)
+                    }
+                }
+            )*
+        }
+    }
+}
+
+op_impl!(Add +, Sub -, Mul *, Div /);
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        match fmt.precision() {
+            Some(precision) => write!(
+                fmt,
+                "[{:.*}, {:.*}]",
+                precision, self.lower, precision, self.upper
+            ),
+            None => write!(fmt, "[{}, {}]", self.lower, self.upper),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+    use crate::linear_algebra::vector::vector_2::Vector2;
+
+    use core::f64::consts::PI;
+
+    fn interval(lower: f64, upper: f64) -> Interval<f64> {
+        Interval::new(lower, upper)
+    }
+
+    #[test]
+    fn arithmetic() {
+        let sum = Interval::point(0.1) + Interval::point(0.2);
+        assert!(sum.contains(0.3) && sum.contains(0.1 + 0.2));
+        assert!(sum.width() > 0.0);
+
+        let (a, b) = (interval(-1.0, 2.0), interval(3.0, 4.0));
+        assert!((a + b).contains_interval(interval(2.0, 6.0)));
+        assert!((a - b).contains_interval(interval(-5.0, -1.0)));
+        assert!((a * b).contains_interval(interval(-4.0, 8.0)));
+        assert!((a * b).width() < 12.0 + 1e-12);
+        assert!((b / b).contains_interval(interval(0.75, 4.0 / 3.0)));
+        assert_eq!(b / a, Interval::entire());
+        assert_eq!(-a, interval(-2.0, 1.0));
+        assert_eq!(a.abs(), interval(0.0, 2.0));
+        assert_eq!((a.magnitude(), a.mignitude()), (2.0, 0.0));
+        assert_eq!(
+            Interval::entire() * Interval::zero(),
+            Interval::outward(0.0, 0.0)
+        );
+
+        assert_eq!(a.intersection(b), None);
+        assert_eq!(a.hull(b), interval(-1.0, 4.0));
+        assert!(a < b && a.partial_cmp(&interval(0.0, 5.0)).is_none());
+        assert_eq!(format!("{:.1}", a), "[-1.0, 2.0]");
+    }
+
+    #[test]
+    fn functions() {
+        let x = interval(0.5, 2.0);
+        for (enclosure, f) in [
+            (x.sqrt(), f64::sqrt as fn(f64) -> f64),
+            (x.exp(), f64::exp),
+            (x.ln(), f64::ln),
+            (x.atan(), f64::atan),
+            (x.tanh(), f64::tanh),
+        ] {
+            assert!(enclosure.contains(f(0.5)) && enclosure.contains(f(2.0)));
+            assert!(enclosure.width() < (f(2.0) - f(0.5)) + 1e-12);
+        }
+        assert_eq!(interval(0.0, PI).sin().upper(), 1.0);
+        assert_eq!(interval(-0.1, 0.1).cos().upper(), 1.0);
+        assert!(interval(-0.1, 0.1).cos().lower() < 0.1f64.cos());
+        assert_eq!(interval(3.0, 3.5).cos().lower(), -1.0);
+        assert_eq!(interval(0.0, 10.0).sin(), interval(-1.0, 1.0));
+        let sin = interval(0.1, 0.2).sin();
+        assert!(sin.contains(0.1f64.sin()) && sin.contains(0.2f64.sin()) && sin.upper() < 0.2);
+        assert_eq!(interval(1.0, 2.0).tan(), Interval::entire());
+        assert!(interval(-1.0, 1.0)
+            .cosh()
+            .contains_interval(interval(1.0, 1.0f64.cosh())));
+        assert_eq!(interval(-4.0, 4.0).sqrt().lower(), 0.0);
+        assert!(interval(-2.0, -1.0).try_sqrt().is_err());
+        assert!(interval(-1.0, 0.0).try_ln().is_err());
+        assert!(interval(1.5, 2.0).try_asin().is_err() && interval(-3.0, -2.0).try_acos().is_err());
+        assert_eq!(interval(0.5, 2.0).try_acos().unwrap().lower(), 0.0);
+
+        let angle = Interval::point(1.0).atan2(interval(-1.0, 1.0));
+        assert!(angle.contains(PI / 4.0) && angle.contains(3.0 * PI / 4.0));
+        assert!(Interval::<f64>::pi().contains(PI));
+        assert!(Interval::<f32>::from_f64(0.1).contains(0.1f32));
+
+        let (e, tanh) = (Real::exp(1.0f64), Real::tanh(1.0f64));
+        assert_eq!(Interval::point(1.0).exp(), Interval::widen(e, e, 2));
+        assert_eq!(Interval::point(1.0).tanh(), Interval::widen(tanh, tanh, 3));
+    }
+
+    #[test]
+    fn signs_and_midpoints() {
+        for (x, sign) in [
+            (interval(0.0, 0.0), interval(0.0, 0.0)),
+            (interval(-0.0, 0.0), interval(0.0, 0.0)),
+            (interval(-1.0, 0.0), interval(-1.0, 0.0)),
+            (interval(-0.0, 2.0), interval(0.0, 1.0)),
+            (interval(0.0, 5.0), interval(0.0, 1.0)),
+            (interval(-2.0, 3.0), interval(-1.0, 1.0)),
+            (interval(-3.0, -2.0), interval(-1.0, -1.0)),
+        ] {
+            assert_eq!(x.signum(), sign);
+        }
+
+        assert_eq!(interval(1.0, 3.0).midpoint(), 2.0);
+        assert_eq!(Interval::<f64>::entire().midpoint(), 0.0);
+        assert_eq!(interval(f64::NEG_INFINITY, 1.0).midpoint(), f64::MIN);
+        assert_eq!(interval(-1.0, f64::INFINITY).midpoint(), f64::MAX);
+        assert_eq!(interval(f64::NEG_INFINITY, -f64::MAX).midpoint(), -f64::MAX);
+        assert_eq!(Interval::<f64>::entire().to_f64(), 0.0);
+    }
+
+    #[test]
+    fn linear_algebra() {
+        let v = Vector2::new(Interval::point(3.0), interval(4.0, 4.0));
+        assert!(v.length().contains(5.0));
+        let matrix = Matrix2x2::from_rows([
+            [interval(1.9, 2.1), Interval::point(1.0)],
+            [Interval::point(1.0), Interval::point(1.0)],
+        ]);
+        assert!(matrix.determinant().contains_interval(interval(0.9, 1.1)));
+
+        #[cfg(feature = "alloc")]
+        {
+            // A point system with solution (1, 2, 3).
+            let a = [2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0].map(Interval::point);
+            let b = [1.0, 1.0, 6.0].map(Interval::point);
+            let x = gaussian_elimination(&a, &b).unwrap();
+            for (x, expected) in x.iter().zip([1.0, 2.0, 3.0]) {
+                assert!(x.contains(expected) && x.width() < 1e-12);
+            }
+            // Uncertain coefficients widen the enclosure, which still holds the point solutions.
+            let mut uncertain = a;
+            uncertain[0] = interval(1.99, 2.01);
+            let x = gaussian_elimination(&uncertain, &b).unwrap();
+            assert!(x[0].contains(1.0) && x[0].width() > 1e-4);
+            let singular = [1.0, 2.0, 2.0, 4.0].map(Interval::point);
+            assert_eq!(gaussian_elimination(&singular, &[Interval::one(); 2]), None);
+            assert!(matches!(
+                try_gaussian_elimination(&singular, &[Interval::one(); 2]),
+                Err(crate::Error::SingularMatrix)
+            ));
+            assert!(matches!(
+                try_gaussian_elimination(&singular, &[Interval::one(); 3]),
+                Err(crate::Error::ShapeMismatch {
+                    expected: 9,
+                    found: 4
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn verified_roots() {
+        let root = newton(
+            |x| x * x - Dual::constant(Interval::point(2.0)),
+            interval(1.0, 2.0),
+        )
+        .unwrap();
+        assert!(root.contains(2.0f64.sqrt()));
+        assert!(root.width() < 1e-15);
+        let root = newton(|x| x.cos() - x, interval(0.0, 1.0)).unwrap();
+        assert!(root.contains(0.7390851332151607) && root.width() < 1e-15);
+        assert_eq!(
+            newton(
+                |x| x * x - Dual::constant(Interval::point(2.0)),
+                interval(2.0, 3.0)
+            ),
+            None
+        );
+        assert_eq!(
+            newton(
+                |x| x * x - Dual::constant(Interval::point(2.0)),
+                interval(-2.0, 2.0)
+            ),
+            None
+        );
+    }
+}
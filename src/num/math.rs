@@ -0,0 +1,918 @@
+//! Software implementations of the `f64` functions that `core` lacks, used by [`Real`] for `f32`
+//! and `f64` when the `std` feature is disabled.
+//!
+//! Most functions follow fdlibm as adapted by musl. `sqrt`, `floor`, `ceil` and `round` are
+//! correctly rounded. `exp`, `ln`, `sin`, `cos`, `asin`, `acos` and `atan` are within two units in
+//! the last place, and `tan`, `sinh`, `cosh`, `tanh` and `atan2` within three, the bounds that
+//! [`Interval`] widens by. The tests check these against the platform's functions, allowing one
+//! unit for the platform's own error. Trigonometric arguments of any size are reduced exactly
+//! against 1280 bits of 2/π. `powf` computes integer and half powers directly and other powers as
+//! `exp(n ln(x))`, so it loses precision when the result is very large or very small.
+//!
+//! [`Real`]: super::Real
+//! [`Interval`]: super::interval::Interval
+
+#![cfg_attr(feature = "std", allow(dead_code))]
+#![allow(clippy::excessive_precision)]
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LOG2_E, PI};
+
+/// The bits of 2/π after the binary point, most significant first.
+const TWO_OVER_PI: [u64; 20] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+];
+
+const PIO2_HI: f64 = FRAC_PI_2;
+const PIO2_LO: f64 = 6.12323399573676603587e-17;
+const PI_LO: f64 = 1.2246467991473531772e-16;
+const LN2_HI: f64 = 6.93147180369123816490e-01;
+const LN2_LO: f64 = 1.90821492927058770002e-10;
+
+/// 2^52, above which every `f64` is an integer.
+const INTEGRAL: f64 = 4503599627370496.0;
+
+fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+fn clear_low_word(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & 0xffffffff00000000)
+}
+
+/// `x * 2^n` without intermediate overflow or underflow.
+fn scalbn(mut x: f64, mut n: i32) -> f64 {
+    let big = f64::from_bits(0x7fe0000000000000);
+    let small = f64::from_bits(0x0010000000000000) * f64::from_bits(0x4340000000000000);
+    if n > 1023 {
+        x *= big;
+        n -= 1023;
+        if n > 1023 {
+            x *= big;
+            n = (n - 1023).min(1023);
+        }
+    } else if n < -1022 {
+        x *= small;
+        n += 1022 - 53;
+        if n < -1022 {
+            x *= small;
+            n = (n + 1022 - 53).max(-1022);
+        }
+    }
+    x * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// `a * b` as a rounded product and its exact rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let split = |x: f64| {
+        let c = 134217729.0 * x;
+        let high = c - (c - x);
+        (high, x - high)
+    };
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = a_low * b_low - (((product - a_high * b_high) - a_low * b_high) - a_high * b_low);
+    (product, error)
+}
+
+pub(crate) fn trunc(x: f64) -> f64 {
+    if x.abs() >= INTEGRAL || x.is_nan() {
+        return x;
+    }
+    (x as i64 as f64).copysign(x)
+}
+
+pub(crate) fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+pub(crate) fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if t < x {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+/// Rounds half-way cases away from zero.
+pub(crate) fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    if (x - t).abs() >= 0.5 {
+        t + 1.0f64.copysign(x)
+    } else {
+        t
+    }
+}
+
+/// The correctly rounded square root, from the integer square root of the scaled significand.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    if x < 0.0 {
+        return f64::NAN;
+    }
+    let bits = x.to_bits();
+    let mut exponent = (bits >> 52) as i32 - 1075;
+    let mut significand = bits & ((1 << 52) - 1);
+    if exponent == -1075 {
+        exponent += 1;
+        while significand & (1 << 52) == 0 {
+            significand <<= 1;
+            exponent -= 1;
+        }
+    } else {
+        significand |= 1 << 52;
+    }
+    if exponent % 2 != 0 {
+        significand <<= 1;
+        exponent -= 1;
+    }
+    // The significand is now in [2^52, 2^54), so the root of it times 2^52 is in [2^52, 2^53).
+    let n = (significand as u128) << 52;
+    let mut remainder = n;
+    let mut root = 0u128;
+    let mut bit = 1u128 << 106;
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    // The root is never exactly half-way, and lies above `root + 1/2` when the remainder
+    // exceeds `root`.
+    if remainder > root {
+        root += 1;
+    }
+    scalbn(root as f64, exponent / 2 - 26)
+}
+
+/// 64 bits of 2/π starting `position` bits after the binary point, counting from one.
+fn two_over_pi_bits(position: i32) -> u64 {
+    let word = |index: i32| {
+        if (0..TWO_OVER_PI.len() as i32).contains(&index) {
+            TWO_OVER_PI[index as usize]
+        } else {
+            0
+        }
+    };
+    let offset = position - 1;
+    if offset <= -64 {
+        0
+    } else if offset < 0 {
+        TWO_OVER_PI[0] >> -offset
+    } else {
+        let (index, shift) = (offset / 64, offset % 64);
+        if shift == 0 {
+            word(index)
+        } else {
+            word(index) << shift | word(index + 1) >> (64 - shift)
+        }
+    }
+}
+
+/// Reduces `x` to `y0 + y1` in [-π/4, π/4] with `x = y0 + y1 + n π/2`, returning `n mod 4`.
+///
+/// `x` is multiplied exactly by a 256 bit window of 2/π, wide enough that the fraction keeps
+/// full precision even for the doubles closest to multiples of π/2.
+fn rem_pio2(x: f64) -> (u32, f64, f64) {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let significand = (bits & ((1 << 52) - 1)) | 1 << 52;
+    // Bits of 2/π above position `exponent - 1` contribute multiples of 4 and are dropped.
+    let window: [u64; 4] = core::array::from_fn(|i| two_over_pi_bits(exponent - 1 + 64 * i as i32));
+    let mut product = [0u64; 4];
+    let mut carry = 0u128;
+    for (limb, &w) in product.iter_mut().zip(window.iter().rev()) {
+        let t = significand as u128 * w as u128 + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    // `product / 2^254` is `x 2/π mod 4`, with the integer part in the top two bits.
+    let mut n = (product[3] >> 62) as u32;
+    product[3] &= (1 << 62) - 1;
+    let negative = product[3] >> 61 == 1;
+    if negative {
+        n += 1;
+        let mut borrow = true;
+        for limb in product.iter_mut() {
+            *limb = !*limb;
+            if borrow {
+                (*limb, borrow) = limb.overflowing_add(1);
+            }
+        }
+        product[3] &= (1 << 62) - 1;
+    }
+    let leading = product
+        .iter()
+        .rev()
+        .position(|&limb| limb != 0)
+        .map_or(256, |i| i as u32 * 64 + product[3 - i].leading_zeros());
+    if leading == 256 {
+        return (n & 3, 0.0, 0.0);
+    }
+    let word = |i: u32| {
+        if i >= 256 {
+            return 0;
+        }
+        let (index, shift) = ((3 - i / 64) as usize, i % 64);
+        let high = product[index] << shift;
+        match (shift, index) {
+            (0, _) | (_, 0) => high,
+            _ => high | product[index - 1] >> (64 - shift),
+        }
+    };
+    let (top, next) = (word(leading), word(leading + 64));
+    let scale = -62 - leading as i32;
+    let high = scalbn((top & !0x7ff) as f64, scale);
+    let low = scalbn(
+        (top & 0x7ff) as f64 + next as f64 * f64::from_bits(0x3bf0000000000000),
+        scale,
+    );
+    let (fraction, error) = (high + low, low - ((high + low) - high));
+    let (product, product_error) = two_product(fraction, PIO2_HI);
+    let tail = product_error + (fraction * PIO2_LO + error * PIO2_HI);
+    let (mut y0, mut y1) = (product + tail, tail - ((product + tail) - product));
+    if negative {
+        (y0, y1) = (-y0, -y1);
+    }
+    if x < 0.0 {
+        (n.wrapping_neg() & 3, -y0, -y1)
+    } else {
+        (n & 3, y0, y1)
+    }
+}
+
+/// sin(x + y) on [-π/4, π/4], where `y` is the tail of `x`.
+fn kernel_sin(x: f64, y: f64) -> f64 {
+    const S1: f64 = -1.66666666666666324348e-01;
+    const S2: f64 = 8.33333333332248946124e-03;
+    const S3: f64 = -1.98412698298579493134e-04;
+    const S4: f64 = 2.75573137070700676789e-06;
+    const S5: f64 = -2.50507602534068634195e-08;
+    const S6: f64 = 1.58969099521155010221e-10;
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    x - ((z * (0.5 * y - v * r) - y) - v * S1)
+}
+
+/// cos(x + y) on [-π/4, π/4], where `y` is the tail of `x`.
+fn kernel_cos(x: f64, y: f64) -> f64 {
+    const C1: f64 = 4.16666666666666019037e-02;
+    const C2: f64 = -1.38888888888741095749e-03;
+    const C3: f64 = 2.48015872894767294178e-05;
+    const C4: f64 = -2.75573143513906633035e-07;
+    const C5: f64 = 2.08757232129817482790e-09;
+    const C6: f64 = -1.13596475577881948265e-11;
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let half = 0.5 * z;
+    let w = 1.0 - half;
+    w + (((1.0 - w) - half) + (z * r - x * y))
+}
+
+/// The quadrant and reduced argument of `x`, skipping the reduction inside [-π/4, π/4].
+fn reduce(x: f64) -> (u32, f64, f64) {
+    if x.abs() <= FRAC_PI_4 {
+        (0, x, 0.0)
+    } else {
+        rem_pio2(x)
+    }
+}
+
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+    if x.abs() < f64::from_bits(0x3e50000000000000) {
+        return (x, 1.0);
+    }
+    let (n, y0, y1) = reduce(x);
+    let (sin, cos) = (kernel_sin(y0, y1), kernel_cos(y0, y1));
+    match n {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+pub(crate) fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+pub(crate) fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+pub(crate) fn tan(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    if x.abs() < f64::from_bits(0x3e40000000000000) {
+        return x;
+    }
+    let (n, y0, y1) = reduce(x);
+    let (sin, cos) = (kernel_sin(y0, y1), kernel_cos(y0, y1));
+    if n % 2 == 0 {
+        sin / cos
+    } else {
+        -cos / sin
+    }
+}
+
+/// The rational approximation of `(asin(√z) / √z - 1) / z` shared by `asin` and `acos`.
+fn asin_rational(z: f64) -> f64 {
+    const P: [f64; 6] = [
+        1.66666666666666657415e-01,
+        -3.25565818622400915405e-01,
+        2.01212532134862925881e-01,
+        -4.00555345006794114027e-02,
+        7.91534994289814532176e-04,
+        3.47933107596021167570e-05,
+    ];
+    const Q: [f64; 4] = [
+        -2.40339491173441421878e+00,
+        2.02094576023350569471e+00,
+        -6.88283971605453293030e-01,
+        7.70381505559019352791e-02,
+    ];
+    let p = z * (P[0] + z * (P[1] + z * (P[2] + z * (P[3] + z * (P[4] + z * P[5])))));
+    let q = 1.0 + z * (Q[0] + z * (Q[1] + z * (Q[2] + z * Q[3])));
+    p / q
+}
+
+pub(crate) fn asin(x: f64) -> f64 {
+    let a = x.abs();
+    if a >= 1.0 || a.is_nan() {
+        return if a == 1.0 {
+            FRAC_PI_2.copysign(x)
+        } else {
+            f64::NAN
+        };
+    }
+    if a < 0.5 {
+        if a < f64::from_bits(0x3e50000000000000) {
+            return x;
+        }
+        return x + x * asin_rational(x * x);
+    }
+    let z = (1.0 - a) * 0.5;
+    let s = sqrt(z);
+    let r = asin_rational(z);
+    let result = if a >= 0.975 {
+        PIO2_HI - (2.0 * (s + s * r) - PIO2_LO)
+    } else {
+        let f = clear_low_word(s);
+        let c = (z - f * f) / (s + f);
+        0.5 * PIO2_HI - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * PIO2_HI - 2.0 * f))
+    };
+    result.copysign(x)
+}
+
+pub(crate) fn acos(x: f64) -> f64 {
+    let a = x.abs();
+    if a >= 1.0 || a.is_nan() {
+        return match x {
+            1.0 => 0.0,
+            -1.0 => PI,
+            _ => f64::NAN,
+        };
+    }
+    if a < 0.5 {
+        if a < f64::from_bits(0x3c60000000000000) {
+            return FRAC_PI_2;
+        }
+        return PIO2_HI - (x - (PIO2_LO - x * asin_rational(x * x)));
+    }
+    if x < 0.0 {
+        let z = (1.0 + x) * 0.5;
+        let s = sqrt(z);
+        let w = asin_rational(z) * s - PIO2_LO;
+        return 2.0 * (PIO2_HI - (s + w));
+    }
+    let z = (1.0 - x) * 0.5;
+    let s = sqrt(z);
+    let f = clear_low_word(s);
+    let c = (z - f * f) / (s + f);
+    let w = asin_rational(z) * s + c;
+    2.0 * (f + w)
+}
+
+pub(crate) fn atan(x: f64) -> f64 {
+    const ATAN_HI: [f64; 4] = [
+        4.63647609000806093515e-01,
+        FRAC_PI_4,
+        9.82793723247329054082e-01,
+        FRAC_PI_2,
+    ];
+    const ATAN_LO: [f64; 4] = [
+        2.26987774529616870924e-17,
+        3.06161699786838301793e-17,
+        1.39033110312309984516e-17,
+        6.12323399573676603587e-17,
+    ];
+    const T: [f64; 11] = [
+        3.33333333333329318027e-01,
+        -1.99999999998764832476e-01,
+        1.42857142725034663711e-01,
+        -1.11111104054623557880e-01,
+        9.09088713343650656196e-02,
+        -7.69187620504482999495e-02,
+        6.66107313738753120669e-02,
+        -5.83357013379057348645e-02,
+        4.97687799461593236017e-02,
+        -3.65315727442169155270e-02,
+        1.62858201153657823623e-02,
+    ];
+    let sign = x;
+    let high = high_word(x) & 0x7fffffff;
+    if high >= 0x44100000 {
+        return if x.is_nan() { x } else { FRAC_PI_2.copysign(x) };
+    }
+    let (id, x) = if high < 0x3fdc0000 {
+        if high < 0x3e400000 {
+            return x;
+        }
+        (None, x)
+    } else {
+        let a = x.abs();
+        if high < 0x3ff30000 {
+            if high < 0x3fe60000 {
+                (Some(0), (2.0 * a - 1.0) / (2.0 + a))
+            } else {
+                (Some(1), (a - 1.0) / (a + 1.0))
+            }
+        } else if high < 0x40038000 {
+            (Some(2), (a - 1.5) / (1.0 + 1.5 * a))
+        } else {
+            (Some(3), -1.0 / a)
+        }
+    };
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (T[0] + w * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * T[10])))));
+    let s2 = w * (T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * T[9]))));
+    match id {
+        None => x - x * (s1 + s2),
+        Some(id) => (ATAN_HI[id] - ((x * (s1 + s2) - ATAN_LO[id]) - x)).copysign(sign),
+    }
+}
+
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x == 1.0 {
+        return atan(y);
+    }
+    if y == 0.0 {
+        return if x.is_sign_negative() {
+            PI.copysign(y)
+        } else {
+            y
+        };
+    }
+    if x == 0.0 {
+        return FRAC_PI_2.copysign(y);
+    }
+    if x.is_infinite() {
+        let angle = match (y.is_infinite(), x > 0.0) {
+            (true, true) => FRAC_PI_4,
+            (true, false) => 3.0 * FRAC_PI_4,
+            (false, true) => 0.0,
+            (false, false) => PI,
+        };
+        return angle.copysign(y);
+    }
+    if y.is_infinite() {
+        return FRAC_PI_2.copysign(y);
+    }
+    let z = atan((y / x).abs());
+    if x > 0.0 {
+        z.copysign(y)
+    } else {
+        (PI - (z - PI_LO)).copysign(y)
+    }
+}
+
+pub(crate) fn exp(x: f64) -> f64 {
+    const P: [f64; 5] = [
+        1.66666666666666019037e-01,
+        -2.77777777770155933842e-03,
+        6.61375632143793436117e-05,
+        -1.65339022054652515390e-06,
+        4.13813679705723846039e-08,
+    ];
+    let high = high_word(x) & 0x7fffffff;
+    let negative = x < 0.0;
+    if high >= 0x4086232b {
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782712893383973096 {
+            return f64::INFINITY;
+        }
+        if x < -745.13321910194110842 {
+            return 0.0;
+        }
+    }
+    let (k, hi, lo) = if high > 0x3fd62e42 {
+        let k = if high >= 0x3ff0a2b2 {
+            (LOG2_E * x + if negative { -0.5 } else { 0.5 }) as i32
+        } else if negative {
+            -1
+        } else {
+            1
+        };
+        (k, x - k as f64 * LN2_HI, k as f64 * LN2_LO)
+    } else if high > 0x3e300000 {
+        (0, x, 0.0)
+    } else {
+        return 1.0 + x;
+    };
+    let x = hi - lo;
+    let xx = x * x;
+    let c = x - xx * (P[0] + xx * (P[1] + xx * (P[2] + xx * (P[3] + xx * P[4]))));
+    let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+    if k == 0 {
+        y
+    } else {
+        scalbn(y, k)
+    }
+}
+
+pub(crate) fn ln(x: f64) -> f64 {
+    const LG: [f64; 7] = [
+        6.666666666666735130e-01,
+        3.999999999940941908e-01,
+        2.857142874366239149e-01,
+        2.222219843214978396e-01,
+        1.818357216161805012e-01,
+        1.531383769920937332e-01,
+        1.479819860511658591e-01,
+    ];
+    let mut x = x;
+    let mut high = high_word(x);
+    let mut k = 0;
+    if high < 0x00100000 || high >> 31 != 0 {
+        if x == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if x < 0.0 || x.is_nan() {
+            return f64::NAN;
+        }
+        // Scales subnormal numbers up.
+        k -= 54;
+        x *= f64::from_bits(0x4350000000000000);
+        high = high_word(x);
+    } else if high >= 0x7ff00000 {
+        return x;
+    } else if x == 1.0 {
+        return 0.0;
+    }
+    // Reduces x into [√2/2, √2).
+    high += 0x3ff00000 - 0x3fe6a09e;
+    k += (high >> 20) as i32 - 0x3ff;
+    high = (high & 0x000fffff) + 0x3fe6a09e;
+    x = f64::from_bits((high as u64) << 32 | (x.to_bits() & 0xffffffff));
+    let f = x - 1.0;
+    let half_square = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG[1] + w * (LG[3] + w * LG[5]));
+    let t2 = z * (LG[0] + w * (LG[2] + w * (LG[4] + w * LG[6])));
+    let r = t2 + t1;
+    let k = k as f64;
+    s * (half_square + r) + k * LN2_LO - half_square + f + k * LN2_HI
+}
+
+fn powi(x: f64, n: i32) -> f64 {
+    let mut result = 1.0;
+    let mut base = x;
+    let mut exponent = n.unsigned_abs();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if y.is_infinite() {
+        return match x.abs() {
+            1.0 => 1.0,
+            a if (a < 1.0) == (y > 0.0) => 0.0,
+            _ => f64::INFINITY,
+        };
+    }
+    let integer = floor(y) == y;
+    if x < 0.0 && x.is_finite() && !integer {
+        return f64::NAN;
+    }
+    let odd = integer && y.abs() < 2.0 * INTEGRAL && floor(y * 0.5) != y * 0.5;
+    let a = x.abs();
+    let magnitude = if y == 0.5 {
+        sqrt(a)
+    } else if integer && y.abs() <= 64.0 {
+        powi(a, y as i32)
+    } else {
+        exp(y * ln(a))
+    };
+    if odd && x.is_sign_negative() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+pub(crate) fn sinh(x: f64) -> f64 {
+    let a = x.abs();
+    let result = if a < 1.0 {
+        if a < f64::from_bits(0x3e50000000000000) {
+            return x;
+        }
+        // The Taylor series, whose first omitted term is below 2^-56 relative to `x`.
+        let z = x * x;
+        let series = [272.0, 210.0, 156.0, 110.0, 72.0, 42.0, 20.0, 6.0]
+            .iter()
+            .fold(1.0, |sum, &d| 1.0 + z / d * sum);
+        return x * series;
+    } else if a < 709.0 {
+        let e = exp(a);
+        0.5 * (e - 1.0 / e)
+    } else {
+        let t = exp(0.5 * a);
+        0.5 * t * t
+    };
+    result.copysign(x)
+}
+
+pub(crate) fn cosh(x: f64) -> f64 {
+    let a = x.abs();
+    if a < 709.0 {
+        let e = exp(a);
+        0.5 * (e + 1.0 / e)
+    } else {
+        let t = exp(0.5 * a);
+        0.5 * t * t
+    }
+}
+
+pub(crate) fn tanh(x: f64) -> f64 {
+    let a = x.abs();
+    if x.is_nan() {
+        x
+    } else if a > 22.0 {
+        1.0f64.copysign(x)
+    } else if a < 0.55 {
+        // Lambert's continued fraction, whose omitted terms are negligible for small `x`.
+        let z = x * x;
+        // Dividing by `1 + w` as `x - x w / (1 + w)` keeps the rounding errors in the smaller term.
+        let w = z
+            / (2..13)
+                .rev()
+                .fold(25.0, |tail, k| (2 * k - 1) as f64 + z / tail);
+        x - x * w / (1.0 + w)
+    } else {
+        (1.0 - 2.0 / (exp(2.0 * a) + 1.0)).copysign(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The distance in units in the last place, treating both zeros and all NaNs as equal.
+    fn ulps(a: f64, b: f64) -> u64 {
+        if a.is_nan() && b.is_nan() || a == b {
+            return 0;
+        }
+        let key = |x: f64| {
+            let bits = x.to_bits() as i64;
+            if bits < 0 {
+                i64::MIN - bits
+            } else {
+                bits
+            }
+        };
+        key(a).abs_diff(key(b))
+    }
+
+    /// Uniformly distributed bit patterns, mapped through `f`.
+    fn samples(count: usize, f: impl Fn(u64) -> f64) -> impl Iterator<Item = f64> {
+        let mut state = 0x2545f4914f6cdd1du64;
+        (0..count).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            f(state)
+        })
+    }
+
+    fn uniform(low: f64, high: f64) -> impl Fn(u64) -> f64 {
+        move |bits| low + (high - low) * ((bits >> 11) as f64 / (1u64 << 53) as f64)
+    }
+
+    /// Random magnitudes over the whole exponent range with either sign.
+    fn any(bits: u64) -> f64 {
+        f64::from_bits(bits & !(0x7ff << 52) | (bits % 0x7ff) << 52)
+    }
+
+    fn check(
+        name: &str,
+        ours: impl Fn(f64) -> f64,
+        reference: impl Fn(f64) -> f64,
+        inputs: impl Iterator<Item = f64>,
+        tolerance: u64,
+    ) {
+        for x in inputs {
+            let (a, b) = (ours(x), reference(x));
+            assert!(
+                ulps(a, b) <= tolerance,
+                "{name}({x:e}) = {a:e}, expected {b:e}"
+            );
+        }
+    }
+
+    const SPECIAL: [f64; 12] = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.5,
+        -0.5,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        5e-324,
+    ];
+
+    #[test]
+    fn rounding() {
+        let inputs = || {
+            samples(20000, any)
+                .chain(samples(20000, uniform(-10.0, 10.0)))
+                .chain(SPECIAL)
+        };
+        check("floor", floor, f64::floor, inputs(), 0);
+        check("ceil", ceil, f64::ceil, inputs(), 0);
+        check("round", round, f64::round, inputs(), 0);
+        check("trunc", trunc, f64::trunc, inputs(), 0);
+        check("sqrt", sqrt, f64::sqrt, inputs(), 0);
+        check(
+            "sqrt",
+            sqrt,
+            f64::sqrt,
+            samples(20000, |b| f64::from_bits(b >> 12)),
+            0,
+        );
+        assert!(round(-0.4).is_sign_negative() && ceil(-0.5).is_sign_negative());
+    }
+
+    #[test]
+    fn exponential() {
+        let inputs = || {
+            samples(20000, uniform(-750.0, 750.0))
+                .chain(samples(20000, uniform(-1.0, 1.0)))
+                .chain(SPECIAL)
+        };
+        check("exp", exp, f64::exp, inputs(), 1);
+        check(
+            "ln",
+            ln,
+            f64::ln,
+            samples(40000, any).map(f64::abs).chain(SPECIAL),
+            1,
+        );
+        check("ln", ln, f64::ln, samples(20000, uniform(0.5, 2.0)), 1);
+        check("sinh", sinh, f64::sinh, inputs(), 2);
+        check("cosh", cosh, f64::cosh, inputs(), 2);
+        check("tanh", tanh, f64::tanh, inputs(), 2);
+        for (x, y) in
+            samples(20000, uniform(0.0, 100.0)).zip(samples(20000, uniform(-10.0, 10.0)).skip(7))
+        {
+            let (a, b) = (powf(x, y), x.powf(y));
+            assert!(
+                ulps(a, b) <= 64,
+                "powf({x:e}, {y:e}) = {a:e}, expected {b:e}"
+            );
+        }
+        for &x in &SPECIAL {
+            for &y in &[
+                0.0,
+                -0.0,
+                0.5,
+                1.0,
+                -1.0,
+                2.0,
+                3.0,
+                -3.0,
+                2.5,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NAN,
+            ] {
+                assert!(ulps(powf(x, y), x.powf(y)) <= 64, "powf({x:e}, {y:e})");
+            }
+        }
+        assert!(powf(-0.0, 3.0).is_sign_negative() && powf(-0.0, 2.0).is_sign_positive());
+        assert_eq!(powf(-0.0, -3.0), f64::NEG_INFINITY);
+        assert_eq!(powf(2.0, 10.0), 1024.0);
+        assert_eq!(powf(-2.0, 3.0), -8.0);
+        assert_eq!(powf(9.0, 0.5), 3.0);
+    }
+
+    #[test]
+    fn trigonometric() {
+        let inputs = || {
+            samples(20000, uniform(-10.0, 10.0))
+                .chain(samples(20000, any))
+                .chain(SPECIAL)
+                .chain([1e22, PI, FRAC_PI_2, 355.0])
+        };
+        // The double closest to a multiple of π/2, where reduction is hardest. The expected
+        // values are correctly rounded, from mpmath with `mp.prec = 3000`, as in
+        // `float(cos(mpf(6381956970095103) * 2**797))`.
+        let hardest = 6381956970095103.0 * 2f64.powi(797);
+        assert_eq!(cos(hardest), -4.687165924254628e-19);
+        assert_eq!(tan(hardest), -2.133485385753704e18);
+        assert_eq!(sin(hardest), 1.0);
+        check("sin", sin, f64::sin, inputs(), 1);
+        check("cos", cos, f64::cos, inputs(), 1);
+        check("tan", tan, f64::tan, inputs(), 2);
+        let unit = || {
+            samples(40000, uniform(-1.0, 1.0))
+                .chain(SPECIAL)
+                .chain([0.975, -0.975, 1e-300])
+        };
+        check("asin", asin, f64::asin, unit(), 1);
+        check("acos", acos, f64::acos, unit(), 1);
+        check(
+            "atan",
+            atan,
+            f64::atan,
+            samples(20000, any)
+                .chain(samples(20000, uniform(-3.0, 3.0)))
+                .chain(SPECIAL),
+            1,
+        );
+        for (y, x) in samples(20000, any)
+            .zip(samples(20000, uniform(-5.0, 5.0)).skip(3))
+            .chain(SPECIAL.iter().flat_map(|&y| SPECIAL.map(|x| (y, x))))
+        {
+            let (a, b) = (atan2(y, x), y.atan2(x));
+            assert!(
+                ulps(a, b) <= 2,
+                "atan2({y:e}, {x:e}) = {a:e}, expected {b:e}"
+            );
+        }
+    }
+}
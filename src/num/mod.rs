@@ -0,0 +1,478 @@
+#[cfg(feature = "alloc")]
+pub mod bigint;
+pub mod complex;
+pub mod dual;
+pub mod fixed;
+pub mod half;
+pub mod hyper_dual;
+pub mod interval;
+mod math;
+pub mod modular;
+pub mod rational;
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+/// A number usable as the element type of vectors and matrices.
+pub trait Num:
+    Sized
+    + Clone
+    + PartialEq
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+}
+
+/// Complex conjugation, which leaves real numbers unchanged.
+pub trait Conjugate: Sized {
+    fn conjugate(&self) -> Self;
+}
+
+pub trait Signed: Num + Neg<Output = Self> {
+    fn abs(&self) -> Self;
+    fn signum(&self) -> Self;
+}
+
+/// How row reduction chooses pivots. The defaults suit exact types: any non-zero element is a
+/// pivot and the first one is taken. `Real` types pivot on the largest element instead, and
+/// treat elements within rounding error of zero as zero.
+pub trait Pivot: Num {
+    /// The magnitude at or below which an element of the row-major `elements` with `columns`
+    /// columns counts as zero.
+    fn tolerance(_elements: &[Self], _columns: usize) -> Self {
+        Self::zero()
+    }
+    fn is_pivot(&self, _tolerance: &Self) -> bool {
+        !self.is_zero()
+    }
+    /// Whether `self` is preferred as a pivot over `other`.
+    fn is_better_pivot(&self, _other: &Self) -> bool {
+        false
+    }
+    /// Whether arithmetic is exact, so elimination may use fraction-free updates whose
+    /// intermediate values grow.
+    const EXACT: bool = true;
+}
+
+impl<T: Real> Pivot for T {
+    /// The largest magnitude in `elements` times the machine epsilon, scaled by the larger
+    /// dimension, as for the numerical rank.
+    fn tolerance(elements: &[Self], columns: usize) -> Self {
+        let largest = elements
+            .iter()
+            .fold(T::zero(), |largest, x| largest.max(x.abs()));
+        let dimension = columns.max(elements.len() / columns.max(1));
+        largest * T::epsilon() * T::from_f64(dimension as f64)
+    }
+    fn is_pivot(&self, tolerance: &Self) -> bool {
+        self.abs() > *tolerance
+    }
+    fn is_better_pivot(&self, other: &Self) -> bool {
+        self.abs() > other.abs()
+    }
+    const EXACT: bool = false;
+}
+
+macro_rules! num_impl {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+            impl Num for $t {}
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+num_impl!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+num_impl!(0.0, 1.0; f32, f64);
+
+macro_rules! signed_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                fn abs(&self) -> Self {
+                    <$t>::abs(*self)
+                }
+                fn signum(&self) -> Self {
+                    <$t>::signum(*self)
+                }
+            }
+        )*
+    };
+}
+
+signed_impl!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// An integer with a remainder, usable as the numerator and denominator of a `Rational`.
+pub trait Integer: Num + Ord + Rem<Output = Self> {
+    /// The non-negative greatest common divisor, which is zero only if both are zero.
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = a % b.clone();
+            a = b;
+            b = remainder;
+        }
+        if a < Self::zero() {
+            Self::zero() - a
+        } else {
+            a
+        }
+    }
+}
+
+/// Arithmetic that returns `None` instead of overflowing or dividing by zero.
+pub trait Checked: Sized {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+}
+
+/// Arithmetic that clamps to the representable range instead of overflowing. Division by zero
+/// still panics.
+pub trait Saturating: Sized {
+    fn saturating_add(&self, rhs: &Self) -> Self;
+    fn saturating_sub(&self, rhs: &Self) -> Self;
+    fn saturating_mul(&self, rhs: &Self) -> Self;
+    fn saturating_div(&self, rhs: &Self) -> Self;
+    /// The sum of the products of the pairs, computed exactly and clamped once at the end, so
+    /// partial sums that overflow can still be cancelled by later terms.
+    fn saturating_dot(pairs: impl IntoIterator<Item = (Self, Self)>) -> Self;
+}
+
+/// An exact sum of products of `i128`s, as the 256-bit two's complement `high 2^128 + low`.
+///
+/// Each product is below 2^254 in magnitude, so the sum is exact until it passes 2^255, beyond
+/// which it saturates.
+#[derive(Copy, Clone, Default)]
+pub(crate) struct WideSum {
+    high: i128,
+    low: u128,
+}
+
+impl WideSum {
+    pub(crate) fn add_product(&mut self, a: i128, b: i128) {
+        let (a_abs, b_abs) = (a.unsigned_abs(), b.unsigned_abs());
+        let (a1, a0) = (a_abs >> 64, a_abs & u64::MAX as u128);
+        let (b1, b0) = (b_abs >> 64, b_abs & u64::MAX as u128);
+        let (middle, carry) = (a1 * b0).overflowing_add(a0 * b1);
+        let (low, low_carry) = (a0 * b0).overflowing_add(middle << 64);
+        let high = a1 * b1 + ((carry as u128) << 64) + (middle >> 64) + low_carry as u128;
+        let (high, low) = if (a < 0) != (b < 0) {
+            ((!high).wrapping_add((low == 0) as u128), low.wrapping_neg())
+        } else {
+            (high, low)
+        };
+        let (low, carry) = self.low.overflowing_add(low);
+        self.low = low;
+        self.high = self
+            .high
+            .saturating_add(high as i128)
+            .saturating_add(carry as i128);
+    }
+    /// The sum shifted right by `shift` bits, rounding down, and clamped to `[min, max]`.
+    pub(crate) fn clamp(self, shift: u32, min: i128, max: i128) -> i128 {
+        let (high, low) = match shift {
+            0 => (self.high, self.low),
+            _ => (
+                self.high >> shift,
+                self.low >> shift | (self.high as u128) << (128 - shift),
+            ),
+        };
+        match high {
+            0 if low <= i128::MAX as u128 => (low as i128).clamp(min, max),
+            -1 if low > i128::MAX as u128 => (low as i128).clamp(min, max),
+            _ if high < 0 => min,
+            _ => max,
+        }
+    }
+}
+
+/// Arithmetic that wraps around the representable range instead of overflowing. Division by
+/// zero still panics.
+pub trait Wrapping: Sized {
+    fn wrapping_add(&self, rhs: &Self) -> Self;
+    fn wrapping_sub(&self, rhs: &Self) -> Self;
+    fn wrapping_mul(&self, rhs: &Self) -> Self;
+    fn wrapping_div(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! integer_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {}
+            impl Pivot for $t {}
+            impl Checked for $t {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *rhs)
+                }
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *rhs)
+                }
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *rhs)
+                }
+            }
+            impl Saturating for $t {
+                fn saturating_add(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_add(*self, *rhs)
+                }
+                fn saturating_sub(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_sub(*self, *rhs)
+                }
+                fn saturating_mul(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_mul(*self, *rhs)
+                }
+                fn saturating_div(&self, rhs: &Self) -> Self {
+                    <$t>::saturating_div(*self, *rhs)
+                }
+                fn saturating_dot(pairs: impl IntoIterator<Item = (Self, Self)>) -> Self {
+                    // Unsigned products only grow the sum, so any overflow saturates at `MAX`.
+                    if <$t>::MIN == 0 {
+                        return pairs
+                            .into_iter()
+                            .try_fold(0u128, |sum, (a, b)| {
+                                sum.checked_add((a as u128).checked_mul(b as u128)?)
+                            })
+                            .map_or(<$t>::MAX, |sum| sum.min(<$t>::MAX as u128) as $t);
+                    }
+                    let mut sum = WideSum::default();
+                    for (a, b) in pairs {
+                        sum.add_product(a as i128, b as i128);
+                    }
+                    sum.clamp(0, <$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+            }
+            impl Wrapping for $t {
+                fn wrapping_add(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_add(*self, *rhs)
+                }
+                fn wrapping_sub(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_sub(*self, *rhs)
+                }
+                fn wrapping_mul(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_mul(*self, *rhs)
+                }
+                fn wrapping_div(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_div(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+integer_impl!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A real number with the elementary functions needed for geometry.
+pub trait Real: Signed + Copy + PartialOrd {
+    fn pi() -> Self;
+    /// The difference between `1` and the next larger representable number.
+    fn epsilon() -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        macro_rules! float_math {
+            ($t:ident, $f:ident, $($argument:expr),*) => {
+                $t::$f($($argument),*)
+            };
+        }
+    } else {
+        // Without `std` the inherent float methods are missing, so `f32` goes through the `f64`
+        // implementations in `math`.
+        macro_rules! float_math {
+            ($t:ident, $f:ident, $($argument:expr),*) => {
+                math::$f($($argument as f64),*) as $t
+            };
+        }
+    }
+}
+
+macro_rules! real_impl {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                fn pi() -> Self {
+                    ::core::$t::consts::PI
+                }
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn sqrt(self) -> Self {
+                    float_math!($t, sqrt, self)
+                }
+                fn sin(self) -> Self {
+                    float_math!($t, sin, self)
+                }
+                fn cos(self) -> Self {
+                    float_math!($t, cos, self)
+                }
+                fn tan(self) -> Self {
+                    float_math!($t, tan, self)
+                }
+                fn asin(self) -> Self {
+                    float_math!($t, asin, self)
+                }
+                fn acos(self) -> Self {
+                    float_math!($t, acos, self)
+                }
+                fn atan(self) -> Self {
+                    float_math!($t, atan, self)
+                }
+                fn atan2(self, other: Self) -> Self {
+                    float_math!($t, atan2, self, other)
+                }
+                fn sinh(self) -> Self {
+                    float_math!($t, sinh, self)
+                }
+                fn cosh(self) -> Self {
+                    float_math!($t, cosh, self)
+                }
+                fn tanh(self) -> Self {
+                    float_math!($t, tanh, self)
+                }
+                fn exp(self) -> Self {
+                    float_math!($t, exp, self)
+                }
+                fn ln(self) -> Self {
+                    float_math!($t, ln, self)
+                }
+                fn powf(self, n: Self) -> Self {
+                    float_math!($t, powf, self, n)
+                }
+                fn floor(self) -> Self {
+                    float_math!($t, floor, self)
+                }
+                fn ceil(self) -> Self {
+                    float_math!($t, ceil, self)
+                }
+                fn round(self) -> Self {
+                    float_math!($t, round, self)
+                }
+                fn sin_cos(self) -> (Self, Self) {
+                    cfg_if::cfg_if! {
+                        if #[cfg(feature = "std")] {
+                            $t::sin_cos(self)
+                        } else {
+                            let (sin, cos) = math::sin_cos(self as f64);
+                            (sin as $t, cos as $t)
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+real_impl!(f32, f64);
+
+/// A binary floating-point number, with infinities and access to neighbouring values.
+pub trait Float: Real {
+    fn infinity() -> Self;
+    fn is_nan(self) -> bool;
+    /// The least number greater than `self`.
+    fn next_up(self) -> Self;
+    /// The greatest number less than `self`.
+    fn next_down(self) -> Self;
+}
+
+macro_rules! float_impl {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                fn infinity() -> Self {
+                    $t::INFINITY
+                }
+                fn is_nan(self) -> bool {
+                    $t::is_nan(self)
+                }
+                fn next_up(self) -> Self {
+                    $t::next_up(self)
+                }
+                fn next_down(self) -> Self {
+                    $t::next_down(self)
+                }
+            }
+        )*
+    };
+}
+
+float_impl!(f32, f64);
@@ -0,0 +1,545 @@
+//! Arithmetic modulo a fixed integer, and exact linear algebra over prime fields.
+
+use super::{Checked, Conjugate, Num, One, Pivot, Zero};
+use crate::linear_algebra::matrix::matrix_2x2::Matrix2x2;
+use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::matrix::matrix_nxn::MatrixNxN;
+use crate::linear_algebra::matrix::row_reduce;
+use crate::linear_algebra::vector::vector_2::Vector2;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::vector_4::Vector4;
+#[cfg(feature = "alloc")]
+use crate::linear_algebra::vector::vector_n::VectorN;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt::{Debug, Display, Error, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An integer modulo `P`, which must be at least 2 and below 2^63.
+///
+/// Odd moduli are stored in Montgomery form, so multiplication needs no division. When `P` is
+/// prime this is the finite field GF(P), and matrices of `ModInt` support exact elimination.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ModInt<const P: u64> {
+    value: u64,
+}
+
+impl<const P: u64> ModInt<P> {
+    const VALID: () = assert!(P >= 2 && P < 1 << 63, "Modulus must be in [2, 2^63)");
+    /// Referenced by the field operations, so they fail to compile for composite moduli.
+    const PRIME: () = assert!(is_prime(P), "Modulus must be prime");
+    const MONTGOMERY: bool = P % 2 == 1;
+    /// `-P^-1 mod 2^64`, by Newton's iteration which doubles the correct bits each step.
+    const NEGATED_INVERSE: u64 = {
+        let mut inverse = P;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(P.wrapping_mul(inverse)));
+            i += 1;
+        }
+        inverse.wrapping_neg()
+    };
+    /// `2^128 mod P`, which converts into Montgomery form.
+    const R_SQUARED: u64 = ((u128::MAX % P as u128 + 1) % P as u128) as u64;
+
+    pub const fn modulus() -> u64 {
+        P
+    }
+    /// Reduces `value` modulo `P`.
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        let value = value % P;
+        Self {
+            value: if Self::MONTGOMERY {
+                Self::reduce(value as u128 * Self::R_SQUARED as u128)
+            } else {
+                value
+            },
+        }
+    }
+    /// The representative in `[0, P)`.
+    pub fn value(self) -> u64 {
+        if Self::MONTGOMERY {
+            Self::reduce(self.value as u128)
+        } else {
+            self.value
+        }
+    }
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+    /// The multiplicative inverse by the extended Euclidean algorithm, or `None` if `self`
+    /// shares a factor with `P`.
+    pub fn inverse(self) -> Option<Self> {
+        let (mut a, mut b) = (self.value() as i128, P as i128);
+        let (mut x, mut y) = (1i128, 0i128);
+        while b != 0 {
+            let quotient = a / b;
+            (a, b) = (b, a - quotient * b);
+            (x, y) = (y, x - quotient * y);
+        }
+        (a == 1).then(|| Self::new(x.rem_euclid(P as i128) as u64))
+    }
+
+    /// Montgomery reduction, `value / 2^64 mod P` for `value < P 2^64`.
+    fn reduce(value: u128) -> u64 {
+        let m = (value as u64).wrapping_mul(Self::NEGATED_INVERSE);
+        let reduced = ((value + m as u128 * P as u128) >> 64) as u64;
+        if reduced >= P {
+            reduced - P
+        } else {
+            reduced
+        }
+    }
+}
+
+macro_rules! from_impl {
+    ($($t:ty),*) => {
+        $(
+            impl<const P: u64> From<$t> for ModInt<P> {
+                fn from(value: $t) -> Self {
+                    Self::new((value as i128).rem_euclid(P as i128) as u64)
+                }
+            }
+        )*
+    };
+}
+
+from_impl!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<const P: u64> Zero for ModInt<P> {
+    fn zero() -> Self {
+        Self::default()
+    }
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64> One for ModInt<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Num for ModInt<P> {}
+
+/// Pivots on the first non-zero element, since every one is invertible when `P` is prime.
+/// Elimination fails to compile for composite `P`.
+impl<const P: u64> Pivot for ModInt<P> {
+    fn is_pivot(&self, _tolerance: &Self) -> bool {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PRIME;
+        !self.is_zero()
+    }
+}
+
+impl<const P: u64> Conjugate for ModInt<P> {
+    fn conjugate(&self) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> Checked for ModInt<P> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(*self + *rhs)
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(*self - *rhs)
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(*self * *rhs)
+    }
+    /// Returns `None` if `rhs` is not invertible.
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        rhs.inverse().map(|inverse| *self * inverse)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = ModInt<P>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let sum = self.value + rhs.value;
+        Self {
+            value: if sum >= P { sum - P } else { sum },
+        }
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = ModInt<P>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            value: if self.value >= rhs.value {
+                self.value - rhs.value
+            } else {
+                self.value + P - rhs.value
+            },
+        }
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = ModInt<P>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = self.value as u128 * rhs.value as u128;
+        Self {
+            value: if Self::MONTGOMERY {
+                Self::reduce(product)
+            } else {
+                (product % P as u128) as u64
+            },
+        }
+    }
+}
+
+impl<const P: u64> Div for ModInt<P> {
+    type Output = ModInt<P>;
+
+    /// Panics if `rhs` is not invertible.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(&rhs)
+            .expect("Division by a non-invertible element")
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = ModInt<P>;
+
+    fn neg(self) -> Self::Output {
+        Self::zero() - self
+    }
+}
+
+impl<const P: u64> Display for ModInt<P> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        Display::fmt(&self.value(), fmt)
+    }
+}
+
+impl<const P: u64> Debug for ModInt<P> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(fmt, "{} (mod {})", self.value(), P)
+    }
+}
+
+/// Deterministic Miller-Rabin, exact for every `n` below 2^64 with these bases.
+const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    const fn multiply(a: u64, b: u64, n: u64) -> u64 {
+        (a as u128 * b as u128 % n as u128) as u64
+    }
+    const fn pow(mut base: u64, mut exponent: u64, n: u64) -> u64 {
+        let mut result = 1;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = multiply(result, base, n);
+            }
+            base = multiply(base, base, n);
+            exponent >>= 1;
+        }
+        result
+    }
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow(BASES[i], odd, n);
+        let mut round = 1;
+        while x != 1 && x != n - 1 && round < shift {
+            x = multiply(x, x, n);
+            round += 1;
+        }
+        if x != 1 && x != n - 1 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Reduces `elements` to reduced row echelon form, writing the pivot columns to `pivots` and
+/// returning the rank.
+fn reduce<const P: u64>(elements: &mut [ModInt<P>], columns: usize, pivots: &mut [usize]) -> usize {
+    let mut rank = 0;
+    row_reduce(elements, columns, |column| {
+        pivots[rank] = column;
+        rank += 1;
+    })
+}
+
+/// Writes the square matrix `elements` extended on the right by the columns of `extra` to
+/// `augmented`.
+fn augment<const P: u64>(
+    elements: &[ModInt<P>],
+    size: usize,
+    extra: usize,
+    augmented: &mut [ModInt<P>],
+    mut f: impl FnMut(usize, usize) -> ModInt<P>,
+) {
+    for (i, element) in augmented.iter_mut().enumerate() {
+        let (row, column) = (i / (size + extra), i % (size + extra));
+        *element = match column < size {
+            true => elements[row * size + column],
+            false => f(row, column - size),
+        };
+    }
+}
+
+/// A basis of the solutions of `A x = 0`, one free variable set to one per basis vector.
+#[cfg(feature = "alloc")]
+fn nullspace<const P: u64>(elements: &[ModInt<P>], size: usize) -> Vec<Vec<ModInt<P>>> {
+    let mut reduced = elements.to_vec();
+    let mut pivots = vec![0; size];
+    let rank = reduce(&mut reduced, size, &mut pivots);
+    let pivots = &pivots[..rank];
+    (0..size)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![ModInt::zero(); size];
+            vector[free] = ModInt::one();
+            for (row, &pivot) in pivots.iter().enumerate() {
+                vector[pivot] = -reduced[row * size + free];
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Writes one solution of `A x = b`, with every free variable zero, to `solution` given
+/// `[A | b]`. Returns `false` if there is none.
+fn solve<const P: u64>(
+    augmented: &mut [ModInt<P>],
+    pivots: &mut [usize],
+    solution: &mut [ModInt<P>],
+) -> bool {
+    let size = solution.len();
+    let rank = reduce(augmented, size + 1, pivots);
+    if rank > 0 && pivots[rank - 1] == size {
+        return false;
+    }
+    solution.fill(ModInt::zero());
+    for (row, &pivot) in pivots[..rank].iter().enumerate() {
+        solution[pivot] = augmented[row * (size + 1) + size];
+    }
+    true
+}
+
+macro_rules! field_impl {
+    ($($matrix:ident $vector:ident $n:literal),*) => {
+        $(
+            /// Exact elimination over GF(P), which fails to compile unless `P` is prime.
+            impl<const P: u64> $matrix<ModInt<P>> {
+                /// A basis of the vectors `x` with `A x = 0`.
+                #[cfg(feature = "alloc")]
+                pub fn nullspace(&self) -> Vec<$vector<ModInt<P>>> {
+                    nullspace(self.as_slice(), $n)
+                        .into_iter()
+                        .map(|vector| $vector::from_array(vector.try_into().unwrap()))
+                        .collect()
+                }
+                /// A solution of `A x = b`, or `None` if the system is inconsistent.
+                pub fn solve(&self, rhs: $vector<ModInt<P>>) -> Option<$vector<ModInt<P>>> {
+                    let mut augmented = [ModInt::zero(); $n * ($n + 1)];
+                    augment(self.as_slice(), $n, 1, &mut augmented, |row, _| rhs.storage[row]);
+                    let mut solution = [ModInt::zero(); $n];
+                    solve(&mut augmented, &mut [0; $n], &mut solution)
+                        .then(|| $vector::from_array(solution))
+                }
+            }
+        )*
+    };
+}
+
+field_impl!(Matrix2x2 Vector2 2, Matrix3x3 Vector3 3, Matrix4x4 Vector4 4);
+
+/// Exact elimination over GF(P), which fails to compile unless `P` is prime.
+#[cfg(feature = "alloc")]
+impl<const P: u64> MatrixNxN<ModInt<P>> {
+    /// A basis of the vectors `x` with `A x = 0`.
+    pub fn nullspace(&self) -> Vec<VectorN<ModInt<P>>> {
+        nullspace(self.as_slice(), self.size())
+            .into_iter()
+            .map(VectorN::from_vec)
+            .collect()
+    }
+    /// A solution of `A x = b`, or `None` if the system is inconsistent.
+    pub fn solve(&self, rhs: &VectorN<ModInt<P>>) -> Option<VectorN<ModInt<P>>> {
+        let size = self.size();
+        let mut augmented = vec![ModInt::zero(); size * (size + 1)];
+        augment(self.as_slice(), size, 1, &mut augmented, |row, _| {
+            rhs.as_slice()[row]
+        });
+        let mut solution = vec![ModInt::zero(); size];
+        solve(&mut augmented, &mut vec![0; size], &mut solution)
+            .then(|| VectorN::from_vec(solution))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F7 = ModInt<7>;
+    type F2 = ModInt<2>;
+    /// The Mersenne prime 2^61 - 1.
+    type Large = ModInt<2305843009213693951>;
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (F7::new(5), F7::new(4));
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((a - b).value(), 1);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((a / b).value(), 3);
+        assert_eq!((-a).value(), 2);
+        assert_eq!(F7::from(-1).value(), 6);
+        assert_eq!(F7::new(100).value(), 2);
+        for value in 1..7 {
+            let x = F7::new(value);
+            assert_eq!(x * x.inverse().unwrap(), F7::one());
+            assert_eq!(x.pow(6), F7::one());
+        }
+        assert_eq!(F7::zero().inverse(), None);
+        assert_eq!(ModInt::<12>::new(4).inverse(), None);
+        assert_eq!(ModInt::<12>::new(5).inverse(), Some(ModInt::new(5)));
+        assert_eq!(ModInt::<12>::new(3).checked_div(&ModInt::new(9)), None);
+        assert_eq!((F2::one() + F2::one()), F2::zero());
+        assert_eq!(format!("{} {:?}", a, a), "5 5 (mod 7)");
+
+        let x = Large::new(1234567890123456789);
+        assert_eq!(x.pow(2305843009213693950), Large::one());
+        assert_eq!((x * x.inverse().unwrap()).value(), 1);
+        let y = Large::new(2305843009213693950);
+        assert_eq!((y * y).value(), 1);
+        assert_eq!(
+            (x * y).value() as u128,
+            1234567890123456789u128 * 2305843009213693950 % 2305843009213693951
+        );
+    }
+
+    #[test]
+    fn primality() {
+        assert_eq!((0..100).filter(|&n| is_prime(n)).count(), 25);
+        assert!((0..16).filter(|&n| is_prime(n)).eq([2, 3, 5, 7, 11, 13]));
+        assert!(is_prime(2305843009213693951) && is_prime(18446744073709551557));
+        // Strong pseudoprimes to many small bases.
+        assert!(!is_prime(3215031751) && !is_prime(3825123056546413051));
+        assert!(!is_prime(2305843009213693953) && !is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn matrices() {
+        let m = |rows: [[i64; 3]; 3]| Matrix3x3::from_rows(rows.map(|row| row.map(F7::from)));
+        let matrix = m([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+        assert_eq!(matrix.determinant(), F7::from(-3));
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix * inverse, Matrix3x3::identity());
+        assert_eq!(matrix.rank(), 3);
+        assert_eq!(matrix.rref(), Matrix3x3::identity());
+        let b = Vector3::new_column(F7::new(1), F7::new(2), F7::new(3));
+        let x = matrix.solve(b.transpose()).unwrap();
+        assert_eq!(matrix * x.transpose(), b);
+
+        // Singular modulo 7 although its integer determinant is -7.
+        let singular = m([[1, 2, 3], [4, 5, 6], [7, 8, 16]]);
+        assert_eq!(singular.determinant(), F7::zero());
+        assert_eq!(singular.inverse(), None);
+        assert_eq!(singular.rank(), 2);
+        #[cfg(feature = "alloc")]
+        {
+            let kernel = singular.nullspace();
+            assert_eq!(kernel.len(), 1);
+            assert_eq!(
+                singular * kernel[0].transpose(),
+                Vector3::new_column(F7::zero(), F7::zero(), F7::zero())
+            );
+        }
+        assert_eq!(
+            singular.solve(Vector3::new(F7::new(1), F7::new(0), F7::new(0))),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn binary_codes() {
+        // A parity check matrix padded to 5x5, whose nullspace is a code of dimension 3.
+        let rows = [
+            [1, 1, 0, 1, 0],
+            [1, 0, 1, 0, 1],
+            [0, 1, 1, 1, 1],
+            [0, 0, 0, 0, 0],
+            [1, 1, 0, 1, 0],
+        ];
+        let matrix = MatrixNxN::from_vec(5, rows.iter().flatten().map(|&x| F2::new(x)).collect());
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.determinant(), F2::zero());
+        assert!(matches!(
+            matrix.try_inverse(),
+            Err(crate::Error::SingularMatrix)
+        ));
+        let code = matrix.nullspace();
+        assert_eq!(code.len(), 3);
+        for word in &code {
+            for row in 0..5 {
+                let parity = (0..5).fold(F2::zero(), |sum, column| {
+                    sum + matrix.row(row)[column] * word.as_slice()[column]
+                });
+                assert!(parity.is_zero());
+            }
+        }
+
+        let invertible = MatrixNxN::from_vec(
+            5,
+            (0..25)
+                .map(|i| F7::from(if i % 6 == 0 { 2 } else { i % 3 }))
+                .collect(),
+        );
+        let inverse = invertible.inverse();
+        let product = (0..25).map(|i| {
+            (0..5).fold(F7::zero(), |sum, k| {
+                sum + invertible.row(i / 5)[k] * inverse.row(k)[i % 5]
+            })
+        });
+        assert!(product
+            .enumerate()
+            .all(|(i, x)| x == F7::from((i % 6 == 0) as u8)));
+        assert_eq!(invertible.rank(), 5);
+        let rhs = VectorN::new((1..=5).map(F7::new).collect());
+        let solution = invertible.solve(&rhs).unwrap();
+        for row in 0..5 {
+            let value = (0..5).fold(F7::zero(), |sum, k| {
+                sum + invertible.row(row)[k] * solution.as_slice()[k]
+            });
+            assert_eq!(value, rhs.as_slice()[row]);
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use super::{Checked, Conjugate, Integer, Num, One, Pivot, Signed, Zero};
+
+use core::cmp::Ordering;
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact fraction of two integers.
+///
+/// Fractions are always stored in lowest terms with a positive denominator, so equal values have
+/// equal representations. Over primitive integers the operators panic on overflow in debug builds
+/// like the integers themselves, while the `Checked` methods report it instead.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// Panics if `denominator` is zero.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        Self::try_new(numerator, denominator).unwrap_or_else(|error| panic!("{}", error))
+    }
+    pub fn try_new(numerator: T, denominator: T) -> Result<Self, crate::Error> {
+        if denominator.is_zero() {
+            return Err(crate::Error::DivisionByZero);
+        }
+        let divisor = numerator.gcd(&denominator);
+        let (numerator, denominator) = (numerator / divisor.clone(), denominator / divisor);
+        Ok(if denominator < T::zero() {
+            Self {
+                numerator: T::zero() - numerator,
+                denominator: T::zero() - denominator,
+            }
+        } else {
+            Self {
+                numerator,
+                denominator,
+            }
+        })
+    }
+    pub fn from_integer(value: T) -> Self {
+        Self {
+            numerator: value,
+            denominator: T::one(),
+        }
+    }
+    pub fn numerator(&self) -> &T {
+        &self.numerator
+    }
+    pub fn denominator(&self) -> &T {
+        &self.denominator
+    }
+    pub fn into_parts(self) -> (T, T) {
+        (self.numerator, self.denominator)
+    }
+    pub fn is_integer(&self) -> bool {
+        self.denominator == T::one()
+    }
+    /// The reciprocal. Panics if `self` is zero.
+    pub fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+    /// The largest integer not greater than `self`.
+    pub fn floor(&self) -> T {
+        let quotient = self.numerator.clone() / self.denominator.clone();
+        if self.numerator < T::zero() && !self.is_integer() {
+            quotient - T::one()
+        } else {
+            quotient
+        }
+    }
+    /// The smallest integer not less than `self`.
+    pub fn ceil(&self) -> T {
+        let quotient = self.numerator.clone() / self.denominator.clone();
+        if self.numerator > T::zero() && !self.is_integer() {
+            quotient + T::one()
+        } else {
+            quotient
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<T: Integer> Zero for Rational<T> {
+    fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+}
+
+impl<T: Integer> One for Rational<T> {
+    fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+}
+
+impl<T: Integer> Num for Rational<T> {}
+
+impl<T: Integer> Pivot for Rational<T> {}
+
+impl<T: Integer + Neg<Output = T>> Signed for Rational<T> {
+    fn abs(&self) -> Self {
+        if self.numerator < T::zero() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+    fn signum(&self) -> Self {
+        Self::from_integer(match self.numerator.cmp(&T::zero()) {
+            Ordering::Less => -T::one(),
+            Ordering::Equal => T::zero(),
+            Ordering::Greater => T::one(),
+        })
+    }
+}
+
+impl<T: Integer> Conjugate for Rational<T> {
+    fn conjugate(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = self.denominator.gcd(&rhs.denominator);
+        let left = rhs.denominator.clone() / divisor.clone();
+        let right = self.denominator.clone() / divisor;
+        Self::new(
+            self.numerator * left.clone() + rhs.numerator * right,
+            self.denominator * left,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Rational<T>;
+
+    /// Over unsigned integers, panics in debug builds if the difference is negative.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let divisor = self.denominator.gcd(&rhs.denominator);
+        let left = rhs.denominator.clone() / divisor.clone();
+        let right = self.denominator.clone() / divisor;
+        Self::new(
+            self.numerator * left.clone() - rhs.numerator * right,
+            self.denominator * left,
+        )
+    }
+}
+
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancelling across before multiplying keeps intermediate values small.
+        let first = self.numerator.gcd(&rhs.denominator);
+        let second = rhs.numerator.gcd(&self.denominator);
+        Self::new(
+            (self.numerator / first.clone()) * (rhs.numerator / second.clone()),
+            (self.denominator / second) * (rhs.denominator / first),
+        )
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Rational<T>;
+
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+macro_rules! op_impl {
+    ($($op:ident $operator:tt),*) => {
+        ::paste::paste! {
+            $(
+                impl<T: Integer> $op<T> for Rational<T> {
+                    type Output = Rational<T>;
+
+                    fn [<$op:lower>](self, rhs: T) -> Self::Output {
+                        self $operator Rational::from_integer(rhs)
+                    }
+                }
+            )*
+        }
+    }
+}
+
+op_impl!(Add +, Sub -, Mul *, Div /);
+
+impl<T: Integer + Checked> Checked for Rational<T> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let divisor = self.denominator.gcd(&rhs.denominator);
+        let left = rhs.denominator.clone() / divisor.clone();
+        let right = self.denominator.clone() / divisor;
+        let numerator = self
+            .numerator
+            .checked_mul(&left)?
+            .checked_add(&rhs.numerator.checked_mul(&right)?)?;
+        Some(Self::new(numerator, self.denominator.checked_mul(&left)?))
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let divisor = self.denominator.gcd(&rhs.denominator);
+        let left = rhs.denominator.clone() / divisor.clone();
+        let right = self.denominator.clone() / divisor;
+        let numerator = self
+            .numerator
+            .checked_mul(&left)?
+            .checked_sub(&rhs.numerator.checked_mul(&right)?)?;
+        Some(Self::new(numerator, self.denominator.checked_mul(&left)?))
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let first = self.numerator.gcd(&rhs.denominator);
+        let second = rhs.numerator.gcd(&self.denominator);
+        let numerator = (self.numerator.clone() / first.clone())
+            .checked_mul(&(rhs.numerator.clone() / second.clone()))?;
+        let denominator =
+            (self.denominator.clone() / second).checked_mul(&(rhs.denominator.clone() / first))?;
+        Some(Self::new(numerator, denominator))
+    }
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (numerator, denominator) = if rhs.numerator < T::zero() {
+            (
+                T::zero().checked_sub(&rhs.denominator)?,
+                T::zero().checked_sub(&rhs.numerator)?,
+            )
+        } else {
+            (rhs.denominator.clone(), rhs.numerator.clone())
+        };
+        self.checked_mul(&Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    /// Compares the continued fraction expansions term by term, which only divides and so
+    /// cannot overflow the way cross-multiplying can.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (self.numerator.clone(), self.denominator.clone());
+        let (mut c, mut d) = (other.numerator.clone(), other.denominator.clone());
+        let mut reversed = false;
+        loop {
+            // `a / b` and `c / d` have positive denominators, so split each into its floor and
+            // a remainder in `[0, denominator)`.
+            let (first, first_remainder) = floor_div(a, b.clone());
+            let (second, second_remainder) = floor_div(c, d.clone());
+            let step = first
+                .cmp(&second)
+                .then_with(|| second_remainder.is_zero().cmp(&first_remainder.is_zero()));
+            if step != Ordering::Equal || first_remainder.is_zero() {
+                return if reversed { step.reverse() } else { step };
+            }
+            // Equal floors leave `first_remainder / b` against `second_remainder / d`, which
+            // compare in the opposite order to their reciprocals.
+            reversed = !reversed;
+            (a, b, c, d) = (b, first_remainder, d, second_remainder);
+        }
+    }
+}
+
+/// The floor of `numerator / denominator` and the non-negative remainder, for a positive
+/// `denominator`.
+fn floor_div<T: Integer>(numerator: T, denominator: T) -> (T, T) {
+    let quotient = numerator.clone() / denominator.clone();
+    let remainder = numerator % denominator.clone();
+    if remainder < T::zero() {
+        (quotient - T::one(), remainder + denominator)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+impl<T: Integer + Display> Display for Rational<T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        if self.is_integer() {
+            write!(fmt, "{}", self.numerator)
+        } else {
+            write!(fmt, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::matrix::matrix_3x3::Matrix3x3;
+    use crate::linear_algebra::matrix::matrix_4x4::Matrix4x4;
+    use crate::linear_algebra::vector::vector_3::Vector3;
+
+    fn r(numerator: i64, denominator: i64) -> Rational<i64> {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(r(2, 4), r(1, 2));
+        assert_eq!(r(3, -6).into_parts(), (-1, 2));
+        assert_eq!(r(0, -5).into_parts(), (0, 1));
+        assert_eq!(Rational::new(6u8, 4).into_parts(), (3, 2));
+        assert!(r(4, 2).is_integer());
+        assert_eq!((r(-7, 2).floor(), r(-7, 2).ceil()), (-4, -3));
+        assert_eq!((r(7, 2).floor(), r(7, 2).ceil()), (3, 4));
+        assert_eq!(r(-7, 2).abs(), r(7, 2));
+        assert_eq!(r(-7, 2).signum(), r(-1, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_denominator() {
+        r(1, 0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(r(1, 2) * 4, r(2, 1));
+        assert_eq!(-r(1, 2), r(-1, 2));
+        assert_eq!(r(0, 1) * r(5, 7), Rational::zero());
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert_eq!(
+            Rational::new(3u32, 1) - Rational::new(1, 2),
+            Rational::new(5, 2)
+        );
+        assert_eq!(
+            Rational::new(3u8, 4).checked_sub(&Rational::new(1, 4)),
+            Some(Rational::new(1, 2))
+        );
+        assert_eq!(
+            Rational::new(1u8, 4).checked_sub(&Rational::new(3, 4)),
+            None
+        );
+        assert_eq!(r(1, 3).to_string(), "1/3");
+        assert_eq!(r(-4, 2).to_string(), "-2");
+    }
+
+    #[test]
+    fn ordering() {
+        // Cross-multiplying any of these overflows `i64`.
+        let large = i64::MAX;
+        assert!(r(large, 2) > r(large - 2, 2));
+        assert!(r(large - 1, large) > r(large - 2, large - 1));
+        assert!(r(-large, large - 1) < r(-1, 1));
+        assert!(r(large - 1, large) > r(large - 2, large));
+        assert_eq!(r(large, 3).cmp(&r(large, 3)), Ordering::Equal);
+        let mut values = [
+            r(3, 7),
+            r(-5, 3),
+            r(0, 1),
+            r(22, 7),
+            r(-5, 4),
+            r(3, 8),
+            r(1, 1),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                r(-5, 3),
+                r(-5, 4),
+                r(0, 1),
+                r(3, 8),
+                r(3, 7),
+                r(1, 1),
+                r(22, 7)
+            ]
+        );
+        assert!(Rational::new(u64::MAX, u64::MAX - 1) < Rational::new(u64::MAX - 1, u64::MAX - 2));
+    }
+
+    #[test]
+    fn checked() {
+        let large = Rational::new(i8::MAX, 2);
+        assert_eq!(large.checked_add(&large), None);
+        assert_eq!(large.checked_mul(&Rational::new(3, 1)), None);
+        assert_eq!(
+            large.checked_mul(&Rational::new(2, 127)),
+            Some(Rational::one())
+        );
+        assert_eq!(large.checked_div(&Rational::zero()), None);
+        assert_eq!(
+            Rational::new(1i8, 2).checked_sub(&Rational::new(1, 3)),
+            Some(Rational::new(1, 6))
+        );
+        assert_eq!(
+            Rational::new(1i8, 2).checked_div(&Rational::new(-1, 3)),
+            Some(Rational::new(-3, 2))
+        );
+    }
+
+    #[test]
+    fn exact_linear_algebra() {
+        // Integer division truncates, while rational elements keep the inverse exact.
+        let integers = Matrix3x3::from_rows([[2, 1, 1], [1, 3, 2], [1, 0, 0]]);
+        let matrix = integers.map(Rational::from_integer);
+        assert_eq!(matrix.determinant(), r(-1, 1));
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse * matrix, Matrix3x3::identity());
+        assert_eq!(
+            Vector3::new(r(1, 1), r(2, 1), r(3, 1)) * inverse * matrix,
+            Vector3::new(r(1, 1), r(2, 1), r(3, 1))
+        );
+
+        let matrix =
+            Matrix4x4::from_rows([[3, 0, 2, -1], [1, 2, 0, -2], [4, 0, 6, -3], [5, 0, 2, 0]])
+                .map(Rational::from_integer);
+        assert_eq!(matrix.determinant(), r(20, 1));
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix * inverse, Matrix4x4::identity());
+        assert_eq!(inverse.as_rows()[0][0], r(3, 5));
+
+        let singular =
+            Matrix3x3::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).map(Rational::from_integer);
+        assert_eq!(singular.inverse(), None);
+        assert_eq!(singular.rank(), 2);
+        assert_eq!(
+            singular.rref(),
+            Matrix3x3::from_rows([
+                [r(1, 1), r(0, 1), r(-1, 1)],
+                [r(0, 1), r(1, 1), r(2, 1)],
+                [r(0, 1), r(0, 1), r(0, 1)],
+            ])
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Helpers shared by the unit tests.
+
+use crate::linear_algebra::quaternion::Quaternion;
+use crate::linear_algebra::vector::vector_2::Vector2;
+use crate::linear_algebra::vector::vector_3::Vector3;
+use crate::linear_algebra::vector::vector_4::Vector4;
+use crate::num::complex::Complex;
+
+use core::fmt::Display;
+
+/// A value with a distance to others of its type, for comparing results with rounding error.
+pub trait Close: Copy + Display {
+    fn distance(self, other: Self) -> f64;
+}
+
+impl Close for f64 {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).abs()
+    }
+}
+
+impl Close for Complex<f64> {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).modulus()
+    }
+}
+
+impl Close for Quaternion<f64> {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).norm()
+    }
+}
+
+macro_rules! vector_close_impl {
+    ($($vector:ident),*) => {
+        $(
+            /// The largest difference between corresponding components.
+            impl Close for $vector<f64> {
+                fn distance(self, other: Self) -> f64 {
+                    self.storage
+                        .iter()
+                        .zip(other.storage)
+                        .fold(0.0, |distance, (a, b)| distance.max((a - b).abs()))
+                }
+            }
+        )*
+    };
+}
+
+vector_close_impl!(Vector2, Vector3, Vector4);
+
+/// Asserts that `a` and `b` are within `1e-12` of each other.
+#[track_caller]
+pub fn assert_close<T: Close>(a: T, b: T) {
+    assert!(a.distance(b) < 1e-12, "{a} is not close to {b}");
+}